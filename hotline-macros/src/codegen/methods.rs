@@ -6,7 +6,7 @@ use crate::codegen::{
     ProcessedStruct,
     ffi::{FfiWrapper, quote_method_call_with_registry},
 };
-use crate::constants::{SET_PREFIX, WITH_PREFIX};
+use crate::constants::{AFTER_RELOAD, SET_PREFIX, VALIDATE, WITH_PREFIX};
 use crate::discovery::ReceiverType;
 use crate::utils::symbols::SymbolName;
 use crate::utils::types::{
@@ -64,6 +64,54 @@ pub fn generate_method_wrappers(
     wrappers
 }
 
+// Body of `HotlineObject::after_reload`: calls the object's own `fn after_reload(&mut self)` if it defines one
+pub fn generate_after_reload_call(struct_name: &Ident, main_impl: &ItemImpl) -> proc_macro2::TokenStream {
+    let Some(method) = main_impl.items.iter().find_map(|item| match item {
+        ImplItem::Fn(method) if method.sig.ident == AFTER_RELOAD => Some(method),
+        _ => None,
+    }) else {
+        return quote! {};
+    };
+
+    let takes_mut_self = matches!(
+        method.sig.inputs.first(),
+        Some(FnArg::Receiver(r)) if r.reference.is_some() && r.mutability.is_some()
+    );
+    if !takes_mut_self || method.sig.inputs.len() != 1 || !matches!(method.sig.output, ReturnType::Default) {
+        abort!(
+            method.sig.ident.span(),
+            "`after_reload` must have the signature `fn after_reload(&mut self)`";
+            note = "It runs after a hot reload has restored the object's state, in place of `initialize`"
+        );
+    }
+
+    quote! { #struct_name::after_reload(self); }
+}
+
+// Body of `HotlineObject::validate`: calls the object's own `fn validate(&self) -> Result<(), String>` if it defines one
+pub fn generate_validate_call(struct_name: &Ident, main_impl: &ItemImpl) -> proc_macro2::TokenStream {
+    let Some(method) = main_impl.items.iter().find_map(|item| match item {
+        ImplItem::Fn(method) if method.sig.ident == VALIDATE => Some(method),
+        _ => None,
+    }) else {
+        return quote! { Ok(()) };
+    };
+
+    let takes_ref_self = matches!(
+        method.sig.inputs.first(),
+        Some(FnArg::Receiver(r)) if r.reference.is_some() && r.mutability.is_none()
+    );
+    if !takes_ref_self || method.sig.inputs.len() != 1 || matches!(method.sig.output, ReturnType::Default) {
+        abort!(
+            method.sig.ident.span(),
+            "`validate` must have the signature `fn validate(&self) -> Result<(), String>`";
+            note = "Debug builds call it after a hot reload restores the object's state"
+        );
+    }
+
+    quote! { #struct_name::validate(self) }
+}
+
 fn generate_method_wrapper(
     struct_name: &Ident,
     method: &syn::ImplItemFn,
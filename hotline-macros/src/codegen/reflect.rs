@@ -0,0 +1,152 @@
+use proc_macro_error2::abort;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Fields, Type};
+
+use crate::codegen::ProcessedStruct;
+
+enum FieldKind {
+    Bool,
+    Float,
+    Integer,
+    Text,
+    Color,
+}
+
+fn field_kind(ty: &Type) -> Option<FieldKind> {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() && type_path.path.segments.len() == 1 => {
+            match type_path.path.segments[0].ident.to_string().as_str() {
+                "bool" => Some(FieldKind::Bool),
+                "f32" | "f64" => Some(FieldKind::Float),
+                "i8" | "i16" | "i32" | "i64" | "isize" | "u8" | "u16" | "u32" | "u64" | "usize" => {
+                    Some(FieldKind::Integer)
+                }
+                "String" => Some(FieldKind::Text),
+                _ => None,
+            }
+        }
+        Type::Tuple(tuple) if tuple.elems.len() == 4 && tuple.elems.iter().all(is_u8) => Some(FieldKind::Color),
+        _ => None,
+    }
+}
+
+fn is_u8(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path) if type_path.path.is_ident("u8"))
+}
+
+fn is_serde_skipped(field: &syn::Field) -> bool {
+    field.attrs.iter().filter(|attr| attr.path().is_ident("serde")).any(|attr| {
+        let mut skipped = false;
+        let parsed = attr.parse_nested_meta(|meta| {
+            skipped |= meta.path.is_ident("skip");
+            // Step over the values of other serde options, e.g. `rename = "x"` or `bound(serialize = "...")`
+            if meta.input.peek(syn::Token![=]) {
+                meta.value()?.parse::<syn::Expr>()?;
+            } else if meta.input.peek(syn::token::Paren) {
+                meta.parse_nested_meta(|nested| {
+                    if nested.input.peek(syn::Token![=]) {
+                        nested.value()?.parse::<syn::Expr>()?;
+                    }
+                    Ok(())
+                })?;
+            }
+            Ok(())
+        });
+        if let Err(e) = parsed {
+            abort!(e.span(), "unreadable serde attribute: {}", e);
+        }
+        skipped
+    })
+}
+
+pub fn generate_field_reflection(struct_name: &syn::Ident, processed: &ProcessedStruct) -> TokenStream {
+    let reflected: Vec<_> = if let Fields::Named(ref fields) = processed.modified_struct.fields {
+        fields
+            .named
+            .iter()
+            .filter(|f| f.ident.as_ref().map(|i| !i.to_string().starts_with("__hotline_")).unwrap_or(false))
+            .filter(|f| !is_serde_skipped(f))
+            .filter_map(|f| field_kind(&f.ty).map(|kind| (f.ident.clone().unwrap(), &f.ty, kind)))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let descriptors = reflected.iter().map(|(name, ty, kind)| {
+        let value = match kind {
+            FieldKind::Bool => quote! { ::hotline::FieldValue::Bool(self.#name) },
+            FieldKind::Float | FieldKind::Integer => quote! { ::hotline::FieldValue::Number(self.#name as f64) },
+            FieldKind::Text => quote! { ::hotline::FieldValue::Text(self.#name.clone()) },
+            FieldKind::Color => quote! { ::hotline::FieldValue::Color(self.#name) },
+        };
+        quote! {
+            ::hotline::FieldDescriptor {
+                name: stringify!(#name).to_string(),
+                type_name: stringify!(#ty).to_string(),
+                value: #value,
+            }
+        }
+    });
+
+    let setters = reflected.iter().map(|(name, ty, kind)| match kind {
+        FieldKind::Bool => quote! {
+            (stringify!(#name), ::hotline::FieldValue::Bool(v)) => self.__commit_field(|s| &mut s.#name, v)
+        },
+        FieldKind::Float => quote! {
+            (stringify!(#name), ::hotline::FieldValue::Number(v)) => self.__commit_field(|s| &mut s.#name, v as #ty)
+        },
+        FieldKind::Integer => quote! {
+            (stringify!(#name), ::hotline::FieldValue::Number(v)) => {
+                let rounded = v.round();
+                if !rounded.is_finite() || rounded < <#ty>::MIN as f64 || rounded > <#ty>::MAX as f64 {
+                    return Err(format!(
+                        "{} takes a whole number in {}..={}, got {}",
+                        stringify!(#name),
+                        <#ty>::MIN,
+                        <#ty>::MAX,
+                        v
+                    ));
+                }
+                self.__commit_field(|s| &mut s.#name, rounded as #ty)
+            }
+        },
+        FieldKind::Text => quote! {
+            (stringify!(#name), ::hotline::FieldValue::Text(v)) => self.__commit_field(|s| &mut s.#name, v)
+        },
+        FieldKind::Color => quote! {
+            (stringify!(#name), ::hotline::FieldValue::Color(v)) => self.__commit_field(|s| &mut s.#name, v)
+        },
+    });
+
+    quote! {
+        impl #struct_name {
+            fn __fields_impl(&self) -> Vec<::hotline::FieldDescriptor> {
+                vec![#(#descriptors),*]
+            }
+
+            fn __set_field_impl(&mut self, name: &str, value: ::hotline::FieldValue) -> Result<(), String> {
+                match (name, value) {
+                    #(#setters,)*
+                    (name, value) => Err(format!(
+                        "{} has no editable field '{}' taking {:?}",
+                        stringify!(#struct_name),
+                        name,
+                        value
+                    )),
+                }
+            }
+
+            // Stores an edited field, putting the old value back if the object's `validate` rejects it
+            #[allow(dead_code)]
+            fn __commit_field<T>(&mut self, field: fn(&mut Self) -> &mut T, value: T) -> Result<(), String> {
+                let previous = ::std::mem::replace(field(self), value);
+                let checked = <Self as ::hotline::HotlineObject>::validate(self);
+                if checked.is_err() {
+                    *field(self) = previous;
+                }
+                checked
+            }
+        }
+    }
+}
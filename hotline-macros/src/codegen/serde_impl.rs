@@ -110,11 +110,15 @@ pub fn generate_state_serialization(struct_name: &syn::Ident, processed: &Proces
         }
 
         impl #struct_name {
-            fn __serialize_state_impl(&self) -> Result<Vec<u8>, String> {
+            fn __serialize_state_impl(&self, format: ::hotline::StateFormat) -> Result<Vec<u8>, String> {
                 let state = #state_struct_name {
                     #(#field_copies,)*
                 };
-                ::hotline::serde_json::to_vec(&state).map_err(|e| e.to_string())
+                match format {
+                    ::hotline::StateFormat::Compact => ::hotline::serde_json::to_vec(&state),
+                    ::hotline::StateFormat::Json => ::hotline::serde_json::to_vec_pretty(&state),
+                }
+                .map_err(|e| e.to_string())
             }
 
             fn __deserialize_state_impl(&mut self, data: &[u8]) -> Result<(), String> {
@@ -155,14 +159,29 @@ pub fn generate_migrate_children_impl(struct_name: &syn::Ident, processed: &Proc
     quote! {
         impl #struct_name {
             fn migrate_children_impl(&mut self, reloaded_libs: &::std::collections::HashSet<String>) -> Result<(), String> {
+                #[allow(unused_mut)]
+                let mut invalid: Vec<String> = Vec::new();
                 #(#field_migrations)*
-                Ok(())
+                if invalid.is_empty() { Ok(()) } else { Err(invalid.join("; ")) }
             }
         }
     }
 }
 
+// Debug builds check a migrated object's invariants on the restored state, before `after_reload` can
+// repair it; violations are collected into `invalid` so the remaining children still migrate before
+// the reload is reported as failed
+fn generate_validation() -> TokenStream {
+    quote! {
+        #[cfg(debug_assertions)]
+        if let Err(e) = new_obj.validate() {
+            invalid.push(format!("{} object {} is invalid after reload: {}", type_name, old_id, e));
+        }
+    }
+}
+
 fn generate_field_migration(field_name: &syn::Ident, field_type: &Type) -> TokenStream {
+    let validation = generate_validation();
     // Handle Option<T> where T is an object type
     if let Type::Path(type_path) = field_type {
         if let Some(segment) = type_path.path.segments.last() {
@@ -190,6 +209,8 @@ fn generate_field_migration(field_name: &syn::Ident, field_type: &Type) -> Token
                                     new_obj.deserialize_state(&data)?;
                                     new_obj.set_registry(registry);
                                     new_obj.set_object_id(old_id);
+                                    #validation
+                                    new_obj.after_reload();
 
                                     eprintln!("Migrating {} object {} -> new object", type_name, old_id);
 
@@ -237,6 +258,8 @@ fn generate_field_migration(field_name: &syn::Ident, field_type: &Type) -> Token
                                                 new_obj.deserialize_state(&data)?;
                                                 new_obj.set_registry(registry);
                                                 new_obj.set_object_id(old_id);
+                                                #validation
+                                                new_obj.after_reload();
 
                                                 eprintln!("Migrating {} object {} -> new object", type_name, old_id);
 
@@ -282,6 +305,8 @@ fn generate_field_migration(field_name: &syn::Ident, field_type: &Type) -> Token
                         new_obj.deserialize_state(&data)?;
                         new_obj.set_registry(registry);
                         new_obj.set_object_id(old_id);
+                        #validation
+                        new_obj.after_reload();
 
                         eprintln!("Migrating {} object {} -> new object", type_name, old_id);
 
@@ -380,7 +405,7 @@ fn is_object_type(ty: &Type) -> bool {
                     // Common trait objects
                     "EventHandler" |
                     // Known custom types that are not objects
-                    "AtlasData" | "RenderCommand" | "AtlasFormat" | "SelectedObject" | "ResizeDir" => false,
+                    "AtlasData" | "RenderCommand" | "AtlasFormat" | "SelectedObject" | "ResizeDir" | "FieldScrub" | "PosterLayout" => false,
                     _ => {
                         // Additional check: if it's a qualified path (e.g., std::time::Instant), it's not an object
                         if type_path.path.segments.len() > 1 {
@@ -254,6 +254,14 @@ fn generate_typed_wrapper(
                 }
             }
 
+            fn serialize_state_as(&self, format: ::hotline::StateFormat) -> Result<Vec<u8>, String> {
+                if let Ok(guard) = self.0.lock() {
+                    guard.serialize_state_as(format)
+                } else {
+                    Err("Failed to lock object".to_string())
+                }
+            }
+
             fn deserialize_state(&mut self, data: &[u8]) -> Result<(), String> {
                 if let Ok(mut guard) = self.0.lock() {
                     guard.deserialize_state(data)
@@ -269,6 +277,36 @@ fn generate_typed_wrapper(
                     Err("Failed to lock object".to_string())
                 }
             }
+
+            fn after_reload(&mut self) {
+                if let Ok(mut guard) = self.0.lock() {
+                    guard.after_reload();
+                }
+            }
+
+            fn validate(&self) -> Result<(), String> {
+                if let Ok(guard) = self.0.lock() {
+                    guard.validate()
+                } else {
+                    Err("Failed to lock object".to_string())
+                }
+            }
+
+            fn fields(&self) -> Vec<::hotline::FieldDescriptor> {
+                if let Ok(guard) = self.0.lock() {
+                    guard.fields()
+                } else {
+                    Vec::new()
+                }
+            }
+
+            fn set_field(&mut self, name: &str, value: ::hotline::FieldValue) -> Result<(), String> {
+                if let Ok(mut guard) = self.0.lock() {
+                    guard.set_field(name, value)
+                } else {
+                    Err("Failed to lock object".to_string())
+                }
+            }
         }
     }
 }
@@ -1,5 +1,7 @@
 pub const WITH_PREFIX: &str = "with_";
 pub const SET_PREFIX: &str = "set_";
+pub const AFTER_RELOAD: &str = "after_reload";
+pub const VALIDATE: &str = "validate";
 
 pub const ERR_NO_REGISTRY: &str = "No library registry available for method";
 pub const ERR_LOCK_FAILED: &str = "Failed to lock object for method";
@@ -15,8 +15,9 @@ mod utils;
 use codegen::core::generate_core_functions;
 use codegen::custom_types::generate_custom_type_proxies_for_types;
 use codegen::fields::{generate_default_impl, generate_field_accessors, generate_setter_builder_methods};
-use codegen::methods::generate_method_wrappers;
+use codegen::methods::{generate_after_reload_call, generate_method_wrappers, generate_validate_call};
 use codegen::process_struct_attributes;
+use codegen::reflect::generate_field_reflection;
 use codegen::serde_impl::{generate_migrate_children_impl, generate_state_serialization};
 use codegen::wrapper::generate_typed_wrappers;
 use discovery::{extract_object_methods, find_referenced_custom_types, find_referenced_object_types};
@@ -206,12 +207,15 @@ pub fn object(input: TokenStream) -> TokenStream {
     // Generate all components
     let field_accessors = generate_field_accessors(struct_name, &processed, &rustc_commit);
     let method_wrappers = generate_method_wrappers(struct_name, main_impl, &processed, &rustc_commit);
+    let after_reload_call = generate_after_reload_call(struct_name, main_impl);
+    let validate_call = generate_validate_call(struct_name, main_impl);
     let core_functions = generate_core_functions(struct_name, &rustc_commit, has_default);
     let setter_builder_impl = generate_setter_builder_methods(struct_name, &processed);
     let default_impl =
         should_generate_default.then(|| generate_default_impl(struct_name, &processed)).unwrap_or_default();
     let state_serialization = generate_state_serialization(struct_name, &processed);
     let migrate_children_impl = generate_migrate_children_impl(struct_name, &processed);
+    let field_reflection = generate_field_reflection(struct_name, &processed);
     let referenced_objects = find_referenced_object_types(&struct_item, &impl_blocks);
 
     // Collect all objects and custom types transitively
@@ -394,7 +398,10 @@ pub fn object(input: TokenStream) -> TokenStream {
                 self.__hotline_registry.get()
             }
             fn serialize_state(&self) -> Result<Vec<u8>, String> {
-                self.__serialize_state_impl()
+                self.__serialize_state_impl(::hotline::StateFormat::Compact)
+            }
+            fn serialize_state_as(&self, format: ::hotline::StateFormat) -> Result<Vec<u8>, String> {
+                self.__serialize_state_impl(format)
             }
             fn deserialize_state(&mut self, data: &[u8]) -> Result<(), String> {
                 self.__deserialize_state_impl(data)
@@ -402,10 +409,23 @@ pub fn object(input: TokenStream) -> TokenStream {
             fn migrate_children(&mut self, reloaded_libs: &::std::collections::HashSet<String>) -> Result<(), String> {
                 self.migrate_children_impl(reloaded_libs)
             }
+            fn after_reload(&mut self) {
+                #after_reload_call
+            }
+            fn validate(&self) -> Result<(), String> {
+                #validate_call
+            }
+            fn fields(&self) -> Vec<::hotline::FieldDescriptor> {
+                self.__fields_impl()
+            }
+            fn set_field(&mut self, name: &str, value: ::hotline::FieldValue) -> Result<(), String> {
+                self.__set_field_impl(name, value)
+            }
         }
 
         #state_serialization
         #migrate_children_impl
+        #field_reflection
 
         #setter_builder_impl
         #(#field_accessors)*
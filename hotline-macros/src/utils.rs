@@ -131,8 +131,9 @@ pub mod types {
                     // if it's not object, not standard, not primitive, it's external
                     !is_object_type(&name) && !is_standard_type(&name) && !is_primitive_type(&name)
                 } else if tp.path.segments.len() > 1 {
-                    // multi-segment paths like sdl2::render::Canvas are external
-                    true
+                    // multi-segment paths like sdl2::render::Canvas are external, except hotline's own
+                    // types (e.g. ::hotline::FieldValue), which every object crate links against
+                    tp.path.segments[0].ident != "hotline"
                 } else {
                     false
                 }
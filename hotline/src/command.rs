@@ -4,7 +4,11 @@ use crate::macho_loader::MachoLoader;
 use libloading::{Library, Symbol};
 use std::any::Any;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, RwLock};
+
+/// (library, symbol, rustc commit) as listed by `LibraryRegistry::exported_symbols`
+pub type ExportedSymbol = (String, String, String);
 
 enum LoadedLibrary {
     Dlopen(Arc<Library>),
@@ -19,6 +23,12 @@ pub struct LibraryRegistry {
     use_custom_loader: bool,
     // Keep old libraries mapped to prevent TLV crashes during hot reload
     old_libs: Arc<Mutex<Vec<LoadedLibrary>>>,
+    // Number of times a loaded library has been replaced
+    reloads: Arc<AtomicU64>,
+    // File each library was last loaded from, for inspecting its symbol table
+    paths: Arc<Mutex<HashMap<String, String>>>,
+    theme: Arc<RwLock<crate::Theme>>,
+    precision: Arc<RwLock<crate::Precision>>,
 }
 
 impl LibraryRegistry {
@@ -27,6 +37,10 @@ impl LibraryRegistry {
             libs: Arc::new(Mutex::new(HashMap::new())),
             use_custom_loader: false,
             old_libs: Arc::new(Mutex::new(Vec::new())),
+            reloads: Arc::new(AtomicU64::new(0)),
+            paths: Arc::new(Mutex::new(HashMap::new())),
+            theme: Arc::new(RwLock::new(crate::Theme::default())),
+            precision: Arc::new(RwLock::new(crate::Precision::default())),
         }
     }
 
@@ -36,6 +50,10 @@ impl LibraryRegistry {
             libs: Arc::new(Mutex::new(HashMap::new())),
             use_custom_loader: true,
             old_libs: Arc::new(Mutex::new(Vec::new())),
+            reloads: Arc::new(AtomicU64::new(0)),
+            paths: Arc::new(Mutex::new(HashMap::new())),
+            theme: Arc::new(RwLock::new(crate::Theme::default())),
+            precision: Arc::new(RwLock::new(crate::Precision::default())),
         }
     }
 
@@ -130,6 +148,7 @@ impl LibraryRegistry {
     pub fn load(&self, lib_path: &str) -> Result<String, Box<dyn std::error::Error>> {
         let lib_name =
             std::path::Path::new(lib_path).file_stem().and_then(|s| s.to_str()).ok_or("invalid lib path")?.to_string();
+        self.paths.lock().unwrap().insert(lib_name.clone(), lib_path.to_string());
 
         if self.use_custom_loader {
             // use custom mach-o loader
@@ -164,6 +183,7 @@ impl LibraryRegistry {
                     {
                         let mut old_libs = self.old_libs.lock().unwrap();
                         old_libs.push(old_lib);
+                        self.reloads.fetch_add(1, Ordering::SeqCst);
                     }
 
                     return Ok(lib_name);
@@ -198,11 +218,74 @@ impl LibraryRegistry {
         if let Some(old_lib) = libs.insert(lib_name.clone(), LoadedLibrary::Dlopen(Arc::new(lib))) {
             let mut old_libs = self.old_libs.lock().unwrap();
             old_libs.push(old_lib);
+            self.reloads.fetch_add(1, Ordering::SeqCst);
         }
 
         Ok(lib_name)
     }
 
+    /// Count of hot reloads so far; changes whenever any library is replaced.
+    pub fn reload_count(&self) -> u64 {
+        self.reloads.load(Ordering::SeqCst)
+    }
+
+    /// Colors objects draw their UI with; follows `set_theme` from the next frame.
+    pub fn theme(&self) -> crate::Theme {
+        *self.theme.read().unwrap()
+    }
+
+    pub fn set_theme(&self, theme: crate::Theme) {
+        *self.theme.write().unwrap() = theme;
+    }
+
+    /// Decimal places objects format numeric readouts with; follows `set_precision` like the theme.
+    pub fn precision(&self) -> crate::Precision {
+        *self.precision.read().unwrap()
+    }
+
+    pub fn set_precision(&self, precision: crate::Precision) {
+        *self.precision.write().unwrap() = precision;
+    }
+
+    /// Hotline-mangled symbols exported by each loaded library as (library, symbol, rustc commit), sorted.
+    /// Symbols whose commit differs from `crate::RUSTC_COMMIT` were built by another toolchain and won't resolve.
+    pub fn exported_symbols(&self) -> Result<Vec<ExportedSymbol>, Box<dyn std::error::Error>> {
+        let mut table = Vec::new();
+        let libs = self.libs.lock().unwrap();
+        let paths = self.paths.lock().unwrap();
+        let mut names: Vec<&String> = libs.keys().collect();
+        names.sort();
+        for lib_name in names {
+            let symbols: std::collections::BTreeSet<String> = match &libs[lib_name] {
+                // libloading can't enumerate exports, so scan the file for mangled names; this also picks up
+                // the names a library looks up in others, which must match the runtime commit just the same
+                LoadedLibrary::Dlopen(_) => {
+                    let path = paths.get(lib_name).ok_or_else(|| format!("no path recorded for '{}'", lib_name))?;
+                    let bytes = std::fs::read(path)?;
+                    bytes
+                        .split(|b| *b == 0)
+                        .filter_map(|chunk| std::str::from_utf8(chunk).ok())
+                        .map(|s| s.to_string())
+                        .collect()
+                }
+                #[cfg(target_os = "macos")]
+                LoadedLibrary::Custom(loader) => loader.lock().unwrap().list_symbols().into_iter().collect(),
+            };
+            for symbol in symbols {
+                // Mach-O prefixes C symbols with an underscore
+                let symbol = symbol.trim_start_matches('_');
+                let Some((name, commit)) = symbol.rsplit_once("__") else {
+                    continue;
+                };
+                let is_commit = commit.len() == 9 && commit.chars().all(|c| c.is_ascii_hexdigit());
+                if is_commit && name.contains("__to__") {
+                    table.push((lib_name.clone(), name.to_string(), commit.to_string()));
+                }
+            }
+        }
+        Ok(table)
+    }
+
     pub fn with_symbol<T, R, F>(&self, lib_name: &str, symbol_name: &str, f: F) -> Result<R, Box<dyn std::error::Error>>
     where
         T: 'static,
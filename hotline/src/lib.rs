@@ -12,6 +12,9 @@ pub use serde_json;
 
 pub mod object_serde;
 
+#[cfg(test)]
+mod test_render_mode;
+
 // GPU rendering trait that objects can use
 pub trait GpuRenderingContext {
     fn create_rgba_texture(&mut self, data: &[u8], width: u32, height: u32) -> Result<u32, String>;
@@ -31,8 +34,120 @@ pub trait GpuRenderingContext {
     );
     fn add_solid_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: [f32; 4]);
     fn add_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: [f32; 4]);
+    // Drawn as geometry rather than textures, with antialiased edges at any size
+    fn add_circle(&mut self, cx: f32, cy: f32, radius: f32, color: [f32; 4]);
+    // `radius` runs to the middle of the stroke
+    fn add_ring(&mut self, cx: f32, cy: f32, radius: f32, thickness: f32, color: [f32; 4]);
+    // Radii past half the shorter side clamp to it, so a large radius gives a pill or circle
+    fn add_rounded_rect(&mut self, x: f32, y: f32, w: f32, h: f32, radius: f32, color: [f32; 4]) {
+        self.add_rounded_rect_corners(x, y, w, h, [radius; 4], color);
+    }
+    // Radii run top-left, top-right, bottom-right, bottom-left
+    fn add_rounded_rect_corners(&mut self, x: f32, y: f32, w: f32, h: f32, radii: [f32; 4], color: [f32; 4]);
+    // Textures can be evicted under memory pressure; cached ids must be revalidated
+    fn has_texture(&self, tex_id: u32) -> bool;
+    // Clips later draws to a screen rect, intersected with any clip already pushed, until the matching pop.
+    // Contexts that can't clip draw everything
+    fn push_clip_rect(&mut self, _x: f32, _y: f32, _w: f32, _h: f32) {}
+    fn pop_clip_rect(&mut self) {}
 }
 
+// Triangle list covering the annulus between two radii (a disc when `inner_radius` is 0), each vertex
+// with the coverage its color's alpha should be scaled by. Edges fade out across `feather`, which
+// renderers set to one device pixel in vertex units, and the segment count follows the on-screen size.
+pub fn annulus_triangles(cx: f32, cy: f32, inner_radius: f32, outer_radius: f32, feather: f32) -> Vec<([f32; 2], f32)> {
+    if outer_radius <= inner_radius || feather <= 0.0 {
+        return Vec::new();
+    }
+    let half = feather / 2.0;
+    // A band thinner than the feather never reaches full coverage
+    let peak = ((outer_radius - inner_radius) / feather).min(1.0);
+    let middle = (inner_radius + outer_radius) / 2.0;
+    let mut stops = Vec::with_capacity(4);
+    if inner_radius > 0.0 {
+        stops.push(((inner_radius - half).max(0.0), 0.0));
+    }
+    stops.push(if inner_radius > 0.0 { ((inner_radius + half).min(middle), peak) } else { (0.0, peak) });
+    stops.push(((outer_radius - half).max(middle), peak));
+    stops.push((outer_radius + half, 0.0));
+
+    // Keeps the gap between each chord and the true edge under a quarter of a pixel
+    let segments = (std::f32::consts::PI * (2.0 * outer_radius / feather).sqrt()).ceil().clamp(12.0, 128.0) as usize;
+    let point = |radius: f32, i: usize| {
+        let angle = std::f32::consts::TAU * i as f32 / segments as f32;
+        [cx + radius * angle.cos(), cy + radius * angle.sin()]
+    };
+    let mut triangles = Vec::with_capacity(segments * (stops.len() - 1) * 6);
+    for band in stops.windows(2) {
+        let ((r0, a0), (r1, a1)) = (band[0], band[1]);
+        for i in 0..segments {
+            let (inner0, inner1) = ((point(r0, i), a0), (point(r0, i + 1), a0));
+            let (outer0, outer1) = ((point(r1, i), a1), (point(r1, i + 1), a1));
+            triangles.extend_from_slice(&[inner0, outer0, inner1, inner1, outer0, outer1]);
+        }
+    }
+    triangles
+}
+
+// Triangle list covering a rounded rect, in the same form as annulus_triangles. Radii run top-left,
+// top-right, bottom-right, bottom-left and clamp to half the shorter side.
+pub fn rounded_rect_triangles(x: f32, y: f32, w: f32, h: f32, radii: [f32; 4], feather: f32) -> Vec<([f32; 2], f32)> {
+    if w <= 0.0 || h <= 0.0 || feather <= 0.0 {
+        return Vec::new();
+    }
+    let half = feather / 2.0;
+    let radii = radii.map(|r| r.clamp(0.0, w.min(h) / 2.0));
+    let peak = (w.min(h) / feather).min(1.0);
+    let largest = radii.iter().copied().fold(0.0, f32::max);
+    let steps = (std::f32::consts::FRAC_PI_2 * ((largest + half) / feather).sqrt()).ceil().clamp(2.0, 32.0) as usize;
+
+    // The edge grown or shrunk by `offset`, as the same number of points either way so the two pair up
+    let outline = |offset: f32| {
+        let (left, top) = ((x - offset).min(x + w / 2.0), (y - offset).min(y + h / 2.0));
+        let (right, bottom) = ((x + w + offset).max(x + w / 2.0), (y + h + offset).max(y + h / 2.0));
+        // Each corner, the direction to its arc's center, and the quarter turn its arc starts at
+        let corners = [
+            (left, top, 1.0, 1.0, 2.0),
+            (right, top, -1.0, 1.0, 3.0),
+            (right, bottom, -1.0, -1.0, 0.0),
+            (left, bottom, 1.0, -1.0, 1.0),
+        ];
+        let mut points = Vec::with_capacity(4 * (steps + 1));
+        for (i, &(corner_x, corner_y, sign_x, sign_y, quarter)) in corners.iter().enumerate() {
+            let r = (radii[i] + offset).max(0.0);
+            let (center_x, center_y) = (corner_x + sign_x * r, corner_y + sign_y * r);
+            for step in 0..=steps {
+                let angle = std::f32::consts::FRAC_PI_2 * (quarter + step as f32 / steps as f32);
+                points.push([center_x + r * angle.cos(), center_y + r * angle.sin()]);
+            }
+        }
+        points
+    };
+    let (inside, outside) = (outline(-half), outline(half));
+    let center = [x + w / 2.0, y + h / 2.0];
+
+    let mut triangles = Vec::with_capacity(inside.len() * 9);
+    for i in 0..inside.len() {
+        let next = (i + 1) % inside.len();
+        let (inner0, inner1) = ((inside[i], peak), (inside[next], peak));
+        let (outer0, outer1) = ((outside[i], 0.0), (outside[next], 0.0));
+        triangles.extend_from_slice(&[(center, peak), inner0, inner1]);
+        triangles.extend_from_slice(&[inner0, outer0, inner1, inner1, outer0, outer1]);
+    }
+    triangles
+}
+
+// Screen-space geometry shared by shape objects (Rect, RegularPolygon, Image).
+// Objects expose these as methods; consumers implement this for their proxies to hit-test uniformly
+pub trait Bounded {
+    fn bounds(&self) -> (f64, f64, f64, f64);
+    fn contains_point(&self, x: f64, y: f64) -> bool;
+    fn center(&self) -> (f64, f64);
+}
+
+// A (start, end) range of text and the color to draw it in, as produced by a syntax highlighter
+pub type ColorSpan = (usize, usize, (u8, u8, u8, u8));
+
 // Re-export libloading for objects to use
 pub use libloading;
 
@@ -113,7 +228,7 @@ mod tlv_support;
 #[cfg(not(target_os = "macos"))]
 mod tlv_support {}
 
-pub use command::{CommandHandler, CommandRegistry, LibraryRegistry};
+pub use command::{CommandHandler, CommandRegistry, ExportedSymbol, LibraryRegistry};
 
 /// Macro to safely call a symbol from a library
 /// The Symbol must be kept alive until after the function call
@@ -192,6 +307,250 @@ pub fn generate_object_id() -> u64 {
     NEXT_OBJECT_ID.fetch_add(1, Ordering::SeqCst)
 }
 
+// GPU texture handles keyed by (object id, purpose). Object ids survive hot reload,
+// so a reloaded object can pick its textures back up instead of recreating them.
+static TEXTURE_HANDLES: OnceLock<RwLock<HashMap<(u64, String), u32>>> = OnceLock::new();
+
+pub fn register_texture_handle(object_id: u64, purpose: &str, tex_id: u32) {
+    let handles = TEXTURE_HANDLES.get_or_init(|| RwLock::new(HashMap::new()));
+    if let Ok(mut handles) = handles.write() {
+        handles.insert((object_id, purpose.to_string()), tex_id);
+    }
+}
+
+pub fn texture_handle(object_id: u64, purpose: &str) -> Option<u32> {
+    TEXTURE_HANDLES.get()?.read().ok()?.get(&(object_id, purpose.to_string())).copied()
+}
+
+/// Return the texture registered for `(object_id, purpose)`, calling `create` and registering
+/// the result only if there is none or the renderer no longer has it.
+pub fn acquire_texture(
+    gpu: &mut dyn GpuRenderingContext,
+    object_id: u64,
+    purpose: &str,
+    create: impl FnOnce(&mut dyn GpuRenderingContext) -> Result<u32, String>,
+) -> Result<u32, String> {
+    if let Some(tex_id) = texture_handle(object_id, purpose).filter(|&tex_id| gpu.has_texture(tex_id)) {
+        return Ok(tex_id);
+    }
+    let tex_id = create(gpu)?;
+    register_texture_handle(object_id, purpose, tex_id);
+    Ok(tex_id)
+}
+
+// Which render path the host drives. Objects that only implement the other one still draw through it,
+// so forcing a mode never blanks an object.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RenderMode {
+    #[default]
+    Gpu,
+    Cpu, // Software buffer uploaded as one texture, for screenshots and headless runs
+}
+
+impl std::str::FromStr for RenderMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "gpu" => Ok(RenderMode::Gpu),
+            "cpu" => Ok(RenderMode::Cpu),
+            _ => Err("expected 'cpu' or 'gpu'".to_string()),
+        }
+    }
+}
+
+impl RenderMode {
+    // Whether the host draws `handler` into the CPU layer: in CPU mode those that can, in GPU mode those that must
+    pub fn draws_on_cpu(self, handler: &dyn EventHandler) -> bool {
+        match self {
+            RenderMode::Cpu => handler.supports_cpu_render(),
+            RenderMode::Gpu => !handler.supports_gpu_render(),
+        }
+    }
+}
+
+// Encoding produced by `HotlineObject::serialize_state_as`. Both are JSON underneath, so
+// `deserialize_state` reads either one back.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StateFormat {
+    #[default]
+    Compact, // Minimal bytes, what hot reload and undo use
+    Json, // Pretty-printed for reading, diffing and version control
+}
+
+impl std::str::FromStr for StateFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "compact" => Ok(StateFormat::Compact),
+            "json" => Ok(StateFormat::Json),
+            _ => Err("expected 'compact' or 'json'".to_string()),
+        }
+    }
+}
+
+// Shared UI colors, held by the `LibraryRegistry` so every object sees the same theme. Objects read it
+// in their render paths each frame, so `set_theme` takes effect on the next frame without a reload.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Theme {
+    pub background: [f32; 4],   // Window clear color
+    pub panel: [f32; 4],        // Panels, popups and widget backgrounds
+    pub panel_header: [f32; 4], // Title bars and the troughs behind value bars
+    pub border: [f32; 4],
+    pub accent: [f32; 4],    // Value bars, pressed buttons and checked boxes
+    pub selection: [f32; 4], // The row being edited
+    pub text: (u8, u8, u8, u8),
+    pub header_text: (u8, u8, u8, u8), // Section headers and field names
+    pub dim_text: (u8, u8, u8, u8),    // Placeholders and inactive states
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Theme {
+            background: [0.196, 0.196, 0.196, 1.0],
+            panel: [0.157, 0.157, 0.157, 0.95],
+            panel_header: [0.235, 0.235, 0.235, 1.0],
+            border: [0.5, 0.5, 0.5, 1.0],
+            accent: [1.0, 0.41, 0.71, 1.0], // Pink
+            selection: [0.31, 0.31, 0.47, 1.0],
+            text: (255, 255, 255, 255),
+            header_text: (200, 200, 255, 255), // Light blue
+            dim_text: (128, 128, 128, 255),
+        }
+    }
+
+    pub fn light() -> Self {
+        Theme {
+            background: [0.9, 0.9, 0.92, 1.0],
+            panel: [0.97, 0.97, 0.97, 0.95],
+            panel_header: [0.84, 0.84, 0.88, 1.0],
+            border: [0.6, 0.6, 0.65, 1.0],
+            accent: [0.85, 0.2, 0.5, 1.0],
+            selection: [0.75, 0.82, 1.0, 1.0],
+            text: (20, 20, 20, 255),
+            header_text: (40, 60, 160, 255),
+            dim_text: (120, 120, 120, 255),
+        }
+    }
+
+    // For text drawn in the accent color
+    pub fn accent_text(&self) -> (u8, u8, u8, u8) {
+        let [r, g, b, a] = self.accent.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+        (r, g, b, a)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+impl std::str::FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "dark" => Ok(Theme::dark()),
+            "light" => Ok(Theme::light()),
+            _ => Err("expected 'dark' or 'light'".to_string()),
+        }
+    }
+}
+
+// Decimal places for numeric readouts by kind of value, held by the `LibraryRegistry` beside the theme
+// so panels and inspectors format the same quantity the same way. Readouts pass a field to `{:.*}`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Precision {
+    pub length: usize, // Positions, sizes and distances
+    pub angle: usize,  // Rotations, in whatever unit the readout shows
+    pub rate: usize,   // Speeds, accelerations and frame rates
+    pub fine: usize,   // Small quantities like damping and density
+    pub number: usize, // Scales, multipliers and anything unclassified
+}
+
+impl Precision {
+    const KINDS: [&'static str; 5] = ["length", "angle", "rate", "fine", "number"];
+
+    fn places_mut(&mut self, kind: &str) -> Option<&mut usize> {
+        match kind {
+            "length" => Some(&mut self.length),
+            "angle" => Some(&mut self.angle),
+            "rate" => Some(&mut self.rate),
+            "fine" => Some(&mut self.fine),
+            "number" => Some(&mut self.number),
+            _ => None,
+        }
+    }
+
+    // Places for a value known only by its field or label name, e.g. "camera_yaw" or "Star Size"
+    pub fn for_name(&self, name: &str) -> usize {
+        let name = name.to_ascii_lowercase();
+        let words: Vec<&str> = name.split(['_', ' ']).collect();
+        let has = |kind: &[&str]| words.iter().any(|word| kind.contains(word));
+        if has(&["rotation", "angle", "yaw", "pitch", "roll", "fov"]) {
+            self.angle
+        } else if has(&["speed", "velocity", "acceleration", "fps", "rate"]) {
+            self.rate
+        } else if has(&["damping", "density", "friction"]) {
+            self.fine
+        } else if has(&["x", "y", "z", "width", "height", "radius", "size", "length", "distance", "offset", "padding"])
+        {
+            self.length
+        } else {
+            self.number
+        }
+    }
+}
+
+impl Default for Precision {
+    fn default() -> Self {
+        Precision { length: 1, angle: 2, rate: 1, fine: 4, number: 2 }
+    }
+}
+
+// Comma-separated `kind=places` over the defaults, e.g. "length=2,angle=3"; `all` sets every kind
+impl std::str::FromStr for Precision {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut precision = Precision::default();
+        for entry in s.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (kind, places) =
+                entry.split_once('=').ok_or_else(|| format!("expected kind=places, got '{}'", entry))?;
+            let places: usize = places.trim().parse().map_err(|e| format!("bad places in '{}': {}", entry, e))?;
+            let kind = kind.trim().to_ascii_lowercase();
+            if kind == "all" {
+                precision = Precision { length: places, angle: places, rate: places, fine: places, number: places };
+            } else {
+                let slot = precision.places_mut(&kind).ok_or_else(|| {
+                    format!("unknown kind '{}', expected all or one of {}", kind, Self::KINDS.join(", "))
+                })?;
+                *slot = places;
+            }
+        }
+        Ok(precision)
+    }
+}
+
+// Live value of one object field, as exposed by `HotlineObject::fields`. Integer and float fields
+// are both carried as `Number`; `set_field` converts back to the field's own type.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FieldValue {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+    Color((u8, u8, u8, u8)),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldDescriptor {
+    pub name: String,
+    pub type_name: String, // Declared Rust type, e.g. "f32" or "(u8, u8, u8, u8)"
+    pub value: FieldValue,
+}
+
 pub trait HotlineObject: Any + Send + Sync {
     fn type_name(&self) -> &'static str;
     fn object_id(&self) -> u64;
@@ -200,9 +559,31 @@ pub trait HotlineObject: Any + Send + Sync {
     fn as_any_mut(&mut self) -> &mut dyn Any;
     fn set_registry(&mut self, registry: &'static LibraryRegistry);
     fn get_registry(&self) -> Option<&'static LibraryRegistry>;
+    // The registry, for the theme and precision every object draws with. Objects only exist inside
+    // one, so a missing registry is a bug rather than a reason to fall back to defaults.
+    fn registry(&self) -> &'static LibraryRegistry {
+        self.get_registry()
+            .unwrap_or_else(|| panic!("{} object {} has no library registry", self.type_name(), self.object_id()))
+    }
     fn serialize_state(&self) -> Result<Vec<u8>, String>;
+    fn serialize_state_as(&self, format: StateFormat) -> Result<Vec<u8>, String>;
     fn deserialize_state(&mut self, data: &[u8]) -> Result<(), String>;
     fn migrate_children(&mut self, reloaded_libs: &HashSet<String>) -> Result<(), String>;
+    // Lifecycle: `new()` builds a default object and the owner calls `initialize` once, on first
+    // creation only. A hot reload instead constructs the new version, restores state with
+    // `deserialize_state`, sets the registry and object id, then calls this. Objects opt in by
+    // defining `fn after_reload(&mut self)` to rebuild `#[serde(skip)]` or derived state; it must
+    // not reset state that was restored (e.g. by calling `initialize`).
+    fn after_reload(&mut self);
+    // Invariant check, defined like `after_reload` as `fn validate(&self) -> Result<(), String>`.
+    // Debug builds run it on each migrated object's restored state, before `after_reload`, and report
+    // a violation as a failed reload.
+    fn validate(&self) -> Result<(), String>;
+    // Reflection over serialized bool, numeric, String and (u8, u8, u8, u8) fields, generated by
+    // `object!`; `#[serde(skip)]` fields and other types are not listed. `set_field` refuses numbers
+    // the field can't hold and runs `validate`, putting the old value back if it fails.
+    fn fields(&self) -> Vec<FieldDescriptor>;
+    fn set_field(&mut self, name: &str, value: FieldValue) -> Result<(), String>;
 }
 
 pub type ObjectHandle = Arc<Mutex<Box<dyn HotlineObject>>>;
@@ -279,6 +660,11 @@ impl<T> Clone for ObjectRef<T> {
 
 // Event handling trait for objects
 pub trait EventHandler: Send + Sync {
+    // Whether a press at (x, y) lands on the handler's interactive region; misses skip its
+    // handle_mouse_down and go on to whatever is underneath. By default every point does.
+    fn hit_test(&self, _x: f64, _y: f64) -> bool {
+        true
+    }
     fn handle_mouse_down(&mut self, _x: f64, _y: f64) -> bool {
         false
     }
@@ -291,6 +677,10 @@ pub trait EventHandler: Send + Sync {
     fn handle_mouse_wheel(&mut self, _x: f64, _y: f64, _delta: f64) -> bool {
         false
     }
+    // Horizontal wheel, positive to the right
+    fn handle_mouse_wheel_x(&mut self, _x: f64, _y: f64, _delta: f64) -> bool {
+        false
+    }
     fn handle_text_input(&mut self, _text: &str) -> bool {
         false
     }
@@ -300,6 +690,22 @@ pub trait EventHandler: Send + Sync {
     fn is_focused(&self) -> bool {
         false
     }
-    fn update(&mut self) {}
+    fn update(&mut self, _dt: f64) {} // Seconds since the last frame, clamped by the host
+    // Seconds between update and render_gpu calls; the host redraws the last frame in between. 0 is every frame
+    fn update_interval(&self) -> f64 {
+        0.0
+    }
     fn render(&mut self, _buffer: &mut [u8], _width: i64, _height: i64, _pitch: i64) {}
+    fn render_gpu(&mut self, _gpu_renderer: &mut dyn GpuRenderingContext) {}
+    // Which of render and render_gpu actually draw; the host uses the other when its RenderMode is missing
+    fn supports_cpu_render(&self) -> bool {
+        false
+    }
+    fn supports_gpu_render(&self) -> bool {
+        true
+    }
+    // Lock of the wrapped object, so the host can recover it if a call panics
+    fn handle(&self) -> Option<ObjectHandle> {
+        None
+    }
 }
@@ -0,0 +1,29 @@
+use crate::{EventHandler, RenderMode};
+
+struct CpuOnly;
+
+impl EventHandler for CpuOnly {
+    fn supports_cpu_render(&self) -> bool {
+        true
+    }
+    fn supports_gpu_render(&self) -> bool {
+        false
+    }
+}
+
+struct GpuOnly;
+
+impl EventHandler for GpuOnly {}
+
+#[test]
+fn test_cpu_only_handler_drawn_on_cpu() {
+    // Drawn into the CPU layer in CPU mode, and in GPU mode since it has nothing else
+    assert!(RenderMode::Cpu.draws_on_cpu(&CpuOnly));
+    assert!(RenderMode::Gpu.draws_on_cpu(&CpuOnly));
+}
+
+#[test]
+fn test_gpu_only_handler_skips_cpu_layer() {
+    assert!(!RenderMode::Cpu.draws_on_cpu(&GpuOnly));
+    assert!(!RenderMode::Gpu.draws_on_cpu(&GpuOnly));
+}
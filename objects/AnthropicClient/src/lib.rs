@@ -3,7 +3,12 @@ hotline::object!({
     pub struct AnthropicClient {
         api_key: Option<String>,
         #[setter]
+        #[default("claude-3-5-sonnet-20241022".to_string())]
+        model: String,
+        #[setter]
         response_target: Option<ChatInterface>,
+        #[serde(skip)]
+        request_task: Option<tokio::task::AbortHandle>, // The request in flight, for cancel_request
     }
 
     impl AnthropicClient {
@@ -67,15 +72,14 @@ hotline::object!({
             // Create the request
             let messages = vec![AnthropicMessage { role: "user".to_string(), content: user_message }];
 
-            let request =
-                AnthropicRequest { model: "claude-3-5-sonnet-20241022".to_string(), max_tokens: 1024, messages };
+            let request = AnthropicRequest { model: self.model.clone(), max_tokens: 1024, messages };
 
             // Clone what we need for the async task
             let api_key_clone = api_key.clone();
             let response_target = self.response_target.clone();
 
             // Spawn async task on hotline runtime
-            ::hotline::hotline_runtime().spawn(async move {
+            let task = ::hotline::hotline_runtime().spawn(async move {
                 // Make async API call
                 let client = reqwest::Client::builder().timeout(std::time::Duration::from_secs(30)).build().unwrap();
 
@@ -127,6 +131,101 @@ hotline::object!({
                     target.receive_llm_response(response_text);
                 }
             });
+            self.request_task = Some(task.abort_handle());
+        }
+
+        // Like send_message, but each piece of the reply goes to the target's receive_llm_delta as it
+        // arrives; receive_llm_response follows with an empty string once it's done, or the error that ended it
+        pub fn send_message_streaming(&mut self, user_message: String) {
+            let Some(api_key) = self.api_key.clone() else {
+                self.send_response("Error: ANTHROPIC_API_KEY not set".to_string());
+                return;
+            };
+            let request = serde_json::json!({
+                "model": self.model,
+                "max_tokens": 1024,
+                "stream": true,
+                "messages": [{ "role": "user", "content": user_message }],
+            });
+            let response_target = self.response_target.clone();
+
+            let task = ::hotline::hotline_runtime().spawn(async move {
+                // A long reply can outlast any total timeout, so only a stalled stream gives up
+                let client =
+                    reqwest::Client::builder().read_timeout(std::time::Duration::from_secs(30)).build().unwrap();
+
+                let response = client
+                    .post("https://api.anthropic.com/v1/messages")
+                    .header("x-api-key", api_key)
+                    .header("anthropic-version", "2023-06-01")
+                    .header("content-type", "application/json")
+                    .json(&request)
+                    .send()
+                    .await;
+
+                let mut target = response_target;
+                let outcome = match response {
+                    Ok(resp) if !resp.status().is_success() => {
+                        let status = resp.status();
+                        let error_text = resp.text().await.unwrap_or_else(|_| "Unknown error".to_string());
+                        format!("API error ({}): {}", status, error_text)
+                    }
+                    Ok(mut resp) => {
+                        // Events are newline-delimited; a chunk can end mid-line or mid-character
+                        let mut pending: Vec<u8> = Vec::new();
+                        let mut outcome = String::new();
+                        'read: loop {
+                            match resp.chunk().await {
+                                Ok(Some(bytes)) => pending.extend_from_slice(&bytes),
+                                Ok(None) => break,
+                                Err(e) => {
+                                    outcome = format!("Error reading response: {}", e);
+                                    break;
+                                }
+                            }
+                            while let Some(end) = pending.iter().position(|&b| b == b'\n') {
+                                let line: Vec<u8> = pending.drain(..=end).collect();
+                                match stream_event(String::from_utf8_lossy(&line).trim_end()) {
+                                    Some(Ok(text)) => {
+                                        if let Some(ref mut target) = target {
+                                            target.receive_llm_delta(text);
+                                        }
+                                    }
+                                    Some(Err(error)) => {
+                                        outcome = error;
+                                        break 'read;
+                                    }
+                                    None => {}
+                                }
+                            }
+                        }
+                        outcome
+                    }
+                    Err(e) => format!("Error making request: {}", e),
+                };
+
+                if let Some(mut target) = target {
+                    target.receive_llm_response(outcome);
+                }
+            });
+            self.request_task = Some(task.abort_handle());
+        }
+
+        // Stops the request in flight and tells the target it was cancelled; nothing in flight does nothing
+        pub fn cancel_request(&mut self) {
+            let Some(task) = self.request_task.take() else {
+                return;
+            };
+            if task.is_finished() {
+                return;
+            }
+            task.abort();
+            // Sent from the runtime like a reply, since the target is usually the caller and still locked
+            if let Some(mut target) = self.response_target.clone() {
+                ::hotline::hotline_runtime().spawn(async move {
+                    target.receive_llm_response("[cancelled]".to_string());
+                });
+            }
         }
 
         fn send_response(&mut self, response: String) {
@@ -136,3 +235,16 @@ hotline::object!({
         }
     }
 });
+
+// Reads one line of the server-sent event stream: the text of a content delta, or the message of an error event
+fn stream_event(line: &str) -> Option<Result<String, String>> {
+    let event: serde_json::Value = serde_json::from_str(line.strip_prefix("data:")?.trim_start()).ok()?;
+    match event["type"].as_str()? {
+        "content_block_delta" => event["delta"]["text"].as_str().map(|text| Ok(text.to_string())),
+        "error" => {
+            let message = event["error"]["message"].as_str().unwrap_or("Unknown error");
+            Some(Err(format!("API error: {}", message)))
+        }
+        _ => None,
+    }
+}
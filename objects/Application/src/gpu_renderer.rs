@@ -16,6 +16,12 @@ pub struct QuadVertex {
     pub color: [f32; 4],
 }
 
+// Quads drawn by a range of calls, so a throttled object's last frame can be drawn again
+#[derive(Clone, Default)]
+pub struct RecordedQuads {
+    batches: Vec<(u32, Option<[f32; 4]>, Vec<QuadVertex>)>,
+}
+
 // Wrapper to make GpuRenderer thread-safe for Hotline
 pub struct GpuRenderer {
     // Store as raw pointer to work around Send+Sync requirements
@@ -58,8 +64,26 @@ struct GpuRendererInner {
     quad_vertex_buffer: Buffer,
     quad_vertices: Vec<QuadVertex>,
     next_texture_id: u32,
-    // Track texture batches: texture_id -> (start_index, count)
-    texture_batches: Vec<(u32, usize, usize)>,
+    // LRU bookkeeping for textures we're allowed to evict (ids >= 2)
+    texture_bytes: HashMap<u32, usize>,
+    texture_last_used: HashMap<u32, u64>,
+    texture_budget_bytes: usize,
+    frame_index: u64,
+    clear_color: sdl3::pixels::Color,
+    // Track texture batches: texture_id -> (start_index, count, clip rect)
+    texture_batches: Vec<(u32, usize, usize, Option<[f32; 4]>)>,
+    clip_stack: Vec<[f32; 4]>, // x, y, w, h in the same units as vertex positions
+    pixel_scale: f32,          // Swapchain pixels per vertex unit as of the last frame, for edge feathering
+}
+
+impl GpuRendererInner {
+    // Grows the last batch when it shares the texture and clip, otherwise starts a new one
+    fn extend_batch(&mut self, tex_id: u32, clip: Option<[f32; 4]>, start_index: usize, count: usize) {
+        match self.texture_batches.last_mut() {
+            Some(batch) if batch.0 == tex_id && batch.3 == clip => batch.2 += count,
+            _ => self.texture_batches.push((tex_id, start_index, count, clip)),
+        }
+    }
 }
 
 impl ::hotline::GpuRenderingContext for GpuRenderer {
@@ -94,6 +118,30 @@ impl ::hotline::GpuRenderingContext for GpuRenderer {
     fn add_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: [f32; 4]) {
         self.add_line(x1, y1, x2, y2, thickness, color);
     }
+
+    fn add_circle(&mut self, cx: f32, cy: f32, radius: f32, color: [f32; 4]) {
+        self.add_circle(cx, cy, radius, color);
+    }
+
+    fn add_ring(&mut self, cx: f32, cy: f32, radius: f32, thickness: f32, color: [f32; 4]) {
+        self.add_ring(cx, cy, radius, thickness, color);
+    }
+
+    fn add_rounded_rect_corners(&mut self, x: f32, y: f32, w: f32, h: f32, radii: [f32; 4], color: [f32; 4]) {
+        self.add_rounded_rect_corners(x, y, w, h, radii, color);
+    }
+
+    fn has_texture(&self, tex_id: u32) -> bool {
+        self.has_texture(tex_id)
+    }
+
+    fn push_clip_rect(&mut self, x: f32, y: f32, w: f32, h: f32) {
+        self.push_clip_rect(x, y, w, h);
+    }
+
+    fn pop_clip_rect(&mut self) {
+        self.pop_clip_rect();
+    }
 }
 
 impl GpuRenderer {
@@ -376,7 +424,14 @@ impl GpuRenderer {
             quad_vertex_buffer,
             quad_vertices: Vec::new(),
             next_texture_id: 2, // Start at 2 since 0=white, 1=font atlas
+            texture_bytes: HashMap::new(),
+            texture_last_used: HashMap::new(),
+            texture_budget_bytes: usize::MAX,
+            frame_index: 0,
+            clear_color: sdl3::pixels::Color::RGBA(50, 50, 50, 255), // Dark gray with full alpha
             texture_batches: Vec::new(),
+            clip_stack: Vec::new(),
+            pixel_scale: 1.0,
         });
 
         Ok(Self { inner: Box::into_raw(inner) })
@@ -449,13 +504,162 @@ impl GpuRenderer {
         cmd.submit().map_err(|e| e.to_string())?;
 
         inner.textures.insert(id, texture);
+        // 4 bytes per pixel for every format we create
+        inner.texture_bytes.insert(id, width as usize * height as usize * 4);
+        inner.texture_last_used.insert(id, inner.frame_index);
+        Ok(id)
+    }
+
+    // Re-uploads an RGBA texture in place, or makes a new one when there is none yet or the size changed
+    pub fn upload_rgba_texture(
+        &mut self,
+        tex_id: Option<u32>,
+        data: &[u8],
+        width: u32,
+        height: u32,
+    ) -> Result<u32, String> {
+        let inner = self.inner_mut();
+        let reusable = tex_id.filter(|id| {
+            inner.textures.get(id).is_some_and(|texture| texture.width() == width && texture.height() == height)
+        });
+        let Some(id) = reusable else {
+            if let Some(id) = tex_id {
+                inner.textures.remove(&id);
+                inner.texture_bytes.remove(&id);
+                inner.texture_last_used.remove(&id);
+            }
+            return self.create_rgba_texture(data, width, height);
+        };
+
+        let cmd = inner.device.acquire_command_buffer().map_err(|e| e.to_string())?;
+        let mut map = inner.transfer_buffer.map::<u8>(&inner.device, true);
+        map.mem_mut()[..data.len()].copy_from_slice(data);
+        map.unmap();
+        let copy_pass = inner.device.begin_copy_pass(&cmd).map_err(|e| e.to_string())?;
+        copy_pass.upload_to_gpu_texture(
+            sdl3::gpu::TextureTransferInfo::new()
+                .with_transfer_buffer(&inner.transfer_buffer)
+                .with_offset(0)
+                .with_pixels_per_row(width),
+            sdl3::gpu::TextureRegion::new()
+                .with_texture(&inner.textures[&id])
+                .with_width(width)
+                .with_height(height)
+                .with_depth(1),
+            false,
+        );
+        inner.device.end_copy_pass(copy_pass);
+        cmd.submit().map_err(|e| e.to_string())?;
+        inner.texture_last_used.insert(id, inner.frame_index);
         Ok(id)
     }
 
+    pub fn has_texture(&self, tex_id: u32) -> bool {
+        self.inner().textures.contains_key(&tex_id)
+    }
+
+    // Vertices queued so far this frame, the start point for record_since
+    pub fn vertex_count(&self) -> usize {
+        self.inner().quad_vertices.len()
+    }
+
+    pub fn record_since(&self, start: usize) -> RecordedQuads {
+        let inner = self.inner();
+        let mut batches = Vec::new();
+        for &(tex_id, batch_start, count, clip) in &inner.texture_batches {
+            let from = batch_start.max(start);
+            if from < batch_start + count {
+                batches.push((tex_id, clip, inner.quad_vertices[from..batch_start + count].to_vec()));
+            }
+        }
+        RecordedQuads { batches }
+    }
+
+    pub fn replay(&mut self, quads: &RecordedQuads) {
+        for (tex_id, clip, vertices) in &quads.batches {
+            let inner = self.inner_mut();
+            if let Some(last_used) = inner.texture_last_used.get_mut(tex_id) {
+                *last_used = inner.frame_index;
+            }
+            let start_index = inner.quad_vertices.len();
+            inner.quad_vertices.extend_from_slice(vertices);
+            inner.extend_batch(*tex_id, *clip, start_index, vertices.len());
+        }
+    }
+
+    pub fn push_clip_rect(&mut self, x: f32, y: f32, w: f32, h: f32) {
+        let inner = self.inner_mut();
+        let (mut x0, mut y0, mut x1, mut y1) = (x, y, x + w, y + h);
+        if let Some(&[px, py, pw, ph]) = inner.clip_stack.last() {
+            x0 = x0.max(px);
+            y0 = y0.max(py);
+            x1 = x1.min(px + pw);
+            y1 = y1.min(py + ph);
+        }
+        inner.clip_stack.push([x0, y0, (x1 - x0).max(0.0), (y1 - y0).max(0.0)]);
+    }
+
+    pub fn pop_clip_rect(&mut self) {
+        if self.inner_mut().clip_stack.pop().is_none() {
+            hotline::debug_rate_limited!("gpu_clip_underflow", 1000, "WARNING: pop_clip_rect without a matching push");
+        }
+    }
+
+    pub fn set_texture_budget(&mut self, bytes: usize) {
+        self.inner_mut().texture_budget_bytes = bytes;
+    }
+
+    // A zero alpha lets a transparent window show the desktop through the background
+    pub fn set_clear_color(&mut self, color: sdl3::pixels::Color) {
+        self.inner_mut().clear_color = color;
+    }
+
+    pub fn texture_bytes(&self) -> usize {
+        self.inner().texture_bytes.values().sum()
+    }
+
+    // Drop least recently drawn textures until we're back under budget.
+    // Textures drawn in the previous frame are kept even if that exceeds the budget.
+    fn evict_textures(&mut self) {
+        let inner = self.inner_mut();
+        let mut total: usize = inner.texture_bytes.values().sum();
+        if total <= inner.texture_budget_bytes {
+            return;
+        }
+
+        let mut by_age: Vec<(u64, u32)> = inner.texture_last_used.iter().map(|(&id, &frame)| (frame, id)).collect();
+        by_age.sort_unstable();
+
+        let mut evicted = 0;
+        for (last_used, id) in by_age {
+            if total <= inner.texture_budget_bytes || last_used + 1 >= inner.frame_index {
+                break;
+            }
+            inner.textures.remove(&id);
+            inner.texture_last_used.remove(&id);
+            total -= inner.texture_bytes.remove(&id).unwrap_or(0);
+            evicted += 1;
+        }
+
+        if total > inner.texture_budget_bytes {
+            hotline::debug_rate_limited!(
+                "gpu_texture_budget",
+                1000,
+                "WARNING: {} bytes of textures in use last frame exceed budget of {} bytes (evicted {})",
+                total,
+                inner.texture_budget_bytes,
+                evicted
+            );
+        }
+    }
+
     pub fn begin_frame(&mut self) {
+        self.evict_textures();
         let inner = self.inner_mut();
+        inner.frame_index += 1;
         inner.quad_vertices.clear();
         inner.texture_batches.clear();
+        inner.clip_stack.clear();
     }
 
     pub fn add_textured_rect(&mut self, x: f32, y: f32, w: f32, h: f32, tex_id: u32, color: [f32; 4]) {
@@ -485,22 +689,15 @@ impl GpuRenderer {
         ];
 
         let inner = self.inner_mut();
+        if let Some(last_used) = inner.texture_last_used.get_mut(&tex_id) {
+            *last_used = inner.frame_index;
+        }
         let start_index = inner.quad_vertices.len();
         inner.quad_vertices.extend_from_slice(&vertices);
 
         // Update or create batch for this texture
-        if let Some(batch) = inner.texture_batches.last_mut() {
-            if batch.0 == tex_id {
-                // Add to existing batch
-                batch.2 += 6; // 6 vertices per quad
-            } else {
-                // Start new batch
-                inner.texture_batches.push((tex_id, start_index, 6));
-            }
-        } else {
-            // First batch
-            inner.texture_batches.push((tex_id, start_index, 6));
-        }
+        let clip = inner.clip_stack.last().copied();
+        inner.extend_batch(tex_id, clip, start_index, 6);
     }
 
     pub fn add_solid_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: [f32; 4]) {
@@ -545,15 +742,55 @@ impl GpuRenderer {
         inner.quad_vertices.extend_from_slice(&vertices);
 
         // Use white texture (ID 0) for lines
-        if let Some(batch) = inner.texture_batches.last_mut() {
-            if batch.0 == 0 {
-                batch.2 += 6;
-            } else {
-                inner.texture_batches.push((0, start_index, 6));
-            }
-        } else {
-            inner.texture_batches.push((0, start_index, 6));
+        let clip = inner.clip_stack.last().copied();
+        inner.extend_batch(0, clip, start_index, 6);
+    }
+
+    pub fn add_circle(&mut self, cx: f32, cy: f32, radius: f32, color: [f32; 4]) {
+        self.add_annulus(cx, cy, 0.0, radius, color);
+    }
+
+    pub fn add_ring(&mut self, cx: f32, cy: f32, radius: f32, thickness: f32, color: [f32; 4]) {
+        let half = thickness / 2.0;
+        self.add_annulus(cx, cy, (radius - half).max(0.0), radius + half, color);
+    }
+
+    pub fn add_rounded_rect(&mut self, x: f32, y: f32, w: f32, h: f32, radius: f32, color: [f32; 4]) {
+        self.add_rounded_rect_corners(x, y, w, h, [radius; 4], color);
+    }
+
+    pub fn add_rounded_rect_corners(&mut self, x: f32, y: f32, w: f32, h: f32, radii: [f32; 4], color: [f32; 4]) {
+        let triangles = hotline::rounded_rect_triangles(x, y, w, h, radii, self.feather());
+        self.add_coverage_triangles(triangles, color);
+    }
+
+    fn add_annulus(&mut self, cx: f32, cy: f32, inner_radius: f32, outer_radius: f32, color: [f32; 4]) {
+        let triangles = hotline::annulus_triangles(cx, cy, inner_radius, outer_radius, self.feather());
+        self.add_coverage_triangles(triangles, color);
+    }
+
+    // One swapchain pixel in vertex units, the width antialiased edges fade over
+    fn feather(&self) -> f32 {
+        1.0 / self.inner().pixel_scale
+    }
+
+    fn add_coverage_triangles(&mut self, triangles: Vec<([f32; 2], f32)>, color: [f32; 4]) {
+        if triangles.is_empty() {
+            return;
         }
+        let vertices = triangles.into_iter().map(|(pos, coverage)| QuadVertex {
+            pos,
+            tex_coord: [0.0, 0.0],
+            color: [color[0], color[1], color[2], color[3] * coverage],
+        });
+        let inner = self.inner_mut();
+        let start_index = inner.quad_vertices.len();
+        inner.quad_vertices.extend(vertices);
+        let count = inner.quad_vertices.len() - start_index;
+
+        // Untextured like lines, so shapes batch with solid rects
+        let clip = inner.clip_stack.last().copied();
+        inner.extend_batch(0, clip, start_index, count);
     }
 
     pub fn render_frame(&mut self, window: &sdl3::video::Window) -> Result<(), String> {
@@ -576,7 +813,7 @@ impl GpuRenderer {
                         .with_texture(&swapchain)
                         .with_load_op(LoadOp::Clear)
                         .with_store_op(StoreOp::Store)
-                        .with_clear_color(sdl3::pixels::Color::RGBA(50, 50, 50, 255))], // Dark gray with full alpha
+                        .with_clear_color(inner.clear_color)],
                     None,
                 )
                 .map_err(|e| e.to_string())?;
@@ -650,8 +887,14 @@ impl GpuRenderer {
                     (*cmd_ptr).push_vertex_uniform_data(0, &push_constants);
                 }
 
+                // Clip rects are in window units like the vertices; the scissor is in swapchain pixels
+                let (pixel_width, pixel_height) = window.size_in_pixels();
+                let scale_x = pixel_width as f32 / screen_width.max(1) as f32;
+                let scale_y = pixel_height as f32 / screen_height.max(1) as f32;
+                inner.pixel_scale = scale_x.max(1.0);
+
                 // Render each texture batch
-                for (batch_idx, &(tex_id, start_index, count)) in inner.texture_batches.iter().enumerate() {
+                for (batch_idx, &(tex_id, start_index, count, clip)) in inner.texture_batches.iter().enumerate() {
                     // Validate we're not drawing past our vertex data
                     if start_index + count > inner.quad_vertices.len() {
                         eprintln!(
@@ -673,6 +916,12 @@ impl GpuRenderer {
                                 inner.textures.keys().collect::<Vec<_>>()
                             );
                         }
+                        hotline::debug_rate_limited!(
+                            "gpu_missing_texture",
+                            1000,
+                            "WARNING: texture {} not found during render (evicted?), drawing white",
+                            tex_id
+                        );
                         inner.textures.get(&0).expect("White texture (ID 0) should always exist")
                     });
 
@@ -682,6 +931,19 @@ impl GpuRenderer {
                         &[TextureSamplerBinding::new().with_sampler(&inner.sampler).with_texture(texture)],
                     );
 
+                    let scissor = match clip {
+                        Some([x, y, w, h]) => {
+                            let (x0, y0) = ((x * scale_x).round().max(0.0), (y * scale_y).round().max(0.0));
+                            let (x1, y1) = (((x + w) * scale_x).round(), ((y + h) * scale_y).round());
+                            if x1 <= x0 || y1 <= y0 {
+                                continue; // Clipped away entirely; SDL rects can't be empty
+                            }
+                            sdl3::rect::Rect::new(x0 as i32, y0 as i32, (x1 - x0) as u32, (y1 - y0) as u32)
+                        }
+                        None => sdl3::rect::Rect::new(0, 0, pixel_width, pixel_height),
+                    };
+                    render_pass.set_scissor(scissor);
+
                     // Draw this batch
                     render_pass.draw_primitives(count, 1, start_index, 0);
                 }
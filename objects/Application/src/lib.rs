@@ -6,6 +6,7 @@ use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 pub mod gpu_renderer;
+mod settings;
 
 #[cfg(target_os = "linux")]
 use png::{BitDepth, ColorType, Encoder};
@@ -42,7 +43,16 @@ impl hotline::EventHandler for CodeEditorAdapter {
 
     fn handle_mouse_wheel(&mut self, _x: f64, _y: f64, delta: f64) -> bool {
         if self.editor.is_focused() {
-            self.editor.add_scroll_velocity(-delta * 20.0);
+            self.editor.scroll_wheel(delta);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn handle_mouse_wheel_x(&mut self, _x: f64, _y: f64, delta: f64) -> bool {
+        if self.editor.is_focused() {
+            self.editor.scroll_wheel_horizontal(delta);
             true
         } else {
             false
@@ -65,6 +75,21 @@ impl hotline::EventHandler for CodeEditorAdapter {
             return false;
         }
 
+        // A buffer that diverged from disk asks which side to keep before anything else
+        if self.editor.has_disk_conflict() {
+            match keycode {
+                13 => {
+                    self.editor.adopt_disk();
+                    return true;
+                }
+                27 => {
+                    self.editor.keep_buffer();
+                    return true;
+                }
+                _ => {}
+            }
+        }
+
         // Handle common keycodes directly
         match keycode {
             8 => {
@@ -77,6 +102,13 @@ impl hotline::EventHandler for CodeEditorAdapter {
                 self.editor.insert_newline();
                 true
             }
+            9 => {
+                // Tab indents
+                for _ in 0..4 {
+                    self.editor.insert_char(' ');
+                }
+                true
+            }
             1073741904 => {
                 // Left arrow
                 self.editor.move_cursor_left(shift);
@@ -102,15 +134,27 @@ impl hotline::EventHandler for CodeEditorAdapter {
     }
 
     fn is_focused(&self) -> bool {
-        // Can't call is_focused on editor because it requires mutable borrow
-        // TODO: Fix this by making is_focused() immutable in CodeEditor
-        false
+        self.editor.is_focused()
     }
 
-    fn update(&mut self) {
+    fn update(&mut self, _dt: f64) {
         self.editor.update_scroll();
     }
 
+    fn render_gpu(&mut self, gpu_renderer: &mut dyn hotline::GpuRenderingContext) {
+        // Text area, file name, find bar, timeline and file menu
+        self.editor.render_gpu(gpu_renderer);
+    }
+
+    // Its text only draws on the GPU; render adds just the focus highlight
+    fn supports_cpu_render(&self) -> bool {
+        false
+    }
+
+    fn supports_gpu_render(&self) -> bool {
+        true
+    }
+
     fn render(&mut self, buffer: &mut [u8], width: i64, height: i64, pitch: i64) {
         self.editor.render(buffer, width, height, pitch);
     }
@@ -174,6 +218,11 @@ impl hotline::EventHandler for ChatInterfaceAdapter {
                 self.chat.insert_char('\n');
                 true
             }
+            9 => {
+                // Tab moves between history and input
+                self.chat.focus_next_field();
+                true
+            }
             1073741904 => {
                 // Left arrow
                 self.chat.move_cursor_left();
@@ -188,23 +237,38 @@ impl hotline::EventHandler for ChatInterfaceAdapter {
         }
     }
 
-    fn update(&mut self) {
+    fn is_focused(&self) -> bool {
+        self.chat.is_focused()
+    }
+
+    fn update(&mut self, _dt: f64) {
         self.chat.update_scroll();
     }
 
+    fn render_gpu(&mut self, gpu_renderer: &mut dyn hotline::GpuRenderingContext) {
+        // Text areas, overlays and the model picker
+        self.chat.render_gpu(gpu_renderer);
+    }
+
+    // ChatInterface::render draws nothing
+    fn supports_cpu_render(&self) -> bool {
+        false
+    }
+
+    fn supports_gpu_render(&self) -> bool {
+        true
+    }
+
+    fn handle(&self) -> Option<hotline::ObjectHandle> {
+        Some(self.chat.handle().clone())
+    }
+
     fn render(&mut self, buffer: &mut [u8], width: i64, height: i64, pitch: i64) {
         // Re-enable CPU rendering
         self.chat.render(buffer, width, height, pitch);
     }
 }
 
-impl ChatInterfaceAdapter {
-    // TODO: Update to use new GPU API
-    // fn render_gpu(&mut self, gpu_renderer: &mut GpuRenderer) {
-    //     self.chat.generate_commands(gpu_renderer);
-    // }
-}
-
 impl StarfieldAdapter {
     fn new(starfield: Starfield) -> Self {
         Self { starfield }
@@ -212,6 +276,10 @@ impl StarfieldAdapter {
 }
 
 impl hotline::EventHandler for StarfieldAdapter {
+    fn hit_test(&self, x: f64, y: f64) -> bool {
+        self.starfield.hit_test(x, y)
+    }
+
     fn handle_mouse_down(&mut self, x: f64, y: f64) -> bool {
         self.starfield.handle_mouse_down(x, y)
     }
@@ -224,8 +292,29 @@ impl hotline::EventHandler for StarfieldAdapter {
         self.starfield.handle_mouse_move(x, y)
     }
 
-    fn update(&mut self) {
-        // Starfield updates itself in generate_commands
+    fn update(&mut self, dt: f64) {
+        self.starfield.update(dt);
+    }
+
+    fn render_gpu(&mut self, gpu_renderer: &mut dyn hotline::GpuRenderingContext) {
+        let start = std::time::Instant::now();
+        self.starfield.render_gpu(gpu_renderer);
+        let elapsed = start.elapsed();
+        if elapsed.as_millis() > 16 {
+            eprintln!("WARNING: Starfield::render_gpu took {}ms (>16ms frame budget)", elapsed.as_millis());
+        }
+    }
+
+    fn supports_cpu_render(&self) -> bool {
+        false
+    }
+
+    fn supports_gpu_render(&self) -> bool {
+        true
+    }
+
+    fn handle(&self) -> Option<hotline::ObjectHandle> {
+        Some(self.starfield.handle().clone())
     }
 
     fn render(&mut self, _buffer: &mut [u8], _width: i64, _height: i64, _pitch: i64) {
@@ -233,15 +322,253 @@ impl hotline::EventHandler for StarfieldAdapter {
     }
 }
 
+// Runs an object's per-frame work at its own interval, replaying its last drawn quads in between
+#[derive(Default, Clone)]
+struct Throttle {
+    interval: f64,
+    pending: f64,
+    quads: Option<gpu_renderer::RecordedQuads>,
+}
+
+impl Throttle {
+    // Accumulates frame time; once the interval has passed, returns all of it and marks a redraw
+    fn tick(&mut self, dt: f64, interval: f64) -> Option<f64> {
+        self.interval = interval;
+        self.pending += dt;
+        if interval > 0.0 && self.pending < interval && self.quads.is_some() {
+            return None;
+        }
+        self.quads = None;
+        Some(std::mem::take(&mut self.pending))
+    }
+
+    fn render(&mut self, gpu: &mut gpu_renderer::GpuRenderer, draw: impl FnOnce(&mut gpu_renderer::GpuRenderer)) {
+        if self.interval <= 0.0 {
+            draw(gpu);
+        } else if let Some(ref quads) = self.quads {
+            gpu.replay(quads);
+        } else {
+            let start = gpu.vertex_count();
+            draw(gpu);
+            self.quads = Some(gpu.record_since(start));
+        }
+    }
+}
+
+// Turns controller buttons and the left stick into key presses for the focused editor, repeating while held
+#[derive(Default, Clone)]
+struct ControllerKeys {
+    bindings: Vec<(u8, i32)>, // (gamepad button, keycode)
+    held: Option<(i32, f64)>, // Key being held and seconds until it next repeats
+}
+
+impl ControllerKeys {
+    const REPEAT_DELAY: f64 = 0.4;
+    const REPEAT_INTERVAL: f64 = 0.08;
+    const STICK_THRESHOLD: f32 = 0.5;
+    // D-pad arrows, A for newline, B to delete, X to indent, left shoulder held for selecting
+    const DEFAULTS: &str =
+        "dpup=Up,dpdown=Down,dpleft=Left,dpright=Right,a=Return,b=Backspace,x=Tab,leftshoulder=Left Shift";
+
+    // Comma-separated button=key pairs of SDL names, e.g. "dpup=Up,a=Return"
+    fn parse(spec: &str) -> Result<Self, String> {
+        let mut bindings = Vec::new();
+        for pair in spec.split(',').map(str::trim).filter(|pair| !pair.is_empty()) {
+            let (button, key) = pair.split_once('=').ok_or_else(|| format!("'{}' is not button=key", pair))?;
+            let (button, key) = (button.trim(), key.trim());
+            let button = sdl3::gamepad::Button::from_string(button)
+                .ok_or_else(|| format!("'{}' is not an SDL gamepad button name", button))?;
+            let key = Keycode::from_name(key).ok_or_else(|| format!("'{}' is not an SDL key name", key))?;
+            bindings.push((button as u8, key as i32));
+        }
+        Ok(Self { bindings, held: None })
+    }
+
+    fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    // Buttons bound to a shift key act as the modifier rather than being pressed
+    fn is_shift(key: i32) -> bool {
+        key == Keycode::LShift as i32 || key == Keycode::RShift as i32
+    }
+
+    fn key_for(&self, button: u8) -> Option<i32> {
+        self.bindings.iter().find(|&&(b, key)| b == button && !Self::is_shift(key)).map(|&(_, key)| key)
+    }
+
+    fn shift(&self, gc: &GameController) -> bool {
+        self.bindings.iter().any(|&(button, key)| Self::is_shift(key) && gc.button_pressed(button))
+    }
+
+    // A held bound button, else the left stick as arrow keys
+    fn held_key(&self, gc: &GameController) -> Option<i32> {
+        let button = self.bindings.iter().find(|&&(button, key)| !Self::is_shift(key) && gc.button_pressed(button));
+        if let Some(&(_, key)) = button {
+            return Some(key);
+        }
+        let (lx, ly, _, _) = gc.axis_values();
+        if lx.abs().max(ly.abs()) < Self::STICK_THRESHOLD {
+            return None;
+        }
+        let key = match (lx.abs() > ly.abs(), lx > 0.0, ly > 0.0) {
+            (true, true, _) => Keycode::Right,
+            (true, false, _) => Keycode::Left,
+            (false, _, true) => Keycode::Down,
+            (false, _, false) => Keycode::Up,
+        };
+        Some(key as i32)
+    }
+
+    // A button press is dispatched from its event; ticks then only repeat it
+    fn press(&mut self, key: i32) {
+        self.held = Some((key, Self::REPEAT_DELAY));
+    }
+
+    // The key due this frame: a new stick direction at once, then repeats after the delay
+    fn tick(&mut self, key: Option<i32>, dt: f64) -> Option<i32> {
+        match (self.held.as_mut(), key) {
+            (Some((held, wait)), Some(key)) if *held == key => {
+                *wait -= dt;
+                if *wait > 0.0 {
+                    return None;
+                }
+                *wait = (*wait + Self::REPEAT_INTERVAL).max(0.0);
+                Some(key)
+            }
+            (_, Some(key)) => {
+                self.press(key);
+                Some(key)
+            }
+            (_, None) => {
+                self.held = None;
+                None
+            }
+        }
+    }
+}
+
+// Objects `initialize` creates, each optionally placed as name@WIDTHxHEIGHT+X+Y; either half of
+// the geometry can be left out to keep the built-in layout, and the starfield always fills the window
+#[derive(Default, Clone)]
+struct StartupSet {
+    entries: Vec<(String, Option<(f64, f64)>, Option<(f64, f64)>)>, // (name, size, position)
+}
+
+impl StartupSet {
+    const NAMES: [&str; 7] = ["editor", "chat", "color_wheel", "autonomy", "render_times", "controller", "starfield"];
+    const DEFAULTS: &str = "editor,chat,color_wheel,autonomy,render_times,controller,starfield";
+
+    fn parse(spec: &str) -> Result<Self, String> {
+        let mut entries = Vec::new();
+        for entry in spec.split(',').map(str::trim).filter(|entry| !entry.is_empty()) {
+            let (name, geometry) = match entry.split_once('@') {
+                Some((name, geometry)) => (name.trim(), Some(geometry.trim())),
+                None => (entry, None),
+            };
+            if !Self::NAMES.contains(&name) {
+                return Err(format!("unknown object '{}', expected one of {}", name, Self::NAMES.join(", ")));
+            }
+            let invalid = || format!("'{}' is not name@WIDTHxHEIGHT+X+Y", entry);
+            let (mut size, mut position) = (None, None);
+            if let Some(geometry) = geometry {
+                let (size_part, position_part) = match geometry.split_once('+') {
+                    Some((size_part, position_part)) => (size_part, Some(position_part)),
+                    None => (geometry, None),
+                };
+                if !size_part.is_empty() {
+                    let (w, h) = size_part.split_once('x').ok_or_else(invalid)?;
+                    size = Some((w.parse().map_err(|_| invalid())?, h.parse().map_err(|_| invalid())?));
+                }
+                if let Some(position_part) = position_part {
+                    let (x, y) = position_part.split_once('+').ok_or_else(invalid)?;
+                    position = Some((x.parse().map_err(|_| invalid())?, y.parse().map_err(|_| invalid())?));
+                }
+            }
+            entries.push((name.to_string(), size, position));
+        }
+        Ok(Self { entries })
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.entries.iter().any(|(entry, _, _)| entry == name)
+    }
+
+    // Starting (x, y, w, h) of `name` over its built-in `default`, or None when it isn't listed
+    fn bounds(&self, name: &str, default: (f64, f64, f64, f64)) -> Option<(f64, f64, f64, f64)> {
+        let (_, size, position) = self.entries.iter().find(|(entry, _, _)| entry == name)?;
+        let (x, y) = position.unwrap_or((default.0, default.1));
+        let (w, h) = size.unwrap_or((default.2, default.3));
+        Some((x, y, w, h))
+    }
+}
+
+// Catches panics from individual objects' per-frame calls so the rest of the app keeps running.
+// An object that keeps panicking is disabled rather than retried every frame.
+#[derive(Default, Clone)]
+struct PanicGuard {
+    panics: std::collections::HashMap<String, u32>,
+    disabled: std::collections::HashSet<String>,
+    last_error: Option<String>,
+}
+
+impl PanicGuard {
+    const MAX_PANICS: u32 = 3;
+
+    // `handle` is the object's lock, which the panic poisons; clearing it keeps the object's
+    // other methods callable so only the failing call is lost
+    fn run<R>(&mut self, name: &str, handle: Option<hotline::ObjectHandle>, f: impl FnOnce() -> R) -> Option<R> {
+        if self.disabled.contains(name) {
+            return None;
+        }
+        match std::panic::catch_unwind(std::panic::AssertUnwindSafe(f)) {
+            Ok(result) => Some(result),
+            Err(payload) => {
+                if let Some(handle) = handle {
+                    handle.clear_poison();
+                }
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(|s| s.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "non-string panic payload".to_string());
+                let count = self.panics.entry(name.to_string()).or_insert(0);
+                *count += 1;
+                let report = if *count >= Self::MAX_PANICS {
+                    self.disabled.insert(name.to_string());
+                    format!("{} disabled after {} panics: {}", name, count, message)
+                } else {
+                    format!("{} panicked: {}", name, message)
+                };
+                eprintln!("ERROR: {}", report);
+                self.last_error = Some(report);
+                None
+            }
+        }
+    }
+
+    fn take_error(&mut self) -> Option<String> {
+        self.last_error.take()
+    }
+}
+
 hotline::object!({
     pub struct Application {
         window_manager: Option<WindowManager>,
         #[serde(skip)]
         event_handlers: Vec<Box<dyn hotline::EventHandler>>,
+        // Indices into event_handlers: input is offered along input_order until a handler consumes it,
+        // and render_order draws back to front. add_event_handler keeps both sorted.
         #[serde(skip)]
-        gpu_renderer: Option<gpu_renderer::GpuRenderer>,
+        handler_layers: Vec<(i32, i32)>, // (input priority, render layer) of each handler
+        #[serde(skip)]
+        input_order: Vec<usize>,
         #[serde(skip)]
-        gpu_texture_cache: std::collections::HashMap<u32, Vec<u8>>,
+        render_order: Vec<usize>,
+        #[serde(skip)]
+        handler_throttles: Vec<crate::Throttle>,
+        #[serde(skip)]
+        gpu_renderer: Option<gpu_renderer::GpuRenderer>,
         fps_counter: Option<TextRenderer>,
         autonomy_checkbox: Option<Checkbox>,
         render_time_checkbox: Option<Checkbox>,
@@ -251,6 +578,8 @@ hotline::object!({
         frame_times: std::collections::VecDeque<std::time::Instant>,
         #[serde(skip)]
         last_fps_update: Option<std::time::Instant>,
+        #[serde(skip)]
+        last_frame: Option<std::time::Instant>,
         current_fps: f64,
         #[serde(skip)]
         last_gpu_print: Option<std::time::Instant>,
@@ -258,14 +587,39 @@ hotline::object!({
         mouse_y: f64,
         #[default(2)]
         pixel_multiple: u32,
+        settings: crate::settings::Settings, // Startup settings, overridable from the environment
+        #[serde(skip)]
+        cpu_frame: Vec<u8>, // Software layer for objects drawn through render rather than render_gpu
+        cpu_frame_texture: Option<u32>,
+        fly_mode: bool, // WASD and Q/E fly the camera; text input and UI shortcuts are held back
+        input_mode_display: Option<TextRenderer>,
+        #[serde(skip)]
+        controller_key_map: crate::ControllerKeys,
+        #[serde(skip)]
+        last_mouse_motion: Option<std::time::Instant>,
+        #[serde(skip)]
+        last_activity: Option<std::time::Instant>,
+        #[serde(skip)]
+        seen_reloads: u64,
         width: u32,
         height: u32,
         zoom_display: Option<TextRenderer>,
         #[serde(skip)]
         zoom_display_until: Option<std::time::Instant>,
+        tooltip: Option<Tooltip>, // Describes the checkbox under the pointer
+        status_banner: Option<TextRenderer>,
+        #[serde(skip)]
+        status_banner_until: Option<std::time::Instant>,
+        #[serde(skip)]
+        panic_guard: crate::PanicGuard, // Qualified so the macro doesn't treat it as an object
         game_controller: Option<GameController>,
+        #[serde(skip)]
+        controller_throttle: crate::Throttle,
         starfield: Option<Starfield>,
         chat_interface: Option<ChatInterface>,
+        code_editor: Option<CodeEditor>,
+        field_inspector: Option<FieldInspector>,
+        inspecting_selection: bool, // Field inspector shows the scene selection rather than the Starfield
         white_pixel_atlas_id: Option<u32>,
     }
 
@@ -281,6 +635,154 @@ hotline::object!({
             (adj_x, adj_y)
         }
 
+        pub fn set_window_size(&mut self, width: u32, height: u32) {
+            assert!(width > 0 && height > 0, "window size must be non-zero");
+            self.settings.window_geometry.size = Some((width, height));
+        }
+
+        pub fn set_window_position(&mut self, x: i32, y: i32) {
+            self.settings.window_geometry.position = Some((x, y));
+        }
+
+        pub fn set_startup_objects(&mut self, startup_objects: String) {
+            self.settings.startup_objects = startup_objects;
+        }
+
+        // Cmd+I: edit the selected shape's fields, or the Starfield's when nothing is selected
+        fn toggle_field_inspector(&mut self) {
+            if self.field_inspector.as_ref().is_some_and(|inspector| inspector.is_visible()) {
+                if let Some(ref mut inspector) = self.field_inspector {
+                    inspector.close();
+                }
+                return;
+            }
+            let selection = self.window_manager.as_mut().and_then(|wm| wm.selected_type_name());
+            self.inspecting_selection = selection.is_some();
+            let title = selection.unwrap_or_else(|| "Starfield".to_string());
+            let fields = self.inspected_fields().unwrap_or_default();
+            if let Some(ref mut inspector) = self.field_inspector {
+                inspector.open(title, fields);
+            }
+        }
+
+        // Higher input priorities get input first and higher render layers draw on top; ties keep
+        // registration order
+        fn add_event_handler(
+            &mut self,
+            handler: Box<dyn hotline::EventHandler>,
+            input_priority: i32,
+            render_layer: i32,
+        ) {
+            self.event_handlers.push(handler);
+            if self.settings.max_event_handlers > 0 && self.event_handlers.len() == self.settings.max_event_handlers + 1
+            {
+                eprintln!(
+                    "WARNING: {} event handlers, over the soft limit of {}; is something adding them in a loop?",
+                    self.event_handlers.len(),
+                    self.settings.max_event_handlers
+                );
+            }
+            self.handler_layers.push((input_priority, render_layer));
+            self.handler_throttles.push(Default::default());
+            let layers = &self.handler_layers;
+            self.input_order = (0..layers.len()).collect();
+            self.input_order.sort_by_key(|&i| std::cmp::Reverse(layers[i].0));
+            self.render_order = (0..layers.len()).collect();
+            self.render_order.sort_by_key(|&i| layers[i].1);
+        }
+
+        fn inspected_fields(&mut self) -> Option<Vec<::hotline::FieldDescriptor>> {
+            if self.inspecting_selection {
+                self.window_manager.as_mut().and_then(|wm| wm.selected_fields())
+            } else {
+                self.starfield.as_ref().map(|sf| sf.fields())
+            }
+        }
+
+        // A rejected edit leaves the target as it was and says why in the status banner
+        fn apply_field_edit(&mut self, name: String, value: ::hotline::FieldValue) {
+            let result = if self.inspecting_selection {
+                self.window_manager
+                    .as_mut()
+                    .ok_or("no window manager".to_string())
+                    .and_then(|wm| wm.set_selected_field(name, value))
+            } else {
+                self.starfield.as_mut().ok_or("no starfield".to_string()).and_then(|sf| sf.set_field(&name, value))
+            };
+            if let Err(e) = result {
+                self.show_status(format!("Edit rejected: {}", e));
+            }
+        }
+
+        // F12: log every loaded hotline symbol with the rustc commit it was built against, flagging any that
+        // differ from the runtime's, and summarize in the status banner
+        fn dump_symbols(&mut self) {
+            let Some(registry) = self.get_registry() else {
+                return;
+            };
+            let summary = match registry.exported_symbols() {
+                Ok(table) => {
+                    let mut mismatched = 0;
+                    for (lib_name, symbol, commit) in &table {
+                        let matches = commit == ::hotline::RUSTC_COMMIT;
+                        mismatched += usize::from(!matches);
+                        eprintln!("{:<20} {} {}{}", lib_name, commit, symbol, if matches { "" } else { "  MISMATCH" });
+                    }
+                    let libraries =
+                        table.iter().map(|(lib_name, _, _)| lib_name).collect::<std::collections::HashSet<_>>();
+                    format!(
+                        "{} symbols in {} libraries, {} not on runtime commit {}",
+                        table.len(),
+                        libraries.len(),
+                        mismatched,
+                        ::hotline::RUSTC_COMMIT
+                    )
+                }
+                Err(e) => format!("Failed to read loaded symbols: {}", e),
+            };
+            self.show_status(summary);
+        }
+
+        // Controller buttons stand in for keys only while the editor has focus
+        fn controller_editing(&self) -> bool {
+            !self.controller_key_map.is_empty() && self.code_editor.as_ref().is_some_and(|e| e.is_focused())
+        }
+
+        // Offers a key press to the event handlers in input order until one consumes it
+        fn dispatch_key_down(&mut self, keycode: i32, shift: bool) {
+            for &i in &self.input_order {
+                let handler = &mut self.event_handlers[i];
+                if handler.handle_key_down(keycode, shift) {
+                    break;
+                }
+            }
+        }
+
+        // Shares the theme with every object and repaints the window background to match
+        fn apply_theme(&mut self, theme: ::hotline::Theme) {
+            self.settings.theme = theme;
+            if let Some(registry) = self.get_registry() {
+                registry.set_theme(theme);
+            }
+            if let Some(ref mut gpu) = self.gpu_renderer {
+                let [r, g, b, a] = theme.background.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8);
+                let color = if self.settings.overlay {
+                    sdl3::pixels::Color::RGBA(0, 0, 0, 0)
+                } else {
+                    sdl3::pixels::Color::RGBA(r, g, b, a)
+                };
+                gpu.set_clear_color(color);
+            }
+        }
+
+        fn show_status(&mut self, message: String) {
+            eprintln!("{}", message);
+            if let Some(ref mut banner) = self.status_banner {
+                banner.set_text(message);
+            }
+            self.status_banner_until = Some(Instant::now() + Duration::from_secs(5));
+        }
+
         pub fn initialize(&mut self) -> Result<(), String> {
             // Set up thread-local registry for proxy object creation
             // The runtime should have already loaded all libraries
@@ -290,6 +792,23 @@ hotline::object!({
                 return Err("Application registry not available during initialize".into());
             }
 
+            // Environment overrides, validated before anything is created
+            self.settings.apply_env()?;
+
+            if let Some(registry) = self.get_registry() {
+                registry.set_theme(self.settings.theme);
+            }
+
+            if let Some(registry) = self.get_registry() {
+                registry.set_precision(self.settings.precision);
+            }
+
+            self.controller_key_map = crate::ControllerKeys::parse(&self.settings.controller_keys)
+                .map_err(|e| format!("invalid HOTLINE_CONTROLLER_KEYS '{}': {}", self.settings.controller_keys, e))?;
+
+            let startup = crate::StartupSet::parse(&self.settings.startup_objects)
+                .map_err(|e| format!("invalid HOTLINE_STARTUP '{}': {}", self.settings.startup_objects, e))?;
+
             // Create GPU renderer
             // GPU renderer will be initialized when window is available
 
@@ -304,6 +823,8 @@ hotline::object!({
             self.window_manager = Some(WindowManager::new());
             if let Some(ref mut wm) = self.window_manager {
                 wm.initialize();
+                wm.set_snap_grid(self.settings.snap_grid);
+                wm.set_max_shapes(self.settings.max_shapes);
 
                 // Set up GPU rendering
                 if let Some(ref mut _gpu) = self.gpu_renderer {
@@ -313,95 +834,114 @@ hotline::object!({
             }
 
             // Create code editor
-            let mut editor = CodeEditor::new();
-            let _ = editor.open("objects/Rect/src/lib.rs");
+            if let Some((x, y, w, h)) = startup.bounds("editor", (400.0, 50.0, 380.0, 500.0)) {
+                let mut editor = CodeEditor::new();
+                let _ = editor.open("objects/Rect/src/lib.rs");
+                editor.set_double_click_ms(self.settings.double_click_ms);
+                editor.set_scroll_multiplier(self.settings.scroll_multiplier);
+                editor.set_scroll_acceleration(self.settings.scroll_acceleration);
+                editor.set_autosave_on_reload(self.settings.editor_autosave_on_reload);
+                editor.set_highlight_selected_word(self.settings.editor_highlight_word);
+                editor.set_max_undo_depth(self.settings.editor_undo_depth);
+
+                // Create rect for editor
+                let editor_rect = Rect::new();
+                let mut editor_rect_ref = editor_rect.clone();
+                editor_rect_ref.initialize(x, y, w, h);
+
+                if let Some(ref mut wm) = self.window_manager {
+                    wm.add_rect(editor_rect.clone());
+                }
+                editor.set_rect(editor_rect);
 
-            // Create rect for editor
-            let editor_rect = Rect::new();
-            let mut editor_rect_ref = editor_rect.clone();
-            editor_rect_ref.initialize(400.0, 50.0, 380.0, 500.0);
+                // Store editor handle for app-level shortcuts
+                self.code_editor = Some(editor.clone());
 
-            if let Some(ref mut wm) = self.window_manager {
-                wm.add_rect(editor_rect.clone());
+                // Add editor as event handler
+                self.add_event_handler(Box::new(CodeEditorAdapter::new(editor)), 0, 1);
             }
-            editor.set_rect(editor_rect);
-
-            // Add editor as event handler
-            self.event_handlers.push(Box::new(CodeEditorAdapter::new(editor)));
 
             // Create color wheel
-            self.color_wheel = Some(ColorWheel::new());
-            if let Some(ref mut wheel) = self.color_wheel {
-                let rect = Rect::new();
-                let mut r_ref = rect.clone();
-                r_ref.initialize(50.0, 400.0, 120.0, 120.0);
-                wheel.set_rect(rect);
+            if let Some((x, y, w, h)) = startup.bounds("color_wheel", (50.0, 400.0, 120.0, 120.0)) {
+                self.color_wheel = Some(ColorWheel::new());
+                if let Some(ref mut wheel) = self.color_wheel {
+                    let rect = Rect::new();
+                    let mut r_ref = rect.clone();
+                    r_ref.initialize(x, y, w, h);
+                    wheel.set_rect(rect);
+                }
             }
 
             // Create chat interface with AnthropicClient
-            let mut chat = ChatInterface::new();
-            chat.initialize();
-
-            // Pass shared white atlas to chat
-            if let Some(atlas_id) = self.white_pixel_atlas_id {
-                chat.set_shared_white_atlas(atlas_id);
-            }
-
-            // Set bounds for chat interface
-            let chat_rect = Rect::new();
-            let mut chat_rect_ref = chat_rect.clone();
-            chat_rect_ref.initialize(800.0, 50.0, 500.0, 700.0);
-            chat.set_rect(chat_rect);
+            if let Some((x, y, w, h)) = startup.bounds("chat", (800.0, 50.0, 500.0, 700.0)) {
+                let mut chat = ChatInterface::new();
+                chat.initialize();
+                chat.set_double_click_ms(self.settings.double_click_ms);
+                chat.set_max_conversation_len(self.settings.chat_max_len);
+                chat.set_min_send_interval_ms(self.settings.chat_send_interval_ms);
+
+                // Pass shared white atlas to chat
+                if let Some(atlas_id) = self.white_pixel_atlas_id {
+                    chat.set_shared_white_atlas(atlas_id);
+                }
 
-            // Create and connect AnthropicClient
-            let mut client = AnthropicClient::new();
-            client.initialize();
-            client.set_response_target(&chat);
+                // Set bounds for chat interface
+                let chat_rect = Rect::new();
+                let mut chat_rect_ref = chat_rect.clone();
+                chat_rect_ref.initialize(x, y, w, h);
+                chat.set_rect(chat_rect);
 
-            // Connect client to chat
-            chat.set_anthropic_client(&client);
+                // Create and connect AnthropicClient
+                let mut client = AnthropicClient::new();
+                client.initialize();
+                client.set_response_target(&chat);
 
-            // Store anthropic client for later use
-            self.anthropic_client = Some(client);
+                // Connect client to chat
+                chat.set_anthropic_client(&client);
 
-            // Set up GPU rendering for chat - DISABLED
-            // if let Some(ref mut gpu) = self.gpu_renderer {
-            //     chat.register_atlases(gpu);
-            // }
+                // Store anthropic client for later use
+                self.anthropic_client = Some(client);
 
-            // Store chat interface
-            self.chat_interface = Some(chat.clone());
+                // Store chat interface
+                self.chat_interface = Some(chat.clone());
 
-            // Add chat as event handler
-            self.event_handlers.push(Box::new(ChatInterfaceAdapter::new(chat)));
+                // Add chat as event handler
+                self.add_event_handler(Box::new(ChatInterfaceAdapter::new(chat)), 0, 1);
+            }
 
             // Create autonomy checkbox
-            self.autonomy_checkbox = Some(Checkbox::new());
-            if let Some(ref mut cb) = self.autonomy_checkbox {
-                let rect = Rect::new();
-                let mut r_ref = rect.clone();
-                r_ref.initialize(20.0, 60.0, 20.0, 20.0);
-                cb.set_rect(rect);
-                cb.set_label("Autonomy".to_string());
+            if let Some((x, y, w, h)) = startup.bounds("autonomy", (20.0, 60.0, 20.0, 20.0)) {
+                self.autonomy_checkbox = Some(Checkbox::new());
+                if let Some(ref mut cb) = self.autonomy_checkbox {
+                    let rect = Rect::new();
+                    let mut r_ref = rect.clone();
+                    r_ref.initialize(x, y, w, h);
+                    cb.set_rect(rect);
+                    cb.set_label("Autonomy".to_string());
+                }
             }
 
             // Create render time checkbox
-            self.render_time_checkbox = Some(Checkbox::new());
-            if let Some(ref mut cb) = self.render_time_checkbox {
-                let rect = Rect::new();
-                let mut r_ref = rect.clone();
-                r_ref.initialize(20.0, 90.0, 20.0, 20.0);
-                cb.set_rect(rect);
-                cb.set_label("Render Times".to_string());
+            if let Some((x, y, w, h)) = startup.bounds("render_times", (20.0, 90.0, 20.0, 20.0)) {
+                self.render_time_checkbox = Some(Checkbox::new());
+                if let Some(ref mut cb) = self.render_time_checkbox {
+                    let rect = Rect::new();
+                    let mut r_ref = rect.clone();
+                    r_ref.initialize(x, y, w, h);
+                    cb.set_rect(rect);
+                    cb.set_label("Render Times".to_string());
+                }
             }
 
+            self.tooltip = Some(Tooltip::new());
+
             // Create FPS counter
             self.fps_counter = Some(TextRenderer::new());
             if let Some(ref mut fps) = self.fps_counter {
                 fps.initialize();
                 fps.set_x(10.0);
                 fps.set_y(10.0);
-                fps.set_color((0, 255, 0, 255)); // Green color (tuple order is B,G,R,A)
+                fps.set_color((0, 255, 0, 255)); // Green
                 fps.set_text("FPS: 0".to_string());
 
                 // Register GPU atlas for FPS counter
@@ -416,10 +956,30 @@ hotline::object!({
             if let Some(ref mut zoom) = self.zoom_display {
                 zoom.set_x(10.0);
                 zoom.set_y(30.0);
-                zoom.set_color((255, 255, 255, 255)); // White in ABGR
+                zoom.set_color((255, 255, 255, 255)); // White
                 zoom.set_text("2x".to_string());
             }
 
+            // Status banner for reporting object errors
+            self.status_banner = Some(TextRenderer::new());
+            if let Some(ref mut banner) = self.status_banner {
+                banner.initialize();
+                banner.set_x(10.0);
+                banner.set_y(50.0);
+                banner.set_color((255, 0, 0, 255)); // Red
+            }
+
+            // Input mode indicator, shown while the keyboard flies the starfield
+            self.input_mode_display = Some(TextRenderer::new());
+            if let Some(ref mut mode) = self.input_mode_display {
+                mode.set_x(10.0);
+                mode.set_y(70.0);
+                mode.set_color((255, 220, 0, 255)); // Yellow
+                mode.set_text(format!("FLYING - {} returns to UI", self.settings.input_mode_key));
+            }
+
+            self.field_inspector = Some(FieldInspector::new());
+
             // Initialize FPS tracking
             self.frame_times = std::collections::VecDeque::with_capacity(120);
             self.last_fps_update = Some(std::time::Instant::now());
@@ -427,39 +987,60 @@ hotline::object!({
             self.last_gpu_print = None;
 
             // Create game controller display
-            self.game_controller = Some(GameController::new());
-            if let Some(ref mut gc) = self.game_controller {
-                gc.initialize();
-                let rect = Rect::new();
-                let mut r_ref = rect.clone();
-                r_ref.initialize(200.0, 400.0, 200.0, 370.0);
-                gc.set_rect(r_ref);
-
-                // Set up GPU rendering
-                if let Some(ref mut gpu) = self.gpu_renderer {
-                    gc.setup_gpu_rendering(gpu);
+            if let Some((x, y, w, h)) = startup.bounds("controller", (200.0, 400.0, 200.0, 370.0)) {
+                self.game_controller = Some(GameController::new());
+                if let Some(ref mut gc) = self.game_controller {
+                    gc.initialize();
+                    if self.settings.controller_hz > 0.0 {
+                        gc.set_update_interval(1.0 / self.settings.controller_hz);
+                    }
+                    gc.set_clip_to_bounds(self.settings.clip_to_bounds);
+                    let rect = Rect::new();
+                    let mut r_ref = rect.clone();
+                    r_ref.initialize(x, y, w, h);
+                    gc.set_rect(r_ref);
+
+                    // Set up GPU rendering
+                    if let Some(ref mut gpu) = self.gpu_renderer {
+                        gc.setup_gpu_rendering(gpu);
+                    }
                 }
             }
 
             // Create starfield (will be sized to full window in run())
-            let mut starfield = Starfield::new();
-            starfield.initialize();
-            // Don't set rect here - will be set to full window size in run()
+            if startup.contains("starfield") {
+                let mut starfield = Starfield::new();
+                starfield.initialize();
+                starfield.set_bloom_enabled(self.settings.star_bloom);
+                starfield.set_transparent_background(self.settings.overlay);
+                starfield.set_max_poster_file_bytes(self.settings.poster_max_file_kb * 1024);
+                starfield.set_poster_signatures(self.settings.poster_signatures);
+                starfield.set_roll_speed(self.settings.roll_speed);
+                starfield.set_text_gamma(self.settings.text_gamma);
+                starfield.set_max_star_spawns_per_frame(self.settings.star_spawn_budget);
+                starfield.set_clip_to_bounds(self.settings.clip_to_bounds);
+                // Don't set rect here - will be set to full window size in run()
 
-            // Set up GPU rendering
-            if let Some(ref mut gpu) = self.gpu_renderer {
-                starfield.setup_gpu_rendering(gpu);
-            }
+                // Set up GPU rendering
+                if let Some(ref mut gpu) = self.gpu_renderer {
+                    starfield.setup_gpu_rendering(gpu);
+                }
 
-            // Store a clone for Application's reference
-            self.starfield = Some(starfield.clone());
+                // Store a clone for Application's reference
+                self.starfield = Some(starfield.clone());
 
-            // Add starfield as event handler
-            self.event_handlers.push(Box::new(StarfieldAdapter::new(starfield)));
+                // Add starfield as event handler: drawn behind everything, and offered input only after the UI
+                self.add_event_handler(Box::new(StarfieldAdapter::new(starfield)), -1, 0);
+            }
 
             Ok(())
         }
 
+        fn is_idle(&self) -> bool {
+            const IDLE_GRACE: Duration = Duration::from_millis(500);
+            self.settings.idle_power_saving && self.last_activity.is_some_and(|t| t.elapsed() > IDLE_GRACE)
+        }
+
         pub fn run(&mut self) -> Result<(), String> {
             // Allow joystick events even when window is not in focus
             sdl3::hint::set("SDL_JOYSTICK_ALLOW_BACKGROUND_EVENTS", "1");
@@ -469,27 +1050,73 @@ hotline::object!({
             sdl3::hint::set("SDL_RENDER_DRIVER", "gpu");
 
             let sdl_context = sdl3::init().map_err(|e| e.to_string())?;
-            let video_subsystem = sdl_context.video().map_err(|e| e.to_string())?;
+            let video_subsystem = match sdl_context.video() {
+                Ok(video) => video,
+                Err(e) if self.settings.software_fallback => {
+                    // Headless machines have no display; SDL's offscreen driver still gives us a window surface
+                    eprintln!("WARNING: No video device ({}), falling back to the offscreen video driver", e);
+                    sdl3::hint::set("SDL_VIDEO_DRIVER", "offscreen");
+                    sdl_context.video().map_err(|e| e.to_string())?
+                }
+                Err(e) => return Err(e.to_string()),
+            };
             let game_controller_subsystem = sdl_context.gamepad().map_err(|e| e.to_string())?;
 
+            // Mappings give unusual controllers the standard gamepad axis and button layout
+            if let Some(ref path) = self.settings.controller_db {
+                let count = game_controller_subsystem
+                    .load_mappings(path)
+                    .map_err(|e| format!("failed to load controller mappings from {}: {}", path, e))?;
+                eprintln!("Loaded {} controller mappings from {}", count, path);
+            }
+
             let display = video_subsystem.get_primary_display().map_err(|e| e.to_string())?;
             let usable_bounds = display.get_usable_bounds().map_err(|e| e.to_string())?;
-            let win_w = (usable_bounds.width() as f32 * 0.9) as u32;
-            let win_h = (usable_bounds.height() as f32 * 0.9) as u32;
+            // Geometry from the env or the setters wins over what the project file saved last run
+            let saved = match self.settings.project_file.as_str() {
+                "" => crate::settings::WindowGeometry::default(),
+                path => {
+                    crate::settings::Project::load(path)
+                        .unwrap_or_else(|e| {
+                            eprintln!("WARNING: {}", e);
+                            Default::default()
+                        })
+                        .window_geometry
+                }
+            };
+            let geometry = &mut self.settings.window_geometry;
+            geometry.size = geometry.size.or(saved.size);
+            geometry.position = geometry.position.or(saved.position);
+            let (win_w, win_h) = self
+                .settings
+                .window_geometry
+                .size
+                .unwrap_or(((usable_bounds.width() as f32 * 0.9) as u32, (usable_bounds.height() as f32 * 0.9) as u32));
             eprintln!("Window size: {}x{}", win_w, win_h);
 
-            let window = video_subsystem
-                .window("hotline - direct calls", win_w, win_h)
-                .position_centered()
-                .high_pixel_density()
-                .resizable()
-                .build()
-                .map_err(|e| e.to_string())?;
+            let mut window_builder = video_subsystem.window("hotline - direct calls", win_w, win_h);
+            match self.settings.window_geometry.position {
+                Some((x, y)) => window_builder.position(x, y),
+                None => window_builder.position_centered(),
+            };
+            if self.settings.overlay {
+                let flags = window_builder.window_flags()
+                    | sdl3::sys::video::SDL_WINDOW_TRANSPARENT as u32
+                    | sdl3::sys::video::SDL_WINDOW_ALWAYS_ON_TOP as u32;
+                window_builder.set_window_flags(flags).borderless();
+            }
+            let mut window = window_builder.high_pixel_density().resizable().build().map_err(|e| e.to_string())?;
 
             // Initialize GPU renderer with the window
             match gpu_renderer::GpuRenderer::new(&window) {
-                Ok(renderer) => {
+                Ok(mut renderer) => {
+                    renderer.set_texture_budget(self.settings.texture_cache_mb * 1024 * 1024);
                     self.gpu_renderer = Some(renderer);
+                    self.apply_theme(self.settings.theme);
+                }
+                Err(e) if self.settings.software_fallback => {
+                    // Every frame then goes through the CPU layer and the window surface
+                    eprintln!("WARNING: Failed to initialize GPU renderer ({}), falling back to software rendering", e);
                 }
                 Err(e) => {
                     return Err(format!("Failed to initialize GPU renderer: {}", e));
@@ -497,6 +1124,8 @@ hotline::object!({
             }
 
             let mut event_pump = sdl_context.event_pump().map_err(|e| e.to_string())?;
+            let mouse = sdl_context.mouse();
+            let keyboard = sdl_context.keyboard();
             video_subsystem.text_input().start(&window);
 
             let (dw, dh) = window.size_in_pixels();
@@ -533,7 +1162,40 @@ hotline::object!({
                 }
             }
 
+            let registry = self.get_registry().ok_or("Application registry not available during run")?;
+            self.seen_reloads = registry.reload_count();
+            self.last_activity = Some(Instant::now());
+
             'running: loop {
+                // When idle, block on input until the next idle frame is due instead of spinning
+                let mut events: Vec<Event> = event_pump.poll_iter().collect();
+                if events.is_empty() && self.is_idle() {
+                    let timeout_ms = 1000 / self.settings.idle_fps.max(1);
+                    if let Some(event) = event_pump.wait_event_timeout(timeout_ms) {
+                        events.push(event);
+                        events.extend(event_pump.poll_iter());
+                    }
+                }
+                if !events.is_empty() {
+                    self.last_activity = Some(Instant::now());
+                }
+
+                // Hide a cursor that has sat still for cursor_hide_secs; the next movement brings it back
+                let moved = events.iter().any(|event| matches!(event, Event::MouseMotion { .. }));
+                if moved || self.last_mouse_motion.is_none() {
+                    self.last_mouse_motion = Some(Instant::now());
+                    if !mouse.is_cursor_showing() {
+                        mouse.show_cursor(true);
+                    }
+                } else if self.settings.cursor_hide_secs > 0.0
+                    && mouse.is_cursor_showing()
+                    && self
+                        .last_mouse_motion
+                        .is_some_and(|t| t.elapsed().as_secs_f64() >= self.settings.cursor_hide_secs)
+                {
+                    mouse.show_cursor(false);
+                }
+
                 // Track frame time
                 let now = std::time::Instant::now();
                 self.frame_times.push_back(now);
@@ -555,7 +1217,7 @@ hotline::object!({
                             self.current_fps = (self.frame_times.len() - 1) as f64 / duration;
 
                             if let Some(ref mut fps) = self.fps_counter {
-                                fps.set_text(format!("FPS: {:.1}", self.current_fps));
+                                fps.set_text(format!("FPS: {:.*}", self.settings.precision.rate, self.current_fps));
                             }
                         }
                         self.last_fps_update = Some(now);
@@ -563,8 +1225,62 @@ hotline::object!({
                 }
 
                 // Handle events
-                for event in event_pump.poll_iter() {
+                for event in events {
+                    let inspector_editing =
+                        self.field_inspector.as_ref().is_some_and(|inspector| inspector.is_editing());
                     match event {
+                        // A text field being typed into in the inspector takes the keyboard until it's done
+                        Event::TextInput { text, .. } if inspector_editing => {
+                            if let Some(ref mut inspector) = self.field_inspector {
+                                inspector.insert_text(&text);
+                            }
+                        }
+                        Event::KeyDown { keycode: Some(kc), .. } if inspector_editing => {
+                            if let Some(ref mut inspector) = self.field_inspector {
+                                match kc {
+                                    Keycode::Return | Keycode::KpEnter => {
+                                        if let Some((name, value)) = inspector.commit_edit() {
+                                            self.apply_field_edit(name, value);
+                                        }
+                                    }
+                                    Keycode::Escape => inspector.cancel_edit(),
+                                    Keycode::Backspace => inspector.backspace(),
+                                    _ => {}
+                                }
+                            }
+                        }
+                        Event::KeyDown { keycode: Some(Keycode::Escape), .. }
+                            if self.window_manager.as_mut().is_some_and(|wm| wm.is_menu_open()) =>
+                        {
+                            // Escape dismisses an open menu before it quits
+                            if let Some(ref mut wm) = self.window_manager {
+                                wm.close_menus();
+                            }
+                        }
+                        Event::KeyDown { keycode: Some(Keycode::Escape), .. }
+                            if self.chat_interface.as_ref().is_some_and(|chat| chat.is_focused()) =>
+                        {
+                            // Escape in the chat stops a reply in flight rather than quitting
+                            if let Some(ref mut chat) = self.chat_interface {
+                                chat.cancel_current();
+                            }
+                        }
+                        Event::KeyDown { keycode: Some(Keycode::Escape), .. }
+                            if self.code_editor.as_ref().is_some_and(|editor| editor.has_disk_conflict()) =>
+                        {
+                            // Escape at the disk conflict prompt keeps the buffer rather than quitting
+                            if let Some(ref mut editor) = self.code_editor {
+                                editor.keep_buffer();
+                            }
+                        }
+                        Event::KeyDown { keycode: Some(Keycode::Escape), .. }
+                            if self.code_editor.as_ref().is_some_and(|editor| editor.is_find_open()) =>
+                        {
+                            // Escape closes the find bar before it quits
+                            if let Some(ref mut editor) = self.code_editor {
+                                editor.close_find();
+                            }
+                        }
                         Event::Quit { .. } | Event::KeyDown { keycode: Some(Keycode::Escape), .. } => {
                             break 'running;
                         }
@@ -590,10 +1306,20 @@ hotline::object!({
                         Event::MouseButtonDown { mouse_btn: MouseButton::Left, x, y, .. } => {
                             let (adj_x, adj_y) = self.transform_mouse_coords(x, y, &window);
 
+                            if let Some(inspector) =
+                                self.field_inspector.as_mut().filter(|inspector| inspector.contains_point(adj_x, adj_y))
+                            {
+                                if let Some((name, value)) = inspector.handle_mouse_down(adj_x, adj_y) {
+                                    self.apply_field_edit(name, value);
+                                }
+                                continue;
+                            }
+
                             let mut consumed = false;
                             // Dispatch to event handlers in order
-                            for handler in &mut self.event_handlers {
-                                if handler.handle_mouse_down(adj_x, adj_y) {
+                            for &i in &self.input_order {
+                                let handler = &mut self.event_handlers[i];
+                                if handler.hit_test(adj_x, adj_y) && handler.handle_mouse_down(adj_x, adj_y) {
                                     consumed = true;
                                     break;
                                 }
@@ -647,12 +1373,17 @@ hotline::object!({
                         Event::MouseButtonUp { mouse_btn: MouseButton::Left, x, y, .. } => {
                             let (adj_x, adj_y) = self.transform_mouse_coords(x, y, &window);
 
+                            if let Some(ref mut inspector) = self.field_inspector {
+                                inspector.handle_mouse_up();
+                            }
+
                             if let Some(ref mut wm) = self.window_manager {
                                 wm.handle_mouse_up(adj_x, adj_y);
                             }
 
                             // Dispatch to all event handlers
-                            for handler in &mut self.event_handlers {
+                            for &i in &self.input_order {
+                                let handler = &mut self.event_handlers[i];
                                 handler.handle_mouse_up(adj_x, adj_y);
                             }
 
@@ -665,12 +1396,21 @@ hotline::object!({
                         Event::MouseMotion { x, y, .. } => {
                             let (adj_x, adj_y) = self.transform_mouse_coords(x, y, &window);
 
+                            let edit = self
+                                .field_inspector
+                                .as_mut()
+                                .and_then(|inspector| inspector.handle_mouse_move(adj_x, adj_y));
+                            if let Some((name, value)) = edit {
+                                self.apply_field_edit(name, value);
+                            }
+
                             if let Some(ref mut wm) = self.window_manager {
                                 wm.handle_mouse_motion(adj_x, adj_y);
                             }
 
                             // Dispatch to all event handlers
-                            for handler in &mut self.event_handlers {
+                            for &i in &self.input_order {
+                                let handler = &mut self.event_handlers[i];
                                 handler.handle_mouse_move(adj_x, adj_y);
                             }
 
@@ -679,26 +1419,85 @@ hotline::object!({
                                     // TODO: Need a way to notify editor of color change
                                 }
                             }
+
+                            if let Some(ref mut tooltip) = self.tooltip {
+                                let over_autonomy =
+                                    self.autonomy_checkbox.as_mut().is_some_and(|cb| cb.contains_point(adj_x, adj_y));
+                                let over_render_times = self
+                                    .render_time_checkbox
+                                    .as_mut()
+                                    .is_some_and(|cb| cb.contains_point(adj_x, adj_y));
+                                if over_autonomy {
+                                    tooltip.hover("Let shapes move around on their own", adj_x, adj_y);
+                                } else if over_render_times {
+                                    tooltip.hover("Show how long each object takes to render", adj_x, adj_y);
+                                } else {
+                                    tooltip.clear();
+                                }
+                            }
                             self.mouse_x = x as f64;
                             self.mouse_y = y as f64;
                         }
-                        Event::MouseWheel { y, .. } => {
+                        Event::MouseWheel { x, y, .. } => {
                             let (win_w, win_h) = window.size();
                             let scale_x = self.width as f64 / win_w as f64;
                             let scale_y = self.height as f64 / win_h as f64;
                             let adj_x = self.mouse_x * scale_x / self.pixel_multiple as f64;
                             let adj_y = self.mouse_y * scale_y / self.pixel_multiple as f64;
 
-                            // Dispatch to event handlers in order
-                            for handler in &mut self.event_handlers {
-                                if handler.handle_mouse_wheel(adj_x, adj_y, y as f64) {
-                                    break;
+                            // Shift turns a plain wheel sideways for mice without a horizontal axis; wheel up
+                            // scrolls left, matching how the vertical axis reads it
+                            let shift = keyboard
+                                .mod_state()
+                                .intersects(sdl3::keyboard::Mod::LSHIFTMOD | sdl3::keyboard::Mod::RSHIFTMOD);
+                            let (x, y) = if shift && x == 0.0 { (-y, 0.0) } else { (x, y) };
+
+                            // Dispatch each axis to event handlers in order; trackpads often report both at once
+                            if y != 0.0 {
+                                for &i in &self.input_order {
+                                    let handler = &mut self.event_handlers[i];
+                                    if handler.handle_mouse_wheel(adj_x, adj_y, y as f64) {
+                                        break;
+                                    }
+                                }
+                            }
+                            if x != 0.0 {
+                                for &i in &self.input_order {
+                                    let handler = &mut self.event_handlers[i];
+                                    if handler.handle_mouse_wheel_x(adj_x, adj_y, x as f64) {
+                                        break;
+                                    }
                                 }
                             }
                         }
+                        Event::KeyDown { keycode: Some(kc), repeat: false, .. }
+                            if Keycode::from_name(&self.settings.input_mode_key) == Some(kc) =>
+                        {
+                            self.fly_mode = !self.fly_mode;
+                            let mode = if self.fly_mode { "Input: starfield camera" } else { "Input: UI" };
+                            self.show_status(mode.to_string());
+                        }
+                        // While flying, the keyboard reaches only the starfield and window shortcuts
+                        Event::TextInput { .. } if self.fly_mode => {}
+                        Event::KeyDown { keycode: Some(kc), .. }
+                            if self.fly_mode
+                                && !matches!(
+                                    kc,
+                                    Keycode::M
+                                        | Keycode::L
+                                        | Keycode::F
+                                        | Keycode::Minus
+                                        | Keycode::KpMinus
+                                        | Keycode::Plus
+                                        | Keycode::Equals
+                                        | Keycode::KpPlus
+                                        | Keycode::F11
+                                        | Keycode::F12
+                                ) => {}
                         Event::TextInput { text, .. } => {
                             // Dispatch to event handlers in order
-                            for handler in &mut self.event_handlers {
+                            for &i in &self.input_order {
+                                let handler = &mut self.event_handlers[i];
                                 if handler.handle_text_input(&text) {
                                     break;
                                 }
@@ -716,8 +1515,43 @@ hotline::object!({
                             let cmd = keymod.contains(sdl3::keyboard::Mod::LCTRLMOD)
                                 || keymod.contains(sdl3::keyboard::Mod::RCTRLMOD);
 
+                            #[cfg(target_os = "macos")]
+                            let ctrl = keymod.contains(sdl3::keyboard::Mod::LCTRLMOD)
+                                || keymod.contains(sdl3::keyboard::Mod::RCTRLMOD);
+
+                            let alt = keymod.contains(sdl3::keyboard::Mod::LALTMOD)
+                                || keymod.contains(sdl3::keyboard::Mod::RALTMOD);
+
+                            let menu_open = self.window_manager.as_mut().is_some_and(|wm| wm.is_menu_open());
+
                             // Handle specific keys first
                             match kc {
+                                Keycode::Application | Keycode::Menu => {
+                                    // Open the context menu from the keyboard
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.open_context_menu_at_selection();
+                                    }
+                                }
+                                Keycode::F10 if shift => {
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.open_context_menu_at_selection();
+                                    }
+                                }
+                                Keycode::Up if menu_open => {
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.move_menu_highlight(-1);
+                                    }
+                                }
+                                Keycode::Down if menu_open => {
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.move_menu_highlight(1);
+                                    }
+                                }
+                                Keycode::Return | Keycode::KpEnter if menu_open => {
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.activate_menu();
+                                    }
+                                }
                                 Keycode::Equals | Keycode::KpPlus if cmd => {
                                     self.pixel_multiple += 1;
                                     if let Some(ref mut zoom) = self.zoom_display {
@@ -749,8 +1583,62 @@ hotline::object!({
                                         }
                                     }
                                 }
-                                Keycode::Tab => {
-                                    // Toggle starfield parameter panel
+                                Keycode::G if !cmd && !self.event_handlers.iter().any(|h| h.is_focused()) => {
+                                    // Cycle the scene snap grid: off, 8, 16, 32
+                                    self.settings.snap_grid = match self.settings.snap_grid as u32 {
+                                        0 => 8.0,
+                                        8 => 16.0,
+                                        16 => 32.0,
+                                        _ => 0.0,
+                                    };
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.set_snap_grid(self.settings.snap_grid);
+                                    }
+                                    let status = match self.settings.snap_grid as u32 {
+                                        0 => "Snap grid: off".to_string(),
+                                        size => format!("Snap grid: {}px", size),
+                                    };
+                                    self.show_status(status);
+                                }
+                                Keycode::LeftBracket | Keycode::RightBracket
+                                    if cmd && !self.event_handlers.iter().any(|h| h.is_focused()) =>
+                                {
+                                    // Cmd+] / Cmd+[: Bring the selected object to the front or send it to the back
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        if kc == Keycode::RightBracket {
+                                            wm.bring_to_front()
+                                        } else {
+                                            wm.send_to_back()
+                                        }
+                                    }
+                                }
+                                Keycode::LeftBracket | Keycode::RightBracket
+                                    if !self.event_handlers.iter().any(|h| h.is_focused()) =>
+                                {
+                                    // [ / ]: Shrink or grow the selected object
+                                    let factor = if kc == Keycode::RightBracket { 1.1 } else { 1.0 / 1.1 };
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.scale_selected(factor);
+                                    }
+                                }
+                                k if Keycode::from_name(&self.settings.select_cycle_key) == Some(k)
+                                    && !self.event_handlers.iter().any(|h| h.is_focused()) =>
+                                {
+                                    // Step the scene selection; in an empty scene Tab toggles the starfield panel
+                                    let view_w = (self.width / self.pixel_multiple) as f64;
+                                    let view_h = (self.height / self.pixel_multiple) as f64;
+                                    let focus = self.settings.select_cycle_focus;
+                                    let cycled = self.window_manager.as_mut().is_some_and(|wm| {
+                                        wm.cycle_selection(shift) && (!focus || wm.focus_selected(view_w, view_h))
+                                    });
+                                    if !cycled && k == Keycode::Tab {
+                                        if let Some(ref mut sf) = self.starfield {
+                                            sf.toggle_panel();
+                                        }
+                                    }
+                                }
+                                Keycode::Tab if !self.event_handlers.iter().any(|h| h.is_focused()) => {
+                                    // Toggle starfield parameter panel; a focused text field takes Tab instead
                                     if let Some(ref mut sf) = self.starfield {
                                         sf.toggle_panel();
                                     }
@@ -758,12 +1646,41 @@ hotline::object!({
                                 Keycode::M => {
                                     // Toggle movement mode
                                     let editing = self.event_handlers.iter().any(|h| h.is_focused());
-                                    if !editing {
+                                    if self.fly_mode || !editing {
                                         if let Some(ref mut sf) = self.starfield {
                                             sf.toggle_movement_mode();
                                         }
                                     }
                                 }
+                                Keycode::L if shift => {
+                                    // Switch posters between their top lines and their declarations
+                                    let editing = self.event_handlers.iter().any(|h| h.is_focused());
+                                    if self.fly_mode || !editing {
+                                        if let Some(ref mut sf) = self.starfield {
+                                            sf.set_poster_signatures(!sf.poster_signatures());
+                                        }
+                                    }
+                                }
+                                Keycode::L => {
+                                    // Cycle poster layout, naming the new one in the status banner
+                                    let editing = self.event_handlers.iter().any(|h| h.is_focused());
+                                    if self.fly_mode || !editing {
+                                        if let Some(ref mut sf) = self.starfield {
+                                            sf.cycle_poster_layout();
+                                            let layout = format!("Poster layout: {:?}", sf.poster_layout());
+                                            self.show_status(layout);
+                                        }
+                                    }
+                                }
+                                Keycode::F if !cmd => {
+                                    // Warp to the nearest code poster
+                                    let editing = self.event_handlers.iter().any(|h| h.is_focused());
+                                    if self.fly_mode || !editing {
+                                        if let Some(ref mut sf) = self.starfield {
+                                            sf.warp_to_nearest_poster();
+                                        }
+                                    }
+                                }
                                 Keycode::Minus | Keycode::KpMinus => {
                                     // Decrease starfield acceleration
                                     if let Some(ref mut sf) = self.starfield {
@@ -782,19 +1699,157 @@ hotline::object!({
                                         eprintln!("Starfield acceleration: {:.1}x", new_val);
                                     }
                                 }
+                                Keycode::I if cmd => {
+                                    self.toggle_field_inspector();
+                                }
+                                Keycode::F11 => {
+                                    toggle_fullscreen(&mut window)?;
+                                }
+                                Keycode::F12 => {
+                                    self.dump_symbols();
+                                }
+                                #[cfg(target_os = "macos")]
+                                Keycode::F if cmd && ctrl => {
+                                    toggle_fullscreen(&mut window)?;
+                                }
+                                Keycode::F if cmd => {
+                                    // Toggle the editor find bar
+                                    if let Some(ref mut editor) = self.code_editor {
+                                        editor.toggle_find();
+                                    }
+                                }
+                                Keycode::C | Keycode::X if cmd => {
+                                    // Copy or cut the focused text's selection to the system clipboard
+                                    let cut = kc == Keycode::X;
+                                    let selected = if let Some(editor) =
+                                        self.code_editor.as_mut().filter(|editor| editor.is_focused())
+                                    {
+                                        if cut { editor.cut_selection() } else { editor.copy_selection() }
+                                    } else if let Some(chat) =
+                                        self.chat_interface.as_mut().filter(|chat| chat.is_focused())
+                                    {
+                                        if cut { chat.cut_selection() } else { chat.copy_selection() }
+                                    } else {
+                                        None
+                                    };
+                                    if let Some(text) = selected {
+                                        if let Err(e) = video_subsystem.clipboard().set_clipboard_text(&text) {
+                                            self.show_status(format!("Failed to copy to clipboard: {}", e));
+                                        }
+                                    }
+                                }
+                                Keycode::V if cmd => {
+                                    let editor_focused = self.code_editor.as_ref().is_some_and(|e| e.is_focused());
+                                    let chat_focused = self.chat_interface.as_ref().is_some_and(|c| c.is_focused());
+                                    if editor_focused || chat_focused {
+                                        match video_subsystem.clipboard().clipboard_text() {
+                                            Ok(text) if editor_focused => {
+                                                if let Some(ref mut editor) = self.code_editor {
+                                                    editor.paste(&text);
+                                                }
+                                            }
+                                            Ok(text) => {
+                                                if let Some(ref mut chat) = self.chat_interface {
+                                                    chat.insert_text(&text);
+                                                }
+                                            }
+                                            Err(e) => self.show_status(format!("Failed to read clipboard: {}", e)),
+                                        }
+                                    }
+                                }
+                                Keycode::D if cmd && shift => {
+                                    // Duplicate the editor's current line or selection
+                                    if let Some(ref mut editor) = self.code_editor {
+                                        if editor.is_focused() {
+                                            editor.duplicate_line();
+                                        }
+                                    }
+                                }
+                                Keycode::T if cmd && shift => {
+                                    let light = self.settings.theme == ::hotline::Theme::light();
+                                    self.apply_theme(if light {
+                                        ::hotline::Theme::dark()
+                                    } else {
+                                        ::hotline::Theme::light()
+                                    });
+                                }
+                                Keycode::H if cmd && shift => {
+                                    // Show or hide the editor's undo timeline
+                                    if let Some(ref mut editor) = self.code_editor {
+                                        editor.toggle_timeline();
+                                    }
+                                }
+                                Keycode::Up | Keycode::Down
+                                    if !alt
+                                        && self
+                                            .code_editor
+                                            .as_ref()
+                                            .is_some_and(|e| e.is_focused() && e.is_timeline_open()) =>
+                                {
+                                    // Scrub the open undo timeline, Up going back
+                                    if let Some(ref mut editor) = self.code_editor {
+                                        if kc == Keycode::Up {
+                                            editor.undo();
+                                        } else {
+                                            editor.redo();
+                                        }
+                                    }
+                                }
+                                Keycode::Up | Keycode::Down
+                                    if alt && self.code_editor.as_ref().is_some_and(|e| e.is_focused()) =>
+                                {
+                                    // Move the editor's current line or selected lines
+                                    if let Some(ref mut editor) = self.code_editor {
+                                        editor.move_lines(kc == Keycode::Up);
+                                    }
+                                }
+                                Keycode::Z if cmd && self.code_editor.as_ref().is_some_and(|e| e.is_focused()) => {
+                                    // Step through the editor's undo timeline
+                                    if let Some(ref mut editor) = self.code_editor {
+                                        if shift {
+                                            editor.redo();
+                                        } else {
+                                            editor.undo();
+                                        }
+                                    }
+                                }
+                                Keycode::Z if cmd && !self.event_handlers.iter().any(|h| h.is_focused()) => {
+                                    // Scene undo; text fields keep their own undo while focused
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        if shift {
+                                            wm.redo();
+                                        } else {
+                                            wm.undo();
+                                        }
+                                    }
+                                }
+                                Keycode::Period if !self.event_handlers.iter().any(|h| h.is_focused()) => {
+                                    // Bring the selection into view
+                                    let view_w = (self.width / self.pixel_multiple) as f64;
+                                    let view_h = (self.height / self.pixel_multiple) as f64;
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.focus_selected(view_w, view_h);
+                                    }
+                                }
+                                Keycode::Delete | Keycode::Backspace
+                                    if !self.event_handlers.iter().any(|h| h.is_focused()) =>
+                                {
+                                    if let Some(ref mut wm) = self.window_manager {
+                                        wm.delete_selected();
+                                    }
+                                }
                                 Keycode::S if cmd => {
-                                    // TODO: Add save support to EventHandler trait if needed
+                                    let result = match self.code_editor {
+                                        Some(ref mut editor) if editor.is_focused() => Some(editor.save()),
+                                        _ => None,
+                                    };
+                                    if let Some(Err(e)) = result {
+                                        self.show_status(e);
+                                    }
                                 }
                                 _ => {
                                     // Convert keycode to i32 for EventHandler trait
-                                    let keycode_i32 = kc as i32;
-
-                                    // Dispatch to event handlers in order
-                                    for handler in &mut self.event_handlers {
-                                        if handler.handle_key_down(keycode_i32, shift) {
-                                            break;
-                                        }
-                                    }
+                                    self.dispatch_key_down(kc as i32, shift);
                                 }
                             }
                         }
@@ -835,6 +1890,14 @@ hotline::object!({
                             if let Some(ref mut gc) = self.game_controller {
                                 gc.update_button(button as u8, true);
                             }
+                            if self.controller_editing() {
+                                if let Some(key) = self.controller_key_map.key_for(button as u8) {
+                                    self.controller_key_map.press(key);
+                                    let gc = self.game_controller.as_ref();
+                                    let shift = gc.is_some_and(|gc| self.controller_key_map.shift(gc));
+                                    self.dispatch_key_down(key, shift);
+                                }
+                            }
                         }
                         Event::ControllerButtonUp { which: _, button, .. } => {
                             if let Some(ref mut gc) = self.game_controller {
@@ -865,8 +1928,11 @@ hotline::object!({
                                 gc.set_connected(false, None);
                             }
                         }
-                        // Also handle raw joystick events as fallback
-                        Event::JoyAxisMotion { which: _, axis_idx, value, .. } => {
+                        // Raw joystick events only for devices without a gamepad mapping; mapped devices also
+                        // report through the Controller events above, with logical rather than positional axes
+                        Event::JoyAxisMotion { which, axis_idx, value, .. }
+                            if !game_controller_subsystem.is_gamepad(which) =>
+                        {
                             if let Some(ref mut gc) = self.game_controller {
                                 let normalized_value = if axis_idx >= 4 {
                                     // Triggers: 0 to 32767 -> 0.0 to 1.0
@@ -878,17 +1944,21 @@ hotline::object!({
                                 gc.update_axis(axis_idx, normalized_value);
                             }
                         }
-                        Event::JoyButtonDown { which: _, button_idx, .. } => {
+                        Event::JoyButtonDown { which, button_idx, .. }
+                            if !game_controller_subsystem.is_gamepad(which) =>
+                        {
                             if let Some(ref mut gc) = self.game_controller {
                                 gc.update_button(button_idx, true);
                             }
                         }
-                        Event::JoyButtonUp { which: _, button_idx, .. } => {
+                        Event::JoyButtonUp { which, button_idx, .. }
+                            if !game_controller_subsystem.is_gamepad(which) =>
+                        {
                             if let Some(ref mut gc) = self.game_controller {
                                 gc.update_button(button_idx, false);
                             }
                         }
-                        Event::JoyDeviceAdded { which, .. } => {
+                        Event::JoyDeviceAdded { which, .. } if !game_controller_subsystem.is_gamepad(which) => {
                             eprintln!("Joystick {} connected", which);
                             if let Some(ref mut gc) = self.game_controller {
                                 gc.set_connected(true, Some(which));
@@ -906,24 +1976,102 @@ hotline::object!({
 
                 if let (Some(wm), Some(cb)) = (&mut self.window_manager, &mut self.autonomy_checkbox) {
                     if cb.checked() {
-                        wm.update_autonomy(self.mouse_x, self.mouse_y);
+                        let (mouse_x, mouse_y) = (self.mouse_x, self.mouse_y);
+                        self.panic_guard
+                            .run("WindowManager", Some(wm.handle().clone()), || wm.update_autonomy(mouse_x, mouse_y));
                     }
                 }
                 if let (Some(wm), Some(cb)) = (&mut self.window_manager, &mut self.render_time_checkbox) {
                     wm.set_show_render_times(cb.checked());
                 }
                 // Update all event handlers
-                for handler in &mut self.event_handlers {
-                    handler.update();
+                // One clamped delta for everyone, so a stall (window drag, breakpoint) isn't replayed as a jump
+                let dt = self
+                    .last_frame
+                    .map_or(0.0, |last| now.duration_since(last).as_secs_f64())
+                    .min(self.settings.max_frame_delta);
+                self.last_frame = Some(now);
+                for (i, handler) in self.event_handlers.iter_mut().enumerate() {
+                    if let Some(elapsed) = self.handler_throttles[i].tick(dt, handler.update_interval()) {
+                        self.panic_guard.run(&format!("event handler {}", i), None, || handler.update(elapsed));
+                    }
+                }
+                if let Some(gc) = &self.game_controller {
+                    self.controller_throttle.tick(dt, gc.update_interval());
+                }
+                if let Some(wm) = &mut self.window_manager {
+                    self.panic_guard.run("WindowManager", Some(wm.handle().clone()), || wm.update_focus(dt));
+                }
+
+                // Anything that changes on its own keeps us rendering at full rate
+                let reloads = registry.reload_count();
+                if reloads != self.seen_reloads {
+                    // Keep the editor buffer consistent with a file that may have just been saved
+                    let synced = self.code_editor.as_mut().map_or(Ok(()), |editor| editor.sync_with_disk());
+                    if let Err(e) = synced {
+                        self.show_status(e);
+                    }
+                }
+                let autonomy = self.autonomy_checkbox.as_mut().is_some_and(|cb| cb.checked());
+                let flying = self.starfield.as_ref().is_some_and(|sf| sf.is_animating());
+                let focusing = self.window_manager.as_ref().is_some_and(|wm| wm.is_focusing());
+                let chatting = self
+                    .chat_interface
+                    .as_ref()
+                    .is_some_and(|chat| chat.is_waiting_for_response() || chat.is_send_refused());
+                let hovering = self.tooltip.as_ref().is_some_and(|tooltip| tooltip.is_pending());
+                if reloads != self.seen_reloads || autonomy || flying || focusing || chatting || hovering {
+                    self.seen_reloads = reloads;
+                    self.last_activity = Some(Instant::now());
                 }
 
                 // Skip CPU render frame entirely
                 // self.render_frame(&mut texture)?;
 
+                // Roll from the shoulder buttons or Q/E while flying or nothing has keyboard focus; only 6DOF uses it
+                let keys = event_pump.keyboard_state();
+
+                // Held controller buttons and the left stick repeat their keys into the focused editor
+                let controller_editing = self.controller_editing();
+                if controller_editing {
+                    let gc = self.game_controller.as_ref();
+                    let held = gc.and_then(|gc| self.controller_key_map.held_key(gc));
+                    let shift = gc.is_some_and(|gc| self.controller_key_map.shift(gc));
+                    if let Some(key) = self.controller_key_map.tick(held, dt) {
+                        self.dispatch_key_down(key, shift);
+                    }
+                }
+
+                if let Some(sf) = &mut self.starfield {
+                    let editing = self.event_handlers.iter().any(|h| h.is_focused());
+                    let held = |scancode| (self.fly_mode || !editing) && keys.is_scancode_pressed(scancode);
+                    let (lb, rb) = match self.game_controller {
+                        Some(ref gc) if !controller_editing => gc.shoulder_values(),
+                        _ => (false, false),
+                    };
+                    let left = lb || held(sdl3::keyboard::Scancode::Q);
+                    let right = rb || held(sdl3::keyboard::Scancode::E);
+                    sf.set_roll_input(right as i32 as f32 - left as i32 as f32);
+                }
+
                 // Update starfield with controller input and sync with event handler
-                if let (Some(sf), Some(gc)) = (&mut self.starfield, &self.game_controller) {
-                    let (lx, ly, rx, ry) = gc.axis_values();
-                    let (lt, rt) = gc.trigger_values();
+                if let Some(sf) = &mut self.starfield {
+                    // Without the controller panel only the fly mode keys drive it
+                    let gc = self.game_controller.as_ref();
+                    let (mut lx, mut ly, rx, ry) = gc.map_or((0.0, 0.0, 0.0, 0.0), |gc| gc.axis_values());
+                    let (mut lt, mut rt) = gc.map_or((0.0, 0.0), |gc| gc.trigger_values());
+                    // The left stick is moving the editor cursor instead
+                    if controller_editing {
+                        (lx, ly) = (0.0, 0.0);
+                    }
+                    // In fly mode W/S stand in for the triggers and A/D for the left stick
+                    if self.fly_mode {
+                        let held = |scancode| keys.is_scancode_pressed(scancode) as i32 as f32;
+                        lx = (lx + held(sdl3::keyboard::Scancode::D) - held(sdl3::keyboard::Scancode::A))
+                            .clamp(-1.0, 1.0);
+                        lt = lt.max(held(sdl3::keyboard::Scancode::W));
+                        rt = rt.max(held(sdl3::keyboard::Scancode::S));
+                    }
                     sf.update_controller(lx, ly, rx, ry, lt, rt);
 
                     // Update the starfield in the event handler to keep them in sync
@@ -940,13 +2088,74 @@ hotline::object!({
                     // No GPU renderer
                 }
 
+                // Refresh the field inspector from its target; it closes if the selection went away
+                if self.field_inspector.as_ref().is_some_and(|inspector| inspector.is_visible()) {
+                    let fields = self.inspected_fields();
+                    if let Some(ref mut inspector) = self.field_inspector {
+                        match fields {
+                            Some(fields) => inspector.update_fields(fields),
+                            None => inspector.close(),
+                        }
+                    }
+                }
+
+                // Handlers drawn into the CPU layer: those that can in CPU mode or without a GPU, those
+                // that must otherwise
+                let cpu_mode = self.settings.render_mode == ::hotline::RenderMode::Cpu || self.gpu_renderer.is_none();
+                let mode = if cpu_mode { ::hotline::RenderMode::Cpu } else { ::hotline::RenderMode::Gpu };
+                let cpu_handlers: Vec<usize> = self
+                    .render_order
+                    .iter()
+                    .copied()
+                    .filter(|&i| mode.draws_on_cpu(self.event_handlers[i].as_ref()))
+                    .collect();
+                let (bw, bh) = (self.width / self.pixel_multiple, self.height / self.pixel_multiple);
+                if cpu_mode || !cpu_handlers.is_empty() {
+                    // The CPU layer takes the place of the scene rects, so it stacks the same in either mode
+                    let pitch = bw as i64 * 4;
+                    let frame = &mut self.cpu_frame;
+                    frame.clear();
+                    frame.resize(bw as usize * bh as usize * 4, 0);
+                    if cpu_mode {
+                        if let Some(wm) = &mut self.window_manager {
+                            self.panic_guard.run("WindowManager", Some(wm.handle().clone()), || {
+                                wm.render(frame, bw as i64, bh as i64, pitch)
+                            });
+                        }
+                    }
+                    for &i in &cpu_handlers {
+                        let handler = &mut self.event_handlers[i];
+                        let handle = handler.handle();
+                        self.panic_guard.run(&format!("event handler {}", i), handle, || {
+                            handler.render(frame, bw as i64, bh as i64, pitch)
+                        });
+                    }
+                }
+
                 // Render objects using new GPU API
                 if let Some(gpu) = &mut self.gpu_renderer {
+                    if cpu_mode || !cpu_handlers.is_empty() {
+                        // Objects write (b, g, r, a); the texture is (r, g, b, a)
+                        let frame = &mut self.cpu_frame;
+                        for pixel in frame.chunks_exact_mut(4) {
+                            pixel.swap(0, 2);
+                        }
+                        let tex_id = gpu.upload_rgba_texture(self.cpu_frame_texture, frame, bw, bh)?;
+                        self.cpu_frame_texture = Some(tex_id);
+                        gpu.add_textured_rect(0.0, 0.0, bw as f32, bh as f32, tex_id, [1.0, 1.0, 1.0, 1.0]);
+                    }
+
                     // Render WindowManager rects
-                    if let Some(wm) = &mut self.window_manager {
-                        // Directly render rects from WindowManager
-                        for i in 0..wm.get_rects_count() {
-                            if let Some(rect) = wm.get_rect_at(i) {
+                    if let Some(wm) = self.window_manager.as_mut().filter(|_| !cpu_mode) {
+                        let view_w = (self.width / self.pixel_multiple) as f64;
+                        let view_h = (self.height / self.pixel_multiple) as f64;
+                        self.panic_guard.run("WindowManager", Some(wm.handle().clone()), || {
+                            wm.render_grid_gpu(gpu, view_w, view_h)
+                        });
+
+                        // Directly render rects from WindowManager, lowest z-index first
+                        for rect in wm.rects_by_z_index() {
+                            if rect.is_visible() {
                                 let (x, y, w, h) = rect.bounds();
 
                                 // Generate color based on position and time (matching Rect's render method)
@@ -966,20 +2175,20 @@ hotline::object!({
 
                     // Render GameController
                     if let Some(gc) = &mut self.game_controller {
-                        gc.render_gpu(gpu);
+                        let guard = &mut self.panic_guard;
+                        self.controller_throttle.render(gpu, |gpu| {
+                            guard.run("GameController", Some(gc.handle().clone()), || gc.render_gpu(gpu));
+                        });
                     }
 
-                    // Render Starfield
-                    if let Some(sf) = &mut self.starfield {
-                        let start = std::time::Instant::now();
-                        sf.render_gpu(gpu);
-                        let elapsed = start.elapsed();
-                        if elapsed.as_millis() > 16 {
-                            eprintln!(
-                                "WARNING: Starfield::render_gpu took {}ms (>16ms frame budget)",
-                                elapsed.as_millis()
-                            );
-                        }
+                    // Render event handlers (Starfield, chat overlays) back to front
+                    for &i in self.render_order.iter().filter(|&&i| !cpu_handlers.contains(&i)) {
+                        let handler = &mut self.event_handlers[i];
+                        let handle = handler.handle();
+                        let guard = &mut self.panic_guard;
+                        self.handler_throttles[i].render(gpu, |gpu| {
+                            guard.run(&format!("event handler {}", i), handle, || handler.render_gpu(gpu));
+                        });
                     }
 
                     // Render ColorWheel
@@ -995,14 +2204,38 @@ hotline::object!({
                         // TODO: Update Checkbox to use new GPU API
                     }
 
+                    if let Some(wm) = &mut self.window_manager {
+                        self.panic_guard.run("WindowManager", Some(wm.handle().clone()), || wm.render_gpu(gpu));
+                    }
+
+                    if let Some(inspector) = &mut self.field_inspector {
+                        self.panic_guard
+                            .run("FieldInspector", Some(inspector.handle().clone()), || inspector.render_gpu(gpu));
+                    }
+
                     // Render FPS counter
                     if let Some(fps) = &mut self.fps_counter {
-                        fps.render_gpu(gpu);
+                        self.panic_guard.run("FPS counter", Some(fps.handle().clone()), || fps.render_gpu(gpu));
+                    }
+                    if let Some(mode) = self.input_mode_display.as_mut().filter(|_| self.fly_mode) {
+                        mode.render_gpu(gpu);
+                    }
+
+                    // Render status banner while an error report is fresh
+                    if let Some(message) = self.panic_guard.take_error() {
+                        if let Some(ref mut banner) = self.status_banner {
+                            banner.set_text(message);
+                        }
+                        self.status_banner_until = Some(Instant::now() + Duration::from_secs(5));
+                    }
+                    let banner_fresh = self.status_banner_until.is_some_and(|until| Instant::now() < until);
+                    if let Some(banner) = self.status_banner.as_mut().filter(|_| banner_fresh) {
+                        banner.render_gpu(gpu);
                     }
 
-                    // Render code editor through event handler
-                    for _handler in &mut self.event_handlers {
-                        // TODO: Update event handlers to support GPU rendering
+                    // Tooltip last so it sits above everything
+                    if let Some(tooltip) = &mut self.tooltip {
+                        self.panic_guard.run("Tooltip", Some(tooltip.handle().clone()), || tooltip.render_gpu(gpu));
                     }
                 }
 
@@ -1016,7 +2249,17 @@ hotline::object!({
                         eprintln!("WARNING: gpu.render_frame took {}ms (>16ms frame budget)", elapsed.as_millis());
                     }
                 } else {
-                    eprintln!("[Application] ERROR: gpu_renderer is None during render!");
+                    // Software fallback: the CPU layer is the whole frame, scaled up onto the window surface
+                    let mut surface = window.surface(&event_pump).map_err(|e| e.to_string())?;
+                    // ARGB8888 is (b, g, r, a) in memory on little-endian machines, as objects write it
+                    let format = sdl3::pixels::PixelFormat::from(sdl3::pixels::PixelFormatEnum::ARGB8888);
+                    let mut layer = sdl3::surface::Surface::from_data(&mut self.cpu_frame, bw, bh, bw * 4, format)
+                        .map_err(|e| e.to_string())?;
+                    layer.set_blend_mode(sdl3::render::BlendMode::None).map_err(|e| e.to_string())?;
+                    layer
+                        .blit_scaled(None, &mut surface, None, sdl3::sys::surface::SDL_ScaleMode::NEAREST)
+                        .map_err(|e| e.to_string())?;
+                    surface.update_window().map_err(|e| e.to_string())?;
                 }
 
                 let frame_render_elapsed = frame_render_start.elapsed();
@@ -1028,11 +2271,28 @@ hotline::object!({
                 }
             }
 
+            // The next run opens the window where this one left it
+            if !self.settings.project_file.is_empty() {
+                let window_geometry =
+                    crate::settings::WindowGeometry { size: Some(window.size()), position: Some(window.position()) };
+                let project = crate::settings::Project { window_geometry };
+                if let Err(e) = project.save(&self.settings.project_file) {
+                    eprintln!("WARNING: {}", e);
+                }
+            }
+
             Ok(())
         }
     }
 });
 
+// Switches between windowed and fullscreen-desktop; SDL follows up with resize events, which
+// update width/height and the Starfield rect
+fn toggle_fullscreen(window: &mut sdl3::video::Window) -> Result<(), String> {
+    let fullscreen = window.fullscreen_state() == sdl3::video::FullscreenType::Off;
+    window.set_fullscreen(fullscreen).map_err(|e| e.to_string())
+}
+
 #[cfg(target_os = "linux")]
 fn save_png(path: &str, width: u32, height: u32, data: &[u8]) -> Result<(), String> {
     let file = File::create(path).map_err(|e| e.to_string())?;
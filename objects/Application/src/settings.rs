@@ -0,0 +1,253 @@
+use sdl3::keyboard::Keycode;
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Startup settings, each overridable by the HOTLINE_* environment variable named in apply_env.
+// A new setting is a field here, its default, and one env_override line.
+#[derive(Clone, ::hotline::serde::Serialize, ::hotline::serde::Deserialize)]
+#[serde(crate = "::hotline::serde")]
+pub struct Settings {
+    pub double_click_ms: u64,
+    pub max_frame_delta: f64, // Longest frame delta handed to updates, in seconds
+    // Only render at idle_fps once nothing has changed for IDLE_GRACE
+    pub idle_power_saving: bool,
+    pub idle_fps: u32,
+    pub cursor_hide_secs: f64,   // Pointer stillness before the cursor hides; 0 never hides it
+    pub software_fallback: bool, // Without a GPU (or any video device), draw on the CPU instead of exiting
+    pub texture_cache_mb: usize,
+    pub snap_grid: f64,           // Scene grid spacing in pixels; 0 places shapes freely
+    pub scroll_multiplier: f64,   // Editor wheel velocity per notch
+    pub scroll_acceleration: f64, // Editor boost per notch of rapid scrolling; 0 disables
+    // Soft limits that warn about runaway creation; 0 disables
+    pub max_shapes: usize,
+    pub max_event_handlers: usize,
+    pub chat_send_interval_ms: u64, // Least time between chat sends; sooner ones are refused with a hint
+    pub chat_max_len: usize,        // Chat bytes kept in memory before older turns are archived; 0 never archives
+    pub poster_max_file_kb: u64,    // Source files over this size get no Starfield poster; 0 allows any
+    pub poster_signatures: bool,    // Starfield posters preview declarations rather than the top of the file
+    pub star_spawn_budget: usize,   // Starfield stars spawned per frame while filling in; 0 is unlimited
+    pub roll_speed: f32,            // Starfield 6DOF roll rate in radians per second
+    pub text_gamma: f32,            // Starfield poster text coverage correction; 1 draws glyphs as rasterized
+    pub clip_to_bounds: bool,       // GameController and Starfield drawing is cut off at their rects
+    pub star_bloom: bool,           // Starfield bloom quality setting
+    // Shared through the registry; Cmd+Shift+T switches between dark and light
+    pub theme: ::hotline::Theme,
+    // Decimal places for numeric readouts, also shared through the registry
+    pub precision: ::hotline::Precision,
+    pub overlay: bool, // Transparent, borderless, always-on-top window over the desktop
+    // SDL key name that steps the scene selection, Shift going back; focus brings each shape into view
+    pub select_cycle_key: String,
+    pub select_cycle_focus: bool,
+    // SDL key name that hands the keyboard between the 2D UI and flying the Starfield camera
+    pub input_mode_key: String,
+    // Controller button=key bindings of SDL names sent to the focused editor; the left stick moves the cursor
+    pub controller_keys: String,
+    pub editor_autosave_on_reload: bool, // Editor writes unsaved edits back when its file changes on disk
+    pub editor_highlight_word: bool,     // Faintly box other copies of the word selected in the editor
+    pub editor_undo_depth: usize,        // Editor undo steps kept for the open file; 0 keeps them all
+    pub render_mode: ::hotline::RenderMode, // Forced render path; objects without it fall back to the other
+    pub controller_hz: f64,              // Controller panel redraw rate; 0 redraws every frame
+    pub controller_db: Option<String>,   // SDL gamecontrollerdb.txt mappings file
+    // Objects created at startup, as parsed by StartupSet; the window manager and overlays always are
+    pub startup_objects: String,
+    pub window_geometry: WindowGeometry,
+    pub project_file: String, // Where the window geometry is saved on exit and restored from; empty keeps none
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            double_click_ms: 400,
+            max_frame_delta: 0.1,
+            idle_power_saving: true,
+            idle_fps: 4,
+            cursor_hide_secs: 3.0,
+            software_fallback: true,
+            texture_cache_mb: 256,
+            snap_grid: 0.0,
+            scroll_multiplier: 20.0,
+            scroll_acceleration: 0.5,
+            max_shapes: 1000,
+            max_event_handlers: 64,
+            chat_send_interval_ms: 2000,
+            chat_max_len: 200_000,
+            poster_max_file_kb: 256,
+            poster_signatures: false,
+            star_spawn_budget: 500,
+            roll_speed: 1.5,
+            text_gamma: 1.0,
+            clip_to_bounds: false,
+            star_bloom: false,
+            theme: ::hotline::Theme::default(),
+            precision: ::hotline::Precision::default(),
+            overlay: false,
+            select_cycle_key: "Tab".to_string(),
+            select_cycle_focus: false,
+            input_mode_key: "F1".to_string(),
+            controller_keys: crate::ControllerKeys::DEFAULTS.to_string(),
+            editor_autosave_on_reload: false,
+            editor_highlight_word: true,
+            editor_undo_depth: 100,
+            render_mode: ::hotline::RenderMode::default(),
+            controller_hz: 0.0,
+            controller_db: None,
+            startup_objects: crate::StartupSet::DEFAULTS.to_string(),
+            window_geometry: WindowGeometry::default(),
+            project_file: "hotline-project.json".to_string(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn apply_env(&mut self) -> Result<(), String> {
+        env_override("HOTLINE_DOUBLE_CLICK_MS", &mut self.double_click_ms)?;
+        env_override("HOTLINE_MAX_FRAME_DELTA", &mut self.max_frame_delta)?;
+        env_override("HOTLINE_IDLE_POWER_SAVING", &mut self.idle_power_saving)?;
+        env_override("HOTLINE_IDLE_FPS", &mut self.idle_fps)?;
+        env_override("HOTLINE_CURSOR_HIDE_SECS", &mut self.cursor_hide_secs)?;
+        env_override("HOTLINE_SOFTWARE_FALLBACK", &mut self.software_fallback)?;
+        env_override("HOTLINE_TEXTURE_CACHE_MB", &mut self.texture_cache_mb)?;
+        env_override("HOTLINE_SNAP_GRID", &mut self.snap_grid)?;
+        env_override("HOTLINE_SCROLL_MULTIPLIER", &mut self.scroll_multiplier)?;
+        env_override("HOTLINE_SCROLL_ACCELERATION", &mut self.scroll_acceleration)?;
+        env_override("HOTLINE_MAX_SHAPES", &mut self.max_shapes)?;
+        env_override("HOTLINE_MAX_EVENT_HANDLERS", &mut self.max_event_handlers)?;
+        env_override("HOTLINE_CHAT_SEND_INTERVAL_MS", &mut self.chat_send_interval_ms)?;
+        env_override("HOTLINE_CHAT_MAX_LEN", &mut self.chat_max_len)?;
+        env_override("HOTLINE_POSTER_MAX_FILE_KB", &mut self.poster_max_file_kb)?;
+        env_override("HOTLINE_POSTER_SIGNATURES", &mut self.poster_signatures)?;
+        env_override("HOTLINE_STAR_SPAWN_BUDGET", &mut self.star_spawn_budget)?;
+        env_override("HOTLINE_ROLL_SPEED", &mut self.roll_speed)?;
+        env_override("HOTLINE_TEXT_GAMMA", &mut self.text_gamma)?;
+        env_override("HOTLINE_CLIP_TO_BOUNDS", &mut self.clip_to_bounds)?;
+        env_override("HOTLINE_STAR_BLOOM", &mut self.star_bloom)?;
+        env_override("HOTLINE_THEME", &mut self.theme)?;
+        env_override("HOTLINE_PRECISION", &mut self.precision)?;
+        env_override("HOTLINE_OVERLAY", &mut self.overlay)?;
+        env_override("HOTLINE_SELECT_CYCLE_KEY", &mut self.select_cycle_key)?;
+        env_override("HOTLINE_SELECT_CYCLE_FOCUS", &mut self.select_cycle_focus)?;
+        env_override("HOTLINE_INPUT_MODE_KEY", &mut self.input_mode_key)?;
+        env_override("HOTLINE_CONTROLLER_KEYS", &mut self.controller_keys)?;
+        env_override("HOTLINE_EDITOR_AUTOSAVE_ON_RELOAD", &mut self.editor_autosave_on_reload)?;
+        env_override("HOTLINE_EDITOR_HIGHLIGHT_WORD", &mut self.editor_highlight_word)?;
+        env_override("HOTLINE_EDITOR_UNDO_DEPTH", &mut self.editor_undo_depth)?;
+        env_override("HOTLINE_RENDER_MODE", &mut self.render_mode)?;
+        env_override("HOTLINE_CONTROLLER_HZ", &mut self.controller_hz)?;
+        env_override("HOTLINE_STARTUP", &mut self.startup_objects)?;
+        env_override("HOTLINE_WINDOW_GEOMETRY", &mut self.window_geometry)?;
+        env_override("HOTLINE_PROJECT_FILE", &mut self.project_file)?;
+        // gamecontrollerdb.txt in the working directory is used when no file is named
+        self.controller_db = std::env::var("HOTLINE_GAMECONTROLLERDB").ok().or_else(|| {
+            std::path::Path::new("gamecontrollerdb.txt").exists().then(|| "gamecontrollerdb.txt".to_string())
+        });
+        self.validate()
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if !self.max_frame_delta.is_finite() || self.max_frame_delta <= 0.0 {
+            return Err("HOTLINE_MAX_FRAME_DELTA must be a positive number of seconds".into());
+        }
+        if self.idle_fps == 0 {
+            return Err("HOTLINE_IDLE_FPS must be at least 1".into());
+        }
+        if !self.cursor_hide_secs.is_finite() || self.cursor_hide_secs < 0.0 {
+            return Err("HOTLINE_CURSOR_HIDE_SECS must be a non-negative number of seconds".into());
+        }
+        if !self.snap_grid.is_finite() || self.snap_grid < 0.0 {
+            return Err("HOTLINE_SNAP_GRID must be a non-negative number of pixels".into());
+        }
+        if !self.scroll_multiplier.is_finite() || self.scroll_multiplier <= 0.0 {
+            return Err("HOTLINE_SCROLL_MULTIPLIER must be a positive number".into());
+        }
+        if !self.scroll_acceleration.is_finite() || self.scroll_acceleration < 0.0 {
+            return Err("HOTLINE_SCROLL_ACCELERATION must be a non-negative number".into());
+        }
+        if !self.roll_speed.is_finite() || self.roll_speed < 0.0 {
+            return Err(format!("roll speed must be non-negative, got {}", self.roll_speed));
+        }
+        if !self.text_gamma.is_finite() || self.text_gamma <= 0.0 {
+            return Err(format!("text gamma must be positive, got {}", self.text_gamma));
+        }
+        if !self.controller_hz.is_finite() || self.controller_hz < 0.0 {
+            return Err("HOTLINE_CONTROLLER_HZ must be a non-negative rate".into());
+        }
+        for (name, key) in
+            [("HOTLINE_SELECT_CYCLE_KEY", &self.select_cycle_key), ("HOTLINE_INPUT_MODE_KEY", &self.input_mode_key)]
+        {
+            if Keycode::from_name(key).is_none() {
+                return Err(format!("invalid {} '{}': not an SDL key name", name, key));
+            }
+        }
+        Ok(())
+    }
+}
+
+// Parses `name` over `field` when it's set; the field keeps its value otherwise
+fn env_override<T: FromStr>(name: &str, field: &mut T) -> Result<(), String>
+where
+    T::Err: Display,
+{
+    if let Ok(value) = std::env::var(name) {
+        *field = value.parse().map_err(|e| format!("invalid {} '{}': {}", name, value, e))?;
+    }
+    Ok(())
+}
+
+// Initial window geometry as WIDTHxHEIGHT[+X+Y]; unset parts come from the project file, else 90% of the
+// usable display bounds, centered
+#[derive(Clone, Copy, Default, ::hotline::serde::Serialize, ::hotline::serde::Deserialize)]
+#[serde(crate = "::hotline::serde")]
+pub struct WindowGeometry {
+    pub size: Option<(u32, u32)>,
+    pub position: Option<(i32, i32)>,
+}
+
+impl FromStr for WindowGeometry {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, String> {
+        let invalid = || "expected WIDTHxHEIGHT[+X+Y]".to_string();
+        let (size, position) = match value.split_once('+') {
+            Some((size, position)) => (size, Some(position)),
+            None => (value, None),
+        };
+        let (w, h) = size.split_once('x').ok_or_else(invalid)?;
+        let (w, h): (u32, u32) = (w.parse().map_err(|_| invalid())?, h.parse().map_err(|_| invalid())?);
+        if w == 0 || h == 0 {
+            return Err(invalid());
+        }
+        let position = match position {
+            Some(position) => {
+                let (x, y) = position.split_once('+').ok_or_else(invalid)?;
+                Some((x.parse().map_err(|_| invalid())?, y.parse().map_err(|_| invalid())?))
+            }
+            None => None,
+        };
+        Ok(Self { size: Some((w, h)), position })
+    }
+}
+
+// What the project file keeps between runs, as JSON
+#[derive(Default, ::hotline::serde::Serialize, ::hotline::serde::Deserialize)]
+#[serde(crate = "::hotline::serde", default)]
+pub struct Project {
+    pub window_geometry: WindowGeometry,
+}
+
+impl Project {
+    // A file that doesn't exist yet is an empty project
+    pub fn load(path: &str) -> Result<Self, String> {
+        match std::fs::read_to_string(path) {
+            Ok(json) => {
+                ::hotline::serde_json::from_str(&json).map_err(|e| format!("invalid project file {}: {}", path, e))
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("failed to read project file {}: {}", path, e)),
+        }
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), String> {
+        let json = ::hotline::serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| format!("failed to write project file {}: {}", path, e))
+    }
+}
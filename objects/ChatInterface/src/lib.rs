@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test_send_sync;
 
+const MODELS: [&str; 3] = ["claude-3-5-sonnet-20241022", "claude-3-5-haiku-20241022", "claude-3-opus-20240229"];
+
 hotline::object!({
     #[derive(Default, Clone)]
     pub struct ChatInterface {
@@ -15,13 +17,39 @@ hotline::object!({
         #[setter]
         anthropic_client: Option<AnthropicClient>,
 
-        conversation: String,
+        model_picker: Option<Dropdown>,
+
+        conversation: String, // Recent turns; older ones move to archive_dir past max_conversation_len
+        #[setter]
+        #[default(200_000)]
+        max_conversation_len: usize, // Bytes; 0 keeps everything in memory
+        #[setter]
+        #[default("chat_archive".to_string())]
+        archive_dir: String,
+        archives: Vec<String>, // Archived chunk paths, oldest first
+        shown_archives: usize, // Newest archives currently prepended to the history view
         #[default(100.0)]
         input_height: f64,
         #[default(2.0)]
         separator_height: f64,
+        #[setter]
+        #[default("Type a message, Enter to send".to_string())]
+        placeholder: String, // Shown in the empty input while it isn't focused; empty hides it
+        placeholder_renderer: Option<TextRenderer>,
+        spinner_renderer: Option<TextRenderer>,
 
         waiting_for_response: bool,
+        #[serde(skip)]
+        request_started: Option<std::time::Instant>, // Drives the spinner while waiting_for_response
+        #[serde(skip)]
+        streamed_reply: bool, // The current reply has started arriving through receive_llm_delta
+        #[setter]
+        #[default(2000)]
+        min_send_interval_ms: u64, // Least time between sends, so rapid sends can't hammer the API; 0 disables
+        #[serde(skip)]
+        last_send: Option<std::time::Instant>,
+        #[serde(skip)]
+        send_refused: bool, // A send came too soon; the hint shows until the interval is up
         shared_white_atlas_id: Option<u32>,
     }
 
@@ -32,20 +60,55 @@ hotline::object!({
         }
 
         pub fn set_rect(&mut self, rect: Rect) {
+            let (x, y, w, h) = rect.bounds();
+            if let Some(ref mut picker) = self.model_picker {
+                picker.set_x(x + w - picker.width() - 5.0);
+                picker.set_y(y + 5.0);
+            }
+            // History above the separator, input below it
+            if let Some(ref mut history) = self.history_area {
+                let mut history_bounds = Rect::new();
+                history_bounds.initialize(x, y, w, h - self.input_height - self.separator_height);
+                history.set_rect(history_bounds);
+            }
+            if let Some(ref mut input) = self.input_area {
+                let mut input_bounds = Rect::new();
+                input_bounds.initialize(x, y + h - self.input_height, w, self.input_height);
+                input.set_rect(input_bounds);
+            }
             self.bounds = Some(rect);
         }
 
         pub fn handle_mouse_down(&mut self, x: f64, y: f64) -> bool {
+            if let Some(ref mut picker) = self.model_picker {
+                let inside = picker.contains_point(x, y);
+                if let Some(index) = picker.handle_mouse_down(x, y) {
+                    if let Some(ref mut client) = self.anthropic_client {
+                        client.set_model(MODELS[index].to_string());
+                    }
+                }
+                if inside {
+                    return true;
+                }
+            }
+
             if let Some(ref bounds) = self.bounds {
                 let bounds_clone = bounds.clone();
                 let (_bx, by, _bw, bh) = bounds_clone.bounds();
                 let history_height = bh - self.input_height - self.separator_height;
 
+                // Only the area under the click keeps focus
                 if y < by + history_height {
+                    if let Some(ref mut input) = self.input_area {
+                        input.set_focused(false);
+                    }
                     if let Some(ref mut history) = self.history_area {
                         return history.handle_mouse_down(x, y);
                     }
                 } else if y > by + history_height + self.separator_height {
+                    if let Some(ref mut history) = self.history_area {
+                        history.set_focused(false);
+                    }
                     if let Some(ref mut input) = self.input_area {
                         return input.handle_mouse_down(x, y);
                     }
@@ -64,6 +127,9 @@ hotline::object!({
         }
 
         pub fn handle_mouse_move(&mut self, x: f64, y: f64) {
+            if let Some(ref mut picker) = self.model_picker {
+                picker.handle_mouse_move(x, y);
+            }
             if let Some(ref mut history) = self.history_area {
                 history.handle_mouse_move(x, y);
             }
@@ -72,6 +138,22 @@ hotline::object!({
             }
         }
 
+        pub fn is_focused(&self) -> bool {
+            self.history_area.as_ref().is_some_and(|history| history.is_focused())
+                || self.input_area.as_ref().is_some_and(|input| input.is_focused())
+        }
+
+        // Tab cycles focus between the history and the input
+        pub fn focus_next_field(&mut self) {
+            let to_history = self.input_area.as_ref().is_some_and(|input| input.is_focused());
+            if let Some(ref mut history) = self.history_area {
+                history.set_focused(to_history);
+            }
+            if let Some(ref mut input) = self.input_area {
+                input.set_focused(!to_history);
+            }
+        }
+
         pub fn insert_char(&mut self, ch: char) {
             if let Some(ref mut input) = self.input_area {
                 if ch == '\n' {
@@ -88,6 +170,20 @@ hotline::object!({
             }
         }
 
+        // From whichever of the history and the input has focus
+        pub fn copy_selection(&self) -> Option<String> {
+            [&self.history_area, &self.input_area]
+                .into_iter()
+                .flatten()
+                .find(|area| area.is_focused())?
+                .copy_selection()
+        }
+
+        // Only the input is editable, so the history's selection can be copied but not cut
+        pub fn cut_selection(&mut self) -> Option<String> {
+            self.input_area.as_mut()?.cut_selection()
+        }
+
         pub fn backspace(&mut self) {
             if let Some(ref mut input) = self.input_area {
                 input.backspace();
@@ -114,9 +210,14 @@ hotline::object!({
 
                 // Check if mouse is over history area
                 if y < by + history_height {
+                    let mut at_top = false;
                     if let Some(ref mut history) = self.history_area {
+                        at_top = history.scroll_offset() <= 0.0;
                         history.add_scroll_velocity(-delta_y * 20.0);
                     }
+                    if at_top && delta_y > 0.0 {
+                        self.load_older_archive();
+                    }
                 } else if y > by + history_height + self.separator_height {
                     // Mouse is over input area
                     if let Some(ref mut input) = self.input_area {
@@ -131,57 +232,104 @@ hotline::object!({
                 return; // Don't send another message while waiting
             }
 
-            if let Some(ref mut input) = self.input_area {
-                let message = input.get_text();
-                if !message.trim().is_empty() {
-                    // append user message to conversation
-                    if !self.conversation.is_empty() {
-                        self.conversation.push_str("\n\n");
-                    }
-                    self.conversation.push_str("User: ");
-                    self.conversation.push_str(&message);
+            let Some(mut input) = self.input_area.clone() else {
+                return;
+            };
+            let message = input.get_text();
+            if message.trim().is_empty() {
+                return;
+            }
+            // Refused rather than queued, so the message stays in the input to send again once the hint clears
+            if self.send_cooldown().is_some() {
+                self.send_refused = true;
+                return;
+            }
+            self.last_send = Some(std::time::Instant::now());
 
-                    // update history
-                    if let Some(ref mut history) = self.history_area {
-                        history.set_text(self.conversation.clone());
-                        // scroll to bottom manually
-                        let line_count = self.conversation.lines().count();
-                        history.set_scroll_offset((line_count as f64 - 10.0).max(0.0) * 20.0);
-                    }
+            // append user message to conversation
+            if !self.conversation.is_empty() {
+                self.conversation.push_str("\n\n");
+            }
+            self.conversation.push_str("User: ");
+            self.conversation.push_str(&message);
+            self.refresh_history();
+
+            // Send to AnthropicClient
+            if let Some(ref mut client) = self.anthropic_client {
+                self.waiting_for_response = true;
+                self.request_started = Some(std::time::Instant::now());
+                client.send_message_streaming(message.clone());
+
+                // Show thinking message
+                self.conversation.push_str("\n\nAssistant: Thinking...");
+            } else {
+                // No client connected
+                self.conversation.push_str("\n\nAssistant: [No AnthropicClient connected]");
+            }
+            self.refresh_history();
 
-                    // Send to AnthropicClient
-                    if let Some(ref mut client) = self.anthropic_client {
-                        self.waiting_for_response = true;
-                        client.send_message(message.clone());
-
-                        // Show thinking message
-                        self.conversation.push_str("\n\nAssistant: Thinking...");
-                        if let Some(ref mut history) = self.history_area {
-                            history.set_text(self.conversation.clone());
-                            let line_count = self.conversation.lines().count();
-                            history.set_scroll_offset((line_count as f64 - 10.0).max(0.0) * 20.0);
-                        }
-                    } else {
-                        // No client connected
-                        self.conversation.push_str("\n\nAssistant: [No AnthropicClient connected]");
-                        if let Some(ref mut history) = self.history_area {
-                            history.set_text(self.conversation.clone());
-                            let line_count = self.conversation.lines().count();
-                            history.set_scroll_offset((line_count as f64 - 10.0).max(0.0) * 20.0);
-                        }
-                    }
+            // clear input
+            input.set_text(String::new());
+        }
 
-                    // clear input
-                    input.set_text(String::new());
-                }
+        pub fn is_waiting_for_response(&self) -> bool {
+            self.waiting_for_response
+        }
+
+        // Time left before another send is allowed
+        fn send_cooldown(&self) -> Option<std::time::Duration> {
+            let interval = std::time::Duration::from_millis(self.min_send_interval_ms);
+            let elapsed = self.last_send?.elapsed();
+            (elapsed < interval).then(|| interval - elapsed)
+        }
+
+        // Showing the too-soon hint, whose countdown needs frames until it clears
+        pub fn is_send_refused(&self) -> bool {
+            self.send_refused && self.send_cooldown().is_some()
+        }
+
+        // One piece of a streamed reply; the first replaces the "Thinking..." placeholder
+        pub fn receive_llm_delta(&mut self, chunk: String) {
+            if !self.waiting_for_response {
+                return;
             }
+            if self.conversation.ends_with("Assistant: Thinking...") {
+                self.conversation.truncate(self.conversation.len() - "Thinking...".len());
+            }
+            self.streamed_reply = true;
+            self.conversation.push_str(&chunk);
+            self.refresh_history();
         }
 
+        // Stops waiting on the current reply; the client's "[cancelled]" reply clears waiting_for_response
+        pub fn cancel_current(&mut self) {
+            if !self.waiting_for_response {
+                return;
+            }
+            if let Some(ref mut client) = self.anthropic_client {
+                client.cancel_request();
+            }
+        }
+
+        // Replies that arrive when none is awaited, such as the end of a cancelled request, are dropped
         pub fn receive_llm_response(&mut self, response: String) {
+            if !self.waiting_for_response {
+                return;
+            }
             self.waiting_for_response = false;
 
-            // Remove "Thinking..." and add actual response
-            if self.conversation.ends_with("Assistant: Thinking...") {
+            if std::mem::take(&mut self.streamed_reply) {
+                // The deltas already wrote the reply; anything left is the error that cut it short
+                if !response.is_empty() {
+                    self.conversation.push_str("\n\n");
+                    self.conversation.push_str(&response);
+                }
+            } else if response.is_empty() && self.conversation.ends_with("Assistant: Thinking...") {
+                // A stream that finished without sending any text
+                self.conversation.truncate(self.conversation.len() - "Thinking...".len());
+                self.conversation.push_str("[Empty response]");
+            } else if self.conversation.ends_with("Assistant: Thinking...") {
+                // Remove "Thinking..." and add actual response
                 self.conversation.truncate(self.conversation.len() - "Thinking...".len());
                 self.conversation.push_str(&response);
             } else {
@@ -190,11 +338,71 @@ hotline::object!({
                 self.conversation.push_str(&response);
             }
 
-            // Update history
+            self.refresh_history();
+        }
+
+        // Shows the conversation scrolled to the bottom, archiving older turns first if it grew too long
+        fn refresh_history(&mut self) {
+            self.archive_old_turns();
+            self.shown_archives = 0;
             if let Some(ref mut history) = self.history_area {
                 history.set_text(self.conversation.clone());
-                let line_count = self.conversation.lines().count();
-                history.set_scroll_offset((line_count as f64 - 10.0).max(0.0) * 20.0);
+                // Scrolling clamps at the last wrapped row, so this lands on the bottom
+                history.scroll_by(f64::INFINITY);
+            }
+        }
+
+        // Writes all but the most recent turns to a new archive file, keeping about half the limit
+        // in memory so this doesn't run on every message. The last turn always stays.
+        fn archive_old_turns(&mut self) {
+            if self.max_conversation_len == 0 || self.conversation.len() <= self.max_conversation_len {
+                return;
+            }
+            let keep_from = self.conversation.len() - self.max_conversation_len / 2;
+            let turns: Vec<usize> = self
+                .conversation
+                .match_indices("\n\nUser: ")
+                .chain(self.conversation.match_indices("\n\nAssistant: "))
+                .map(|(i, _)| i)
+                .collect();
+            let Some(split) = turns.iter().filter(|&&i| i >= keep_from).min().or(turns.iter().max()).copied() else {
+                return; // A single turn has nowhere to split
+            };
+
+            let millis = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or_default();
+            let path = std::path::Path::new(&self.archive_dir).join(format!("chat-{}.txt", millis));
+            let written = std::fs::create_dir_all(&self.archive_dir)
+                .and_then(|_| std::fs::write(&path, &self.conversation[..split]));
+            match written {
+                Ok(()) => {
+                    self.archives.push(path.to_string_lossy().into_owned());
+                    self.conversation.drain(..split + 2);
+                }
+                Err(e) => eprintln!(
+                    "ChatInterface: keeping old turns in memory, archiving to {} failed: {}",
+                    path.display(),
+                    e
+                ),
+            }
+        }
+
+        // Prepends the newest archive not yet shown, for scrolling up past the top of the history
+        fn load_older_archive(&mut self) {
+            if self.shown_archives >= self.archives.len() {
+                return;
+            }
+            let path = self.archives[self.archives.len() - 1 - self.shown_archives].clone();
+            self.shown_archives += 1;
+            match std::fs::read_to_string(&path) {
+                Ok(text) => {
+                    if let Some(ref mut history) = self.history_area {
+                        history.prepend_text(&(text + "\n\n"));
+                    }
+                }
+                Err(e) => eprintln!("ChatInterface: skipping chat archive {}: {}", path, e),
             }
         }
 
@@ -207,6 +415,7 @@ hotline::object!({
             let mut history = TextArea::new();
             history.set_editable(false);
             history.set_show_cursor(false);
+            history.set_word_wrap(true); // Replies are long paragraphs
             history.set_background_color(13); // dark gray
             self.set_history_area(&history);
 
@@ -216,6 +425,20 @@ hotline::object!({
             input.set_show_cursor(true);
             input.set_background_color(38); // slightly lighter gray
             self.set_input_area(&input);
+
+            let mut picker = Dropdown::new();
+            picker.set_options(MODELS.iter().map(|m| m.to_string()).collect());
+            picker.set_width(220.0);
+            self.model_picker = Some(picker);
+        }
+
+        pub fn set_double_click_ms(&mut self, ms: u64) {
+            if let Some(ref mut history) = self.history_area {
+                history.set_double_click_ms(ms);
+            }
+            if let Some(ref mut input) = self.input_area {
+                input.set_double_click_ms(ms);
+            }
         }
 
         pub fn update_scroll(&mut self) {
@@ -237,6 +460,66 @@ hotline::object!({
             }
         }
 
+        // Text areas and overlays, clipped to the panel; only the model picker's list may drop past it
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if let Some((x, y, w, h)) = self.bounds.as_ref().map(|bounds| bounds.clone().bounds()) {
+                if let Some(registry) = self.get_registry() {
+                    ::hotline::set_library_registry(registry);
+                }
+                let theme = self.registry().theme();
+                gpu_renderer.push_clip_rect(x as f32, y as f32, w as f32, h as f32);
+
+                if let Some(ref mut history) = self.history_area {
+                    history.render_gpu(gpu_renderer);
+                }
+                let sep_y = y + h - self.input_height - self.separator_height;
+                let sep_color = [77.0 / 255.0, 77.0 / 255.0, 77.0 / 255.0, 1.0];
+                gpu_renderer.add_solid_rect(x as f32, sep_y as f32, w as f32, self.separator_height as f32, sep_color);
+                if let Some(ref mut input) = self.input_area {
+                    input.render_gpu(gpu_renderer);
+                }
+
+                // Matches the TextArea's text inset, so the placeholder sits where typing starts
+                let input_y = y + h - self.input_height + 10.0;
+
+                let idle =
+                    self.input_area.as_ref().is_some_and(|input| !input.is_focused() && input.get_text().is_empty());
+                if idle && !self.placeholder.is_empty() {
+                    let tr = self.placeholder_renderer.get_or_insert_with(TextRenderer::new);
+                    tr.set_text(self.placeholder.clone());
+                    tr.set_color(theme.dim_text);
+                    tr.set_x(x + 10.0);
+                    tr.set_y(input_y);
+                    tr.render_gpu(gpu_renderer);
+                }
+
+                let status = if self.waiting_for_response {
+                    const FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+                    let elapsed = self.request_started.map_or(0, |start| start.elapsed().as_millis());
+                    let text = format!("Waiting for reply {}", FRAMES[(elapsed / 100) as usize % FRAMES.len()]);
+                    Some((text, theme.header_text))
+                } else if let Some(left) = self.send_cooldown().filter(|_| self.send_refused) {
+                    Some((format!("Wait {}s to send", left.as_secs() + 1), theme.accent_text()))
+                } else {
+                    self.send_refused = false;
+                    None
+                };
+                if let Some((text, color)) = status {
+                    let tr = self.spinner_renderer.get_or_insert_with(TextRenderer::new);
+                    let width = tr.measure_text(&text);
+                    tr.set_text(text);
+                    tr.set_color(color);
+                    tr.set_x(x + w - width - 18.0); // Clear of the scroll bar
+                    tr.set_y(input_y);
+                    tr.render_gpu(gpu_renderer);
+                }
+                gpu_renderer.pop_clip_rect();
+            }
+            if let Some(ref mut picker) = self.model_picker {
+                picker.render_gpu(gpu_renderer);
+            }
+        }
+
         pub fn register_atlases(&mut self, gpu_renderer: &mut GPURenderer) {
             if let Some(ref mut history) = self.history_area {
                 history.register_atlases(gpu_renderer);
@@ -250,12 +533,11 @@ hotline::object!({
             if let Some(ref bounds) = self.bounds {
                 let bounds_clone = bounds.clone();
                 let (x, y, w, h) = bounds_clone.bounds();
+                // The text areas clip to their own rects inside this one
+                gpu_renderer.push_clip_rect(x, y, w, h);
 
-                // generate commands for history area
+                // generate commands for history area, laid out by set_rect
                 if let Some(ref mut history) = self.history_area {
-                    let mut history_bounds = Rect::new();
-                    history_bounds.initialize(x, y, w, h - self.input_height - self.separator_height);
-                    history.set_rect(history_bounds);
                     history.generate_commands(gpu_renderer);
                 }
 
@@ -276,11 +558,9 @@ hotline::object!({
 
                 // generate commands for input area
                 if let Some(ref mut input) = self.input_area {
-                    let mut input_bounds = Rect::new();
-                    input_bounds.initialize(x, y + h - self.input_height, w, self.input_height);
-                    input.set_rect(input_bounds);
                     input.generate_commands(gpu_renderer);
                 }
+                gpu_renderer.pop_clip_rect();
             }
         }
     }
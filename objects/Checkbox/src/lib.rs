@@ -34,6 +34,10 @@ hotline::object!({
             self.checked
         }
 
+        pub fn contains_point(&mut self, x: f64, y: f64) -> bool {
+            self.rect.as_mut().is_some_and(|r| r.contains_point(x, y))
+        }
+
         pub fn handle_mouse_down(&mut self, x: f64, y: f64) {
             if let Some(ref mut r) = self.rect {
                 if r.contains_point(x, y) {
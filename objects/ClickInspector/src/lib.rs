@@ -1,12 +1,29 @@
 hotline::object!({
     #[derive(Default)]
     pub struct ClickInspector {
-        items: Vec<String>,
+        items: Vec<String>, // Each 2 leading spaces nests a level; unindented lines start a section
         renderers: Vec<TextRenderer>,
         #[default(10.0)]
         x: f64,
         #[default(120.0)]
         y: f64,
+        #[setter]
+        #[default(200.0)]
+        width: f64,
+        #[setter]
+        #[default(16.0)]
+        item_height: f64,
+        #[setter]
+        #[default(6.0)]
+        padding: f64, // Inside the panel edge, and the gap before each section after the first
+        #[setter]
+        #[default(12.0)]
+        indent: f64, // Per nesting level
+        // None follows the registry's theme
+        header_color: Option<(u8, u8, u8, u8)>,
+        text_color: Option<(u8, u8, u8, u8)>,
+        background_color: Option<[f32; 4]>,
+        separator_color: Option<[f32; 4]>,
         dragging: bool,
         drag_offset_x: f64,
         drag_offset_y: f64,
@@ -14,20 +31,72 @@ hotline::object!({
     }
 
     impl ClickInspector {
+        pub fn set_header_color(&mut self, color: (u8, u8, u8, u8)) {
+            self.header_color = Some(color);
+        }
+
+        pub fn set_text_color(&mut self, color: (u8, u8, u8, u8)) {
+            self.text_color = Some(color);
+        }
+
+        pub fn set_background_color(&mut self, color: [f32; 4]) {
+            self.background_color = Some(color);
+        }
+
+        pub fn set_separator_color(&mut self, color: [f32; 4]) {
+            self.separator_color = Some(color);
+        }
+
+        fn theme(&self) -> ::hotline::Theme {
+            self.registry().theme()
+        }
+
         fn ensure_renderers(&mut self) {
             if let Some(registry) = self.get_registry() {
                 ::hotline::set_library_registry(registry);
             }
             if self.renderers.len() != self.items.len() {
                 self.renderers.clear();
-                for item in &self.items {
-                    self.renderers.push(TextRenderer::new().with_text(item.clone()).with_color((255, 255, 255, 255)));
+                for _ in &self.items {
+                    self.renderers.push(TextRenderer::new());
                 }
-            } else {
-                for (renderer, item) in self.renderers.iter_mut().zip(&self.items) {
-                    renderer.set_text(item.clone());
+            }
+            let theme = self.theme();
+            let header_color = self.header_color.unwrap_or(theme.header_text);
+            let text_color = self.text_color.unwrap_or(theme.text);
+            for (i, item) in self.items.iter().enumerate() {
+                let color = if self.is_header(i) { header_color } else { text_color };
+                self.renderers[i].set_text(item.trim_start().to_string());
+                self.renderers[i].set_color(color);
+            }
+        }
+
+        // Section starts and any line with nested lines under it
+        fn is_header(&self, index: usize) -> bool {
+            let level = depth(&self.items[index]);
+            level == 0 || self.items.get(index + 1).is_some_and(|next| depth(next) > level)
+        }
+
+        // (x, y) of each item's text, plus the y of each section separator
+        fn layout(&self) -> (Vec<(f64, f64)>, Vec<f64>) {
+            let mut positions = Vec::with_capacity(self.items.len());
+            let mut separators = Vec::new();
+            let mut y = self.y + self.padding;
+            for (i, item) in self.items.iter().enumerate() {
+                let level = depth(item);
+                if level == 0 && i > 0 {
+                    separators.push(y + self.padding / 2.0);
+                    y += self.padding;
                 }
+                positions.push((self.x + self.padding + level as f64 * self.indent, y));
+                y += self.item_height;
             }
+            (positions, separators)
+        }
+
+        fn height(&self) -> f64 {
+            let sections = self.items.iter().skip(1).filter(|item| depth(item) == 0).count();
+            self.items.len() as f64 * self.item_height + (sections as f64 + 2.0) * self.padding
         }
 
         pub fn open(&mut self, items: Vec<String>) {
@@ -57,10 +126,7 @@ hotline::object!({
             if !self.visible {
                 return false;
             }
-            let item_height = 16.0;
-            let width = 200.0;
-            let height = item_height * self.items.len() as f64;
-            if x >= self.x && x <= self.x + width && y >= self.y && y <= self.y + height {
+            if x >= self.x && x <= self.x + self.width && y >= self.y && y <= self.y + self.height() {
                 self.dragging = true;
                 self.drag_offset_x = x - self.x;
                 self.drag_offset_y = y - self.y;
@@ -85,12 +151,39 @@ hotline::object!({
                 return;
             }
             self.ensure_renderers();
-            let item_height = 16.0;
-            for (i, renderer) in self.renderers.iter_mut().enumerate() {
-                renderer.set_x(self.x);
-                renderer.set_y(self.y + i as f64 * item_height);
+            let (positions, _) = self.layout();
+            for (renderer, (x, y)) in self.renderers.iter_mut().zip(positions) {
+                renderer.set_x(x);
+                renderer.set_y(y);
                 renderer.render(buffer, buffer_width, buffer_height, pitch);
             }
         }
+
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if !self.visible {
+                return;
+            }
+            self.ensure_renderers();
+            let theme = self.theme();
+            let separator = self.separator_color.unwrap_or(theme.border);
+            let (x, y, w, h) = (self.x as f32, self.y as f32, self.width as f32, self.height() as f32);
+            gpu_renderer.add_solid_rect(x, y, w, h, self.background_color.unwrap_or(theme.panel));
+            gpu_renderer.add_solid_rect(x, y, 1.0, h, separator);
+
+            let (positions, separators) = self.layout();
+            for sep_y in separators {
+                let inset = self.padding as f32;
+                gpu_renderer.add_solid_rect(x + inset, sep_y as f32, w - 2.0 * inset, 1.0, separator);
+            }
+            for (renderer, (x, y)) in self.renderers.iter_mut().zip(positions) {
+                renderer.set_x(x);
+                renderer.set_y(y);
+                renderer.render_gpu(gpu_renderer);
+            }
+        }
     }
 });
+
+fn depth(item: &str) -> usize {
+    (item.len() - item.trim_start_matches(' ').len()) / 2
+}
@@ -1,3 +1,19 @@
+// One step of the editor's undo timeline: the whole buffer after an edit
+#[derive(Clone, Default)]
+struct EditSnapshot {
+    text: String,
+    cursor: usize,
+    label: &'static str, // What produced this state, e.g. "Typing", shown in the timeline
+}
+
+mod rust_syntax;
+#[cfg(test)]
+mod test_rust_syntax;
+
+// Rows of the timeline panel around the current step
+const TIMELINE_ROWS: usize = 10;
+const TIMELINE_ROW_HEIGHT: f64 = 16.0;
+
 hotline::object!({
     #[derive(Clone, Default)]
     pub struct CodeEditor {
@@ -7,6 +23,29 @@ hotline::object!({
         highlight: Option<HighlightLens>,
         text_area: Option<TextArea>,
         file_menu: Option<ContextMenu>,
+        find_open: bool,
+        find_query: String,
+        find_matches: Vec<(usize, usize)>,
+        find_current: usize,
+        disk_text: Option<String>, // File content as last opened or saved, to tell our edits apart from disk changes
+        disk_conflict: Option<String>, // Disk content that changed under unsaved edits, until one side is picked
+        #[setter]
+        autosave_on_reload: bool, // Settle conflicts by writing the buffer back instead of asking
+        #[serde(skip)]
+        history: Vec<crate::EditSnapshot>, // Undo timeline for the open file; history_pos is the buffer
+        #[serde(skip)]
+        history_pos: usize,
+        #[serde(skip)]
+        history_stale: bool, // The newest step hasn't caught up with a run of merged edits yet
+        #[setter]
+        #[default(100)]
+        max_undo_depth: usize, // Steps kept behind the newest edit; 0 keeps every step of the session
+        timeline_open: bool,
+        #[setter]
+        #[default(true)]
+        syntax_highlighting: bool, // Colors .rs files; other files stay in the text color
+        #[serde(skip)]
+        syntax: Option<(Vec<::hotline::ColorSpan>, usize)>, // Spans last lexed and the byte length they were lexed at
     }
 
     impl CodeEditor {
@@ -34,8 +73,14 @@ hotline::object!({
             }
         }
 
-        pub fn is_focused(&mut self) -> bool {
-            if let Some(ref mut ta) = self.text_area { ta.is_focused() } else { false }
+        pub fn set_double_click_ms(&mut self, ms: u64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_double_click_ms(ms);
+            }
+        }
+
+        pub fn is_focused(&self) -> bool {
+            self.text_area.as_ref().is_some_and(|ta| ta.is_focused())
         }
 
         pub fn contains_point(&mut self, x: f64, y: f64) -> bool {
@@ -43,6 +88,15 @@ hotline::object!({
         }
 
         pub fn handle_mouse_down(&mut self, x: f64, y: f64) -> bool {
+            if self.timeline_open && x >= self.timeline_x() {
+                let row =
+                    self.timeline_rows().into_iter().find(|&(_, row_y)| y >= row_y && y < row_y + TIMELINE_ROW_HEIGHT);
+                if let Some((index, _)) = row {
+                    self.jump_to_history(index);
+                    return true;
+                }
+            }
+
             if let Some(ref mut menu) = self.file_menu {
                 if menu.is_visible() {
                     if let Some(sel) = menu.handle_mouse_down(x, y) {
@@ -85,8 +139,11 @@ hotline::object!({
 
             self.initialize();
             if let Some(ref mut ta) = self.text_area {
-                ta.set_text(text);
+                ta.set_text(text.clone());
             }
+            self.disk_text = Some(text);
+            self.disk_conflict = None;
+            self.reset_history("Opened");
             Ok(())
         }
 
@@ -95,6 +152,8 @@ hotline::object!({
                 if let Some(ref mut ta) = self.text_area {
                     let text = ta.get_text();
                     std::fs::write(path, &text).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+                    self.disk_text = Some(text);
+                    self.disk_conflict = None;
                 }
                 Ok(())
             } else {
@@ -102,6 +161,206 @@ hotline::object!({
             }
         }
 
+        pub fn is_modified(&self) -> bool {
+            match (&self.disk_text, &self.text_area) {
+                (Some(disk), Some(ta)) => ta.get_text() != *disk,
+                _ => false,
+            }
+        }
+
+        pub fn has_disk_conflict(&self) -> bool {
+            self.disk_conflict.is_some()
+        }
+
+        // Call after a reload, which usually means a source file was saved. An unmodified buffer follows
+        // the disk; a modified one is written back with autosave_on_reload, otherwise it waits for
+        // keep_buffer or adopt_disk.
+        pub fn sync_with_disk(&mut self) -> Result<(), String> {
+            let Some(path) = self.file_path.clone() else {
+                return Ok(());
+            };
+            let disk = std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+            if self.disk_text.as_deref() == Some(disk.as_str()) {
+                return Ok(());
+            }
+            if !self.is_modified() {
+                self.load_disk_text(disk);
+            } else if self.autosave_on_reload {
+                self.save()?;
+            } else {
+                self.disk_conflict = Some(disk);
+            }
+            Ok(())
+        }
+
+        // Keep the edits; they stay modified against the new disk content until saved
+        pub fn keep_buffer(&mut self) {
+            if let Some(disk) = self.disk_conflict.take() {
+                self.disk_text = Some(disk);
+            }
+        }
+
+        pub fn adopt_disk(&mut self) {
+            if let Some(disk) = self.disk_conflict.take() {
+                self.load_disk_text(disk);
+            }
+        }
+
+        fn load_disk_text(&mut self, disk: String) {
+            if let Some(ref mut ta) = self.text_area {
+                let cursor = ta.get_cursor();
+                ta.set_text(disk.clone());
+                ta.set_cursor(cursor);
+                ta.clear_selection();
+            }
+            self.disk_text = Some(disk);
+            self.record_edit("Loaded from disk", false);
+            if self.find_open {
+                self.refresh_find();
+            }
+        }
+
+        fn snapshot(&self, label: &'static str) -> Option<crate::EditSnapshot> {
+            let ta = self.text_area.as_ref()?;
+            Some(crate::EditSnapshot { text: ta.get_text(), cursor: ta.get_cursor(), label })
+        }
+
+        fn reset_history(&mut self, label: &'static str) {
+            self.refresh_syntax();
+            self.history = self.snapshot(label).into_iter().collect();
+            self.history_pos = 0;
+            self.history_stale = false;
+        }
+
+        // Call after every buffer edit. Redo steps past the current one are dropped; `merge` folds the
+        // edit into the step before it when that came from the same kind of edit, so typing a word is one step.
+        fn record_edit(&mut self, label: &'static str, merge: bool) {
+            self.refresh_syntax();
+            // Only the newest step grows; after an undo, the step being returned to stays intact
+            let at_newest = self.history_pos > 0 && self.history_pos + 1 == self.history.len();
+            if merge && at_newest && self.history[self.history_pos].label == label {
+                // Snapshotted when the run ends, rather than copying the buffer every keystroke
+                self.history_stale = true;
+                return;
+            }
+            self.flush_history();
+            let Some(state) = self.snapshot(label) else {
+                return;
+            };
+            if self.history.get(self.history_pos).is_some_and(|current| current.text == state.text) {
+                return;
+            }
+            self.history.truncate(self.history_pos + 1);
+            self.history.push(state);
+            if self.max_undo_depth > 0 && self.history.len() > self.max_undo_depth + 1 {
+                self.history.drain(..self.history.len() - self.max_undo_depth - 1);
+            }
+            self.history_pos = self.history.len() - 1;
+        }
+
+        // Catches the newest step up with the buffer after a run of merged edits
+        fn flush_history(&mut self) {
+            if std::mem::take(&mut self.history_stale) {
+                if let Some(state) = self.snapshot(self.history[self.history_pos].label) {
+                    self.history[self.history_pos] = state;
+                }
+            }
+        }
+
+        // Re-lexes only around what changed since the last refresh, keeping the spans either side
+        fn refresh_syntax(&mut self) {
+            let rust = self.syntax_highlighting && self.file_path.as_deref().is_some_and(|p| p.ends_with(".rs"));
+            let Some(ref mut ta) = self.text_area else {
+                return;
+            };
+            let unchanged = ta.take_unchanged_ends();
+            if !rust {
+                self.syntax = None;
+                ta.set_colored_spans(Vec::new());
+                return;
+            }
+            let len = ta.byte_len();
+            let spans = match (self.syntax.take(), unchanged) {
+                (Some((spans, _)), None) => {
+                    self.syntax = Some((spans, len));
+                    return;
+                }
+                (Some((old, old_len)), Some(ends)) => {
+                    crate::rust_syntax::relex_spans(&old, old_len, len, ends, |start, end| ta.text_in_bytes(start, end))
+                }
+                (None, _) => crate::rust_syntax::rust_spans(&ta.get_text()),
+            };
+            ta.set_colored_spans(spans.clone());
+            self.syntax = Some((spans, len));
+        }
+
+        pub fn undo(&mut self) -> bool {
+            self.history_pos > 0 && self.jump_to_history(self.history_pos - 1)
+        }
+
+        pub fn redo(&mut self) -> bool {
+            self.jump_to_history(self.history_pos + 1)
+        }
+
+        // Restores the buffer as it was after timeline step `index`; later steps stay for redo until the next edit
+        pub fn jump_to_history(&mut self, index: usize) -> bool {
+            self.flush_history();
+            let Some(state) = self.history.get(index).cloned() else {
+                return false;
+            };
+            self.history_pos = index;
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_text(state.text);
+                ta.set_cursor(state.cursor);
+                ta.clear_selection();
+            }
+            self.refresh_syntax();
+            if self.find_open {
+                self.refresh_find();
+            }
+            true
+        }
+
+        pub fn history_len(&self) -> usize {
+            self.history.len()
+        }
+
+        pub fn history_position(&self) -> usize {
+            self.history_pos
+        }
+
+        pub fn toggle_timeline(&mut self) {
+            self.timeline_open = !self.timeline_open;
+        }
+
+        pub fn is_timeline_open(&self) -> bool {
+            self.timeline_open
+        }
+
+        // Timeline steps shown in the panel with the y of each row, newest at the top
+        fn timeline_rows(&self) -> Vec<(usize, f64)> {
+            let Some((_, y, _, _)) = self.rect.as_ref().map(|rect| rect.clone().bounds()) else {
+                return Vec::new();
+            };
+            if self.history.is_empty() {
+                return Vec::new();
+            }
+            let newest = (self.history_pos + TIMELINE_ROWS / 2).max(TIMELINE_ROWS - 1).min(self.history.len() - 1);
+            let oldest = (newest + 1).saturating_sub(TIMELINE_ROWS);
+            (oldest..=newest)
+                .rev()
+                .enumerate()
+                .map(|(row, index)| (index, y + 20.0 + (row + 1) as f64 * TIMELINE_ROW_HEIGHT))
+                .collect()
+        }
+
+        fn timeline_x(&self) -> f64 {
+            self.rect.as_ref().map_or(0.0, |rect| {
+                let (x, _, w, _) = rect.clone().bounds();
+                x + w - 180.0
+            })
+        }
+
         pub fn open_file_menu(&mut self, x: f64, y: f64) -> Result<(), String> {
             let mut menu = self.file_menu.take().unwrap_or_else(ContextMenu::new);
             let mut items = Vec::new();
@@ -120,22 +379,175 @@ hotline::object!({
             Ok(())
         }
 
+        pub fn open_find(&mut self) {
+            self.find_open = true;
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_focused(true);
+            }
+            self.refresh_find();
+        }
+
+        pub fn close_find(&mut self) {
+            self.find_open = false;
+            self.find_matches.clear();
+            self.find_current = 0;
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_highlights(Vec::new());
+                ta.set_active_highlight(None);
+            }
+        }
+
+        pub fn toggle_find(&mut self) {
+            if self.find_open { self.close_find() } else { self.open_find() }
+        }
+
+        pub fn is_find_open(&self) -> bool {
+            self.find_open
+        }
+
+        pub fn find_query(&self) -> String {
+            self.find_query.clone()
+        }
+
+        pub fn set_find_query(&mut self, query: String) {
+            self.find_query = query;
+            self.refresh_find();
+        }
+
+        pub fn find_next(&mut self) {
+            if !self.find_matches.is_empty() {
+                self.find_current = (self.find_current + 1) % self.find_matches.len();
+                self.select_current_match();
+            }
+        }
+
+        pub fn find_previous(&mut self) {
+            if !self.find_matches.is_empty() {
+                self.find_current = (self.find_current + self.find_matches.len() - 1) % self.find_matches.len();
+                self.select_current_match();
+            }
+        }
+
+        // "3 of 12" style counter for the find bar
+        pub fn match_counter(&self) -> String {
+            if self.find_matches.is_empty() {
+                "0 of 0".to_string()
+            } else {
+                format!("{} of {}", self.find_current + 1, self.find_matches.len())
+            }
+        }
+
+        // Recompute matches on every query edit so highlights follow typing
+        fn refresh_find(&mut self) {
+            let Some(ref mut ta) = self.text_area else {
+                return;
+            };
+            self.find_matches = ta.find_all(&self.find_query);
+
+            // Keep the current match at or after the cursor as the query narrows
+            let cursor = ta.get_cursor();
+            let anchor = ta.get_selection().map(|(s, e)| s.min(e)).unwrap_or(cursor);
+            self.find_current = self.find_matches.iter().position(|(s, _)| *s >= anchor).unwrap_or(0);
+
+            ta.set_highlights(self.find_matches.clone());
+            if self.find_matches.is_empty() {
+                ta.set_active_highlight(None);
+            } else {
+                self.select_current_match();
+            }
+        }
+
+        fn select_current_match(&mut self) {
+            if let (Some(ta), Some(&(start, end))) = (self.text_area.as_mut(), self.find_matches.get(self.find_current))
+            {
+                ta.set_active_highlight(Some(self.find_current));
+                ta.set_selection(Some((start, end)));
+                ta.set_cursor(end);
+            }
+        }
+
         pub fn insert_char(&mut self, ch: char) {
+            if self.find_open {
+                self.find_query.push(ch);
+                self.refresh_find();
+                return;
+            }
             if let Some(ref mut ta) = self.text_area {
                 ta.insert_char(ch);
             }
+            // A space or tab closes the word, so the next one undoes separately
+            self.record_edit("Typing", !ch.is_whitespace());
         }
 
         pub fn insert_newline(&mut self) {
+            if self.find_open {
+                self.find_next();
+                return;
+            }
             if let Some(ref mut ta) = self.text_area {
                 ta.insert_newline();
             }
+            self.record_edit("Newline", false);
+        }
+
+        pub fn copy_selection(&self) -> Option<String> {
+            self.text_area.as_ref()?.copy_selection()
+        }
+
+        pub fn cut_selection(&mut self) -> Option<String> {
+            if self.find_open {
+                return None;
+            }
+            let cut = self.text_area.as_mut()?.cut_selection();
+            if cut.is_some() {
+                self.record_edit("Cut", false);
+            }
+            cut
+        }
+
+        // Into the find query while the find bar is open, keeping only the first line
+        pub fn paste(&mut self, text: &str) {
+            if self.find_open {
+                self.find_query.push_str(text.lines().next().unwrap_or(""));
+                self.refresh_find();
+                return;
+            }
+            if let Some(ref mut ta) = self.text_area {
+                ta.insert_text(text);
+            }
+            self.record_edit("Paste", false);
+        }
+
+        pub fn duplicate_line(&mut self) {
+            if self.find_open {
+                return;
+            }
+            if let Some(ref mut ta) = self.text_area {
+                ta.duplicate_line();
+            }
+            self.record_edit("Duplicate line", false);
+        }
+
+        pub fn move_lines(&mut self, up: bool) {
+            if self.find_open {
+                return;
+            }
+            if let Some(ref mut ta) = self.text_area {
+                ta.move_lines(up);
+            }
+            self.record_edit("Move lines", true);
         }
 
         pub fn backspace(&mut self) {
+            if self.find_open {
+                self.find_query.pop();
+                self.refresh_find();
+                return;
+            }
             if let Some(ref mut ta) = self.text_area {
                 ta.backspace();
             }
+            self.record_edit("Delete", true);
         }
 
         pub fn move_cursor_left(&mut self, shift: bool) {
@@ -168,28 +580,62 @@ hotline::object!({
             }
         }
 
+        pub fn scroll_horizontal_by(&mut self, delta: f64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.scroll_horizontal_by(delta);
+            }
+        }
+
         pub fn add_scroll_velocity(&mut self, delta: f64) {
             if let Some(ref mut ta) = self.text_area {
                 ta.add_scroll_velocity(delta);
             }
         }
 
+        pub fn scroll_wheel(&mut self, delta: f64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.scroll_wheel(delta);
+            }
+        }
+
+        pub fn scroll_wheel_horizontal(&mut self, delta: f64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.scroll_wheel_horizontal(delta);
+            }
+        }
+
+        pub fn set_scroll_multiplier(&mut self, multiplier: f64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_scroll_multiplier(multiplier);
+            }
+        }
+
+        pub fn set_scroll_acceleration(&mut self, acceleration: f64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_scroll_acceleration(acceleration);
+            }
+        }
+
+        pub fn set_highlight_selected_word(&mut self, highlight: bool) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_highlight_selected_word(highlight);
+            }
+        }
+
         pub fn update_scroll(&mut self) {
             if let Some(ref mut ta) = self.text_area {
                 ta.update_scroll();
             }
         }
 
-        pub fn render(&mut self, buffer: &mut [u8], buffer_width: i64, buffer_height: i64, pitch: i64) {
+        // File name, find bar and undo timeline, positioned for either render path
+        fn overlay_labels(&self) -> Vec<TextRenderer> {
             if let Some(registry) = self.get_registry() {
                 ::hotline::set_library_registry(registry);
             }
+            let mut labels = Vec::new();
 
-            if let Some(ref mut ta) = self.text_area {
-                ta.render(buffer, buffer_width, buffer_height, pitch);
-            }
-
-            // Draw file name at top of the rect
+            // File name at top of the rect
             if let Some(ref rect) = self.rect {
                 let (x, y, _w, _h) = rect.clone().bounds();
                 if let Some(name) = self.file_name.clone().or_else(|| {
@@ -200,17 +646,69 @@ hotline::object!({
                             .unwrap_or_else(|| path.clone())
                     })
                 }) {
-                    if let Some(registry) = self.get_registry() {
-                        ::hotline::set_library_registry(registry);
-                    }
                     let mut tr = TextRenderer::new();
+                    let name = if self.has_disk_conflict() {
+                        format!("{} *  changed on disk: Enter loads it, Esc keeps yours", name)
+                    } else if self.is_modified() {
+                        format!("{} *", name)
+                    } else {
+                        name
+                    };
                     tr.set_text(name);
                     tr.set_x(x + 10.0);
                     tr.set_y(y + 2.0);
-                    tr.render(buffer, buffer_width, buffer_height, pitch);
+                    labels.push(tr);
                 }
             }
 
+            // Find bar with match counter
+            if let Some(rect) = self.rect.as_ref().filter(|_| self.find_open) {
+                let (x, y, w, _h) = rect.clone().bounds();
+                let mut tr = TextRenderer::new();
+                tr.set_text(format!("Find: {}  {}", self.find_query, self.match_counter()));
+                tr.set_x(x + w - 180.0);
+                tr.set_y(y + 2.0);
+                labels.push(tr);
+            }
+
+            // Undo timeline, current step marked; clicking a row jumps there
+            if self.timeline_open {
+                let x = self.timeline_x();
+                let rows = self.timeline_rows();
+                let mut header = TextRenderer::new();
+                header.set_text(format!("History {}/{}", self.history_pos + 1, self.history.len()));
+                header.set_color((200, 200, 255, 255));
+                header.set_x(x);
+                header.set_y(rows.first().map_or(0.0, |&(_, y)| y) - TIMELINE_ROW_HEIGHT);
+                labels.push(header);
+                for (index, y) in rows {
+                    let marker = if index == self.history_pos { ">" } else { " " };
+                    let color = if index > self.history_pos { (128, 128, 128, 255) } else { (255, 255, 255, 255) };
+                    let mut tr = TextRenderer::new();
+                    tr.set_text(format!("{} {:>3} {}", marker, index + 1, self.history[index].label));
+                    tr.set_color(color);
+                    tr.set_x(x);
+                    tr.set_y(y);
+                    labels.push(tr);
+                }
+            }
+
+            labels
+        }
+
+        pub fn render(&mut self, buffer: &mut [u8], buffer_width: i64, buffer_height: i64, pitch: i64) {
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+
+            if let Some(ref mut ta) = self.text_area {
+                ta.render(buffer, buffer_width, buffer_height, pitch);
+            }
+
+            for mut label in self.overlay_labels() {
+                label.render(buffer, buffer_width, buffer_height, pitch);
+            }
+
             // Draw highlight
             if self.is_focused() {
                 if let Some(ref mut hl) = self.highlight {
@@ -227,11 +725,31 @@ hotline::object!({
             }
         }
 
+        // The highlight lens only draws on the CPU, so it is left to render
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+
+            if let Some(ref mut ta) = self.text_area {
+                ta.render_gpu(gpu_renderer);
+            }
+
+            for mut label in self.overlay_labels() {
+                label.render_gpu(gpu_renderer);
+            }
+
+            if let Some(ref mut menu) = self.file_menu {
+                menu.render_gpu(gpu_renderer);
+            }
+        }
+
         // Delegation methods for convenience
         pub fn set_text(&mut self, text: String) {
             if let Some(ref mut ta) = self.text_area {
                 ta.set_text(text);
             }
+            self.record_edit("Replaced text", false);
         }
 
         pub fn update_text_color(&mut self, color: (u8, u8, u8, u8)) {
@@ -239,5 +757,23 @@ hotline::object!({
                 ta.update_text_color(color);
             }
         }
+
+        pub fn set_cursor_color(&mut self, color: (u8, u8, u8, u8)) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_cursor_color(color);
+            }
+        }
+
+        pub fn set_selection_color(&mut self, color: (u8, u8, u8, u8)) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_selection_color(color);
+            }
+        }
+
+        pub fn set_cursor_width(&mut self, width: f64) {
+            if let Some(ref mut ta) = self.text_area {
+                ta.set_cursor_width(width);
+            }
+        }
     }
 });
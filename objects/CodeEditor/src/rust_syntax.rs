@@ -0,0 +1,184 @@
+// A lexer for coloring Rust source, not parsing it: it knows keywords, strings, comments and
+// numbers, and leaves everything else in the text color
+
+pub const KEYWORD_COLOR: (u8, u8, u8, u8) = (198, 120, 221, 255);
+pub const STRING_COLOR: (u8, u8, u8, u8) = (152, 195, 121, 255);
+pub const COMMENT_COLOR: (u8, u8, u8, u8) = (110, 118, 132, 255);
+pub const NUMBER_COLOR: (u8, u8, u8, u8) = (209, 154, 102, 255);
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern", "false", "fn",
+    "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "self", "Self",
+    "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where", "while",
+];
+
+// Bytes past its own end that a token can depend on: the longest char literal lookahead
+const LOOKAHEAD: usize = 16;
+
+// Sorted, non-overlapping byte ranges of `text` with their colors
+pub fn rust_spans(text: &str) -> Vec<hotline::ColorSpan> {
+    let mut spans = Vec::new();
+    lex(text, &mut spans, |_| false);
+    spans
+}
+
+// Spans after an edit that kept the first `prefix` and last `suffix` bytes of the `old_len` bytes
+// `old` was lexed from. Lexing restarts at the last old span ending well before the edit and stops
+// past it at the first point the old lexer was also between tokens; the old spans either side are
+// reused. `read(start, end)` returns the new text between two byte offsets.
+pub fn relex_spans(
+    old: &[hotline::ColorSpan],
+    old_len: usize,
+    new_len: usize,
+    (prefix, suffix): (usize, usize),
+    mut read: impl FnMut(usize, usize) -> String,
+) -> Vec<hotline::ColorSpan> {
+    let keep = old.partition_point(|&(_, end, _)| end + LOOKAHEAD <= prefix);
+    let restart = keep.checked_sub(1).map_or(0, |k| old[k].1);
+    let edit_end = new_len - suffix;
+    // Most edits settle within a line or two; an opened comment or string reads on until it closes
+    let mut reach = 256;
+    loop {
+        let window = read(restart, edit_end + reach);
+        let window_end = restart + window.len();
+        // Near the end of a cut-off window, a token may lex differently than with the text after it
+        let trusted = if window_end == new_len { new_len } else { window_end.saturating_sub(LOOKAHEAD) };
+        let mut spans = old[..keep].to_vec();
+        let mut resume = None;
+        // A byte past the edit, so the raw string lookbehind sees unchanged text as well
+        let resync = |i: usize| {
+            let at = restart + i;
+            if at <= edit_end || at > trusted {
+                return false;
+            }
+            let old_at = at + old_len - new_len;
+            let k = old.partition_point(|&(start, _, _)| start < old_at);
+            let between = old.get(k).is_some_and(|span| span.0 == old_at)
+                || k.checked_sub(1).is_some_and(|before| old[before].1 == old_at);
+            if between {
+                resume = Some(k);
+            }
+            between
+        };
+        let from = spans.len();
+        lex(&window, &mut spans, resync);
+        for span in &mut spans[from..] {
+            span.0 += restart;
+            span.1 += restart;
+        }
+        if let Some(k) = resume {
+            spans.extend(
+                old[k..].iter().map(|&(start, end, color)| (start + new_len - old_len, end + new_len - old_len, color)),
+            );
+            return spans;
+        }
+        if window_end == new_len {
+            return spans;
+        }
+        reach *= 4;
+    }
+}
+
+// Pushes the spans of `text`, stopping early if `resync(i)` accepts a byte `i` where a token could start
+fn lex(text: &str, spans: &mut Vec<hotline::ColorSpan>, mut resync: impl FnMut(usize) -> bool) {
+    let bytes = text.as_bytes();
+    let at = |i: usize| bytes.get(i).copied().unwrap_or(0);
+    let is_ident = |b: u8| b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80;
+    let mut i = 0;
+    while i < bytes.len() {
+        if resync(i) {
+            return;
+        }
+        let start = i;
+        let b = bytes[i];
+        if b == b'/' && at(i + 1) == b'/' {
+            i = text[i..].find('\n').map_or(bytes.len(), |n| i + n);
+            spans.push((start, i, COMMENT_COLOR));
+        } else if b == b'/' && at(i + 1) == b'*' {
+            // Block comments nest in Rust
+            let mut depth = 0;
+            while i < bytes.len() {
+                if at(i) == b'/' && at(i + 1) == b'*' {
+                    depth += 1;
+                    i += 2;
+                } else if at(i) == b'*' && at(i + 1) == b'/' {
+                    depth -= 1;
+                    i += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    i += 1;
+                }
+            }
+            spans.push((start, i.min(bytes.len()), COMMENT_COLOR));
+        } else if let Some(quote) = raw_string_quote(bytes, i) {
+            // r"..", r#".."#, br#".."#: no escapes, closed by the quote and as many hashes
+            let hashes = quote - i - if b == b'b' { 2 } else { 1 };
+            let closing = format!("\"{}", "#".repeat(hashes));
+            i = text[quote + 1..].find(&closing).map_or(bytes.len(), |n| quote + 1 + n + closing.len());
+            spans.push((start, i, STRING_COLOR));
+        } else if b == b'"' || (b == b'b' && at(i + 1) == b'"') {
+            i = if b == b'b' { i + 2 } else { i + 1 };
+            while i < bytes.len() && bytes[i] != b'"' {
+                i += if bytes[i] == b'\\' { 2 } else { 1 };
+            }
+            i = (i + 1).min(bytes.len());
+            spans.push((start, i, STRING_COLOR));
+        } else if b == b'\'' {
+            // A char literal closes within a few bytes; otherwise it's a lifetime, left uncolored
+            match char_literal_end(text, i) {
+                Some(end) => {
+                    i = end;
+                    spans.push((start, i, STRING_COLOR));
+                }
+                None => i += 1,
+            }
+        } else if b.is_ascii_digit() {
+            // Digits, suffixes and a decimal point, but not the `..` of a range
+            while i < bytes.len() && (is_ident(bytes[i]) || (bytes[i] == b'.' && at(i + 1).is_ascii_digit())) {
+                i += 1;
+            }
+            spans.push((start, i, NUMBER_COLOR));
+        } else if is_ident(b) {
+            while i < bytes.len() && is_ident(bytes[i]) {
+                i += 1;
+            }
+            if KEYWORDS.contains(&&text[start..i]) {
+                spans.push((start, i, KEYWORD_COLOR));
+            }
+        } else {
+            i += text[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+}
+
+// Byte index of the opening quote when a raw string starts at `i`
+fn raw_string_quote(bytes: &[u8], i: usize) -> Option<usize> {
+    // Part of a longer identifier, like the `r` ending `bar`
+    if i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_') {
+        return None;
+    }
+    let mut j = match (bytes.get(i), bytes.get(i + 1)) {
+        (Some(b'r'), _) => i + 1,
+        (Some(b'b'), Some(b'r')) => i + 2,
+        _ => return None,
+    };
+    while bytes.get(j) == Some(&b'#') {
+        j += 1;
+    }
+    (bytes.get(j) == Some(&b'"')).then_some(j)
+}
+
+// End of the char literal opening at `i`, like 'a', '\n' or '\u{1F600}'
+fn char_literal_end(text: &str, i: usize) -> Option<usize> {
+    let rest = &text[i + 1..];
+    let mut chars = rest.char_indices();
+    let (_, first) = chars.next()?;
+    if first == '\\' {
+        let close = rest[1..].find('\'')? + 1;
+        return (close <= 10).then_some(i + 1 + close + 1);
+    }
+    let (close, c) = chars.next()?;
+    (c == '\'').then_some(i + 1 + close + 1)
+}
@@ -0,0 +1,39 @@
+use crate::rust_syntax::{COMMENT_COLOR, KEYWORD_COLOR, NUMBER_COLOR, STRING_COLOR, relex_spans, rust_spans};
+
+#[test]
+fn test_rust_spans() {
+    let source = "fn main<'a>() { let s = r#\"x\"#; 1..2.5 } // done";
+    let spans: Vec<_> =
+        rust_spans(source).into_iter().map(|(start, end, color)| (&source[start..end], color)).collect();
+    assert_eq!(
+        spans,
+        vec![
+            ("fn", KEYWORD_COLOR),
+            ("let", KEYWORD_COLOR),
+            ("r#\"x\"#", STRING_COLOR),
+            ("1", NUMBER_COLOR),
+            ("2.5", NUMBER_COLOR),
+            ("// done", COMMENT_COLOR),
+        ]
+    );
+}
+
+// Re-lexing around an edit has to match lexing the whole edited text
+#[test]
+fn test_relex_spans() {
+    let source = format!("{}let c = 'x';\n/* note */ struct S;\n", "fn f() { let n = 1; } // one\n".repeat(40));
+    let mid = source.len() / 2;
+    // (byte, bytes removed, inserted): an opened comment, a reopened string, a char literal, ordinary typing
+    let edits =
+        [(mid, 0, "/*"), (mid, 0, "\""), (source.len() - 30, 1, ""), (4, 0, " 'a'"), (mid, 3, "if"), (0, 0, "r#\"")];
+    for (at, removed, inserted) in edits {
+        let old = rust_spans(&source);
+        let mut text = source.clone();
+        text.replace_range(at..at + removed, inserted);
+        let ends = (at, source.len() - at - removed);
+        let relexed = relex_spans(&old, source.len(), text.len(), ends, |start, end| {
+            text[start..end.min(text.len())].to_string()
+        });
+        assert_eq!(relexed, rust_spans(&text), "after inserting {:?} at {}", inserted, at);
+    }
+}
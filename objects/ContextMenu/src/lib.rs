@@ -6,6 +6,7 @@ hotline::object!({
         x: f64,
         y: f64,
         visible: bool,
+        highlighted: Option<usize>, // Keyboard-highlighted item
     }
 
     impl ContextMenu {
@@ -29,6 +30,7 @@ hotline::object!({
             self.x = x;
             self.y = y;
             self.visible = true;
+            self.highlighted = None;
         }
 
         pub fn open_with_items(&mut self, items: Vec<String>, x: f64, y: f64) {
@@ -36,10 +38,40 @@ hotline::object!({
             self.x = x;
             self.y = y;
             self.visible = true;
+            self.highlighted = None;
         }
 
         pub fn close(&mut self) {
             self.visible = false;
+            self.highlighted = None;
+        }
+
+        pub fn position(&self) -> (f64, f64) {
+            (self.x, self.y)
+        }
+
+        // Move the keyboard highlight by `delta` items, wrapping around
+        pub fn move_highlight(&mut self, delta: i64) {
+            if !self.visible || self.items.is_empty() {
+                return;
+            }
+            let len = self.items.len() as i64;
+            let next = match self.highlighted {
+                Some(i) => (i as i64 + delta).rem_euclid(len),
+                None if delta >= 0 => 0,
+                None => len - 1,
+            };
+            self.highlighted = Some(next as usize);
+        }
+
+        // Choose the highlighted item, closing the menu
+        pub fn activate(&mut self) -> Option<String> {
+            if !self.visible {
+                return None;
+            }
+            let result = self.highlighted.and_then(|i| self.items.get(i).cloned());
+            self.close();
+            result
         }
 
         pub fn set_items(&mut self, items: Vec<String>) {
@@ -71,7 +103,7 @@ hotline::object!({
                 }
                 cursor_y += item_height;
             }
-            self.visible = false;
+            self.close();
             result
         }
 
@@ -101,11 +133,50 @@ hotline::object!({
                 }
             }
 
+            // Brighten the keyboard-highlighted row
+            if let Some(i) = self.highlighted {
+                let row_start = (self.y + i as f64 * item_height).max(0.0).floor() as i64;
+                let row_end = (self.y + (i + 1) as f64 * item_height).min(buffer_height as f64).ceil() as i64;
+                for y in row_start..row_end {
+                    for x in x_start..x_end {
+                        let offset = (y * pitch + x * 4) as usize;
+                        if offset + 3 < buffer.len() {
+                            buffer[offset] = 128;
+                            buffer[offset + 1] = 64;
+                            buffer[offset + 2] = 64;
+                            buffer[offset + 3] = 200;
+                        }
+                    }
+                }
+            }
+
             for (i, renderer) in self.renderers.iter_mut().enumerate() {
                 renderer.set_x(self.x);
                 renderer.set_y(self.y + i as f64 * item_height);
                 renderer.render(buffer, buffer_width, buffer_height, pitch);
             }
         }
+
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if !self.visible {
+                return;
+            }
+            self.initialize();
+            let theme = self.registry().theme();
+            let item_height = 16.0;
+            let (x, y, w) = (self.x as f32, self.y as f32, 100.0);
+            let h = self.items.len() as f32 * item_height as f32;
+            gpu_renderer.add_solid_rect(x, y, w, h, theme.panel);
+            if let Some(i) = self.highlighted {
+                let row_y = y + i as f32 * item_height as f32;
+                gpu_renderer.add_solid_rect(x, row_y, w, item_height as f32, theme.selection);
+            }
+            for (i, renderer) in self.renderers.iter_mut().enumerate() {
+                renderer.set_color(theme.text);
+                renderer.set_x(self.x);
+                renderer.set_y(self.y + i as f64 * item_height);
+                renderer.render_gpu(gpu_renderer);
+            }
+        }
     }
 });
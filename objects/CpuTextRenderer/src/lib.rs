@@ -14,15 +14,15 @@ hotline::object!({
 
     #[derive(Clone, Default)]
     pub struct CpuTextRenderer {
-        #[default(Vec::new())]
-        font_atlas: Vec<u8>, // RGBA bitmap
+        #[serde(skip)]
+        font_atlas: Vec<u8>, // RGBA bitmap, reloaded from the font files rather than saved
         #[default(0)]
         atlas_width: u32,
         #[default(0)]
         atlas_height: u32,
-        #[default(HashMap::new())]
+        #[serde(skip)]
         glyphs: HashMap<char, GlyphInfo>,
-        #[default(HashMap::new())]
+        #[serde(skip)]
         kerning: HashMap<(char, char), i32>,
         #[default(0)]
         font_size: u32,
@@ -30,10 +30,29 @@ hotline::object!({
         line_gap: u32,
         #[default(0)]
         space_width: u32,
+        #[setter]
+        #[default(2048)]
+        max_texture_width: u32, // Widest texture a line is rendered into before it's split
+        #[setter]
+        #[default(1 << 20)]
+        max_texture_pixels: u32, // Pixel budget per line texture, whatever its shape
+        #[default(1.0)]
+        gamma: f32, // Glyph coverage correction; above 1 thickens dark text and thins light text, 1 is the raw atlas
     }
 
     impl CpuTextRenderer {
         pub fn initialize(&mut self) {
+            self.load_font();
+        }
+
+        // The font isn't part of the saved state, so a reloaded renderer that had one loads it again
+        fn after_reload(&mut self) {
+            if self.font_size > 0 {
+                self.load_font();
+            }
+        }
+
+        fn load_font(&mut self) {
             // Load font atlas PNG
             let png_data = std::fs::read("fonts/owlet/owlet.png").unwrap();
             let decoder = png::Decoder::new(&png_data[..]);
@@ -106,7 +125,39 @@ hotline::object!({
             );
         }
 
+        // Advance width of a single line, in pixels
+        pub fn measure_text(&self, text: &str) -> f64 {
+            let mut cursor_x = 0i32;
+            let mut prev_char: Option<char> = None;
+            for ch in text.chars() {
+                if let Some(prev) = prev_char {
+                    if let Some(&kern) = self.kerning.get(&(prev, ch)) {
+                        cursor_x += kern;
+                    }
+                }
+                match self.glyphs.get(&ch) {
+                    Some(glyph) if ch != ' ' => cursor_x += glyph.advance as i32,
+                    _ => cursor_x += self.space_width as i32,
+                }
+                prev_char = Some(ch);
+            }
+            cursor_x as f64
+        }
+
         pub fn render_line(&self, text: String, color: (u8, u8, u8, u8)) -> (Vec<u8>, u32, u32, u32) {
+            // Lines wider than one texture are cut at the first tile; render_line_tiles keeps the rest
+            let mut tiles = self.render_tiles(&text, color, 1);
+            tiles.remove(0)
+        }
+
+        // The whole line as tiles no larger than max_texture_width or max_texture_pixels, left to right.
+        // Each is (row-padded RGBA, logical width, texture width, height) like render_line; tile n+1 starts
+        // where tile n's logical width ends.
+        pub fn render_line_tiles(&self, text: String, color: (u8, u8, u8, u8)) -> Vec<(Vec<u8>, u32, u32, u32)> {
+            self.render_tiles(&text, color, usize::MAX)
+        }
+
+        fn render_tiles(&self, text: &str, color: (u8, u8, u8, u8), max_tiles: usize) -> Vec<(Vec<u8>, u32, u32, u32)> {
             // Calculate line dimensions by finding the bounding box of all glyphs
             let mut cursor_x = 0i32;
             let mut min_x = 0i32;
@@ -138,7 +189,7 @@ hotline::object!({
             // If there was no text, max_x would be 0, but we should make sure it's at least the final cursor position
             max_x = max_x.max(cursor_x);
 
-            let mut width = (max_x - min_x).max(0) as u32;
+            let width = (max_x - min_x).max(0) as u32;
             let height = self.font_size + self.line_gap;
 
             // Check for unusual height values that might indicate a mismatch
@@ -149,46 +200,70 @@ hotline::object!({
                 );
             }
 
-            // Cap width to prevent excessively large buffers that might cause rendering issues
-            let max_width = 2048; // Increased to handle wider lines
-            if width > max_width {
+            // Check for potential issues with negative min_x
+            if min_x < 0 {
+                eprintln!(
+                    "WARNING: Negative min_x detected, min_x={} for text='{}', this might cause clipping",
+                    min_x, text
+                );
+            }
+
+            // Keep every texture within the GPU's limits, both per side and overall
+            let tile_width = self.max_texture_width.min(self.max_texture_pixels / height.max(1)).max(1);
+            let tile_count = (width.div_ceil(tile_width) as usize).max(1);
+            if tile_count > max_tiles {
                 eprintln!(
                     "WARNING: Line width capped at {} from {} for text='{}', consider splitting long lines",
-                    max_width, width, text
+                    tile_width * max_tiles as u32,
+                    width,
+                    text
                 );
-                width = max_width;
             }
 
-            let logical_width = width;
+            (0..tile_count.min(max_tiles) as u32)
+                .map(|tile| {
+                    let x0 = tile * tile_width;
+                    self.render_tile(text, color, min_x + x0 as i32, tile_width.min(width - x0), height)
+                })
+                .collect()
+        }
+
+        // Positive; 1 leaves coverage as rasterized
+        pub fn set_gamma(&mut self, gamma: f32) {
+            assert!(gamma.is_finite() && gamma > 0.0, "text gamma must be positive, got {}", gamma);
+            self.gamma = gamma;
+        }
+
+        // Output alpha for each atlas coverage value. Light text on a dark background reads heavier than
+        // dark text on light, so the exponent runs from 1/gamma for black text to gamma for white.
+        fn coverage_table(&self, color: (u8, u8, u8, u8)) -> [u8; 256] {
+            let luminance = (0.2126 * color.0 as f32 + 0.7152 * color.1 as f32 + 0.0722 * color.2 as f32) / 255.0;
+            let exponent = self.gamma.powf(2.0 * luminance - 1.0);
+            std::array::from_fn(|alpha| {
+                let coverage = (alpha as f32 / 255.0).powf(exponent);
+                (coverage * color.3 as f32).round() as u8
+            })
+        }
 
+        // Render the columns of the line starting `left` pixels from its origin
+        fn render_tile(
+            &self,
+            text: &str,
+            color: (u8, u8, u8, u8),
+            left: i32,
+            width: u32,
+            height: u32,
+        ) -> (Vec<u8>, u32, u32, u32) {
             // The GPU requires texture rows to be aligned to 256 bytes. We create a
             // tightly-packed buffer first, then copy it into a padded destination buffer.
             let mut temp_buffer = vec![0u8; (width * height * 4) as usize];
             let row_pitch = (width * 4 + 255) & !255;
             let mut buffer = vec![0u8; (row_pitch * height) as usize];
+            let coverage = self.coverage_table(color);
 
-            // Verify buffer size is correct
-            assert_eq!(
-                temp_buffer.len(),
-                (width * height * 4) as usize,
-                "Buffer size mismatch: expected {}, got {}",
-                (width * height * 4),
-                temp_buffer.len()
-            );
-
-            // Removed logging to reduce clutter, only critical errors will be logged
-
-            // Check for potential issues with negative min_x
-            if min_x < 0 {
-                eprintln!(
-                    "WARNING: Negative min_x detected, min_x={} for text='{}', this might cause clipping",
-                    min_x, text
-                );
-            }
-
-            // Render glyphs, offsetting by min_x to fit in the buffer
-            cursor_x = -min_x;
-            prev_char = None;
+            // Render glyphs, offsetting by the tile's left edge to fit in the buffer
+            let mut cursor_x = -left;
+            let mut prev_char: Option<char> = None;
 
             for ch in text.chars() {
                 // Apply kerning
@@ -223,20 +298,13 @@ hotline::object!({
                     let glyph_top = glyph.offset_y + self.font_size as i32;
                     let glyph_bottom = glyph_top + glyph.height as i32;
 
-                    // Check if entire glyph would be out of bounds
-                    if glyph_right <= 0 || glyph_left >= width as i32 || glyph_bottom <= 0 || glyph_top >= height as i32
-                    {
-                        eprintln!(
-                            "WARNING: Glyph '{}' skipped due to out of bounds: x=[{},{}] y=[{},{}] buffer={}x{}",
-                            ch, glyph_left, glyph_right, glyph_top, glyph_bottom, width, height
-                        );
+                    // Glyphs belonging to other tiles are expected; only vertical overflow is suspicious
+                    if glyph_right <= 0 || glyph_left >= width as i32 {
                         cursor_x += glyph.advance as i32;
                         prev_char = Some(ch);
                         continue;
                     }
-
-                    // Debug log for glyphs that partially exceed bounds
-                    if glyph_left < 0 || glyph_right > width as i32 || glyph_top < 0 || glyph_bottom > height as i32 {
+                    if glyph_top < 0 || glyph_bottom > height as i32 {
                         eprintln!(
                             "WARNING: Glyph '{}' partially out of bounds: x=[{},{}] y=[{},{}] buffer={}x{}",
                             ch, glyph_left, glyph_right, glyph_top, glyph_bottom, width, height
@@ -254,48 +322,24 @@ hotline::object!({
                                 continue;
                             }
 
-                            let src_idx = ((glyph.y + gy) * self.atlas_width + (glyph.x + gx)) * 4;
-                            let dst_idx = (dst_y as u32 * width + dst_x as u32) * 4;
-
-                            // Double-check indices are valid
-                            let src_idx_end = src_idx as usize + 3;
-                            let dst_idx_end = dst_idx as usize + 3;
-                            if src_idx_end >= self.font_atlas.len() {
+                            let src_idx = (((glyph.y + gy) * self.atlas_width + (glyph.x + gx)) * 4) as usize;
+                            let dst_idx = ((dst_y as u32 * width + dst_x as u32) * 4) as usize;
+                            if src_idx + 3 >= self.font_atlas.len() {
                                 eprintln!(
                                     "ERROR: Source index out of bounds for '{}': {} >= {}",
                                     ch,
-                                    src_idx_end,
+                                    src_idx + 3,
                                     self.font_atlas.len()
                                 );
                                 continue;
                             }
-                            if dst_idx_end > temp_buffer.len() {
-                                eprintln!(
-                                    "ERROR: Dest index out of bounds for '{}': {} > {} (x={}, y={}, width={})",
-                                    ch,
-                                    dst_idx_end,
-                                    temp_buffer.len(),
-                                    dst_x,
-                                    dst_y,
-                                    width
-                                );
-                                panic!("Buffer overflow detected!");
-                            }
-
-                            debug_assert!(
-                                dst_idx_end <= temp_buffer.len(),
-                                "Buffer overflow: dst_idx_end={} > buffer.len={}",
-                                dst_idx_end,
-                                temp_buffer.len()
-                            );
 
-                            let alpha = self.font_atlas[src_idx as usize + 3];
+                            let alpha = self.font_atlas[src_idx + 3];
                             if alpha > 0 {
-                                let idx = dst_idx as usize;
-                                temp_buffer[idx] = color.0; // R (changed from BGR to RGB)
-                                temp_buffer[idx + 1] = color.1; // G
-                                temp_buffer[idx + 2] = color.2; // B
-                                temp_buffer[idx + 3] = (alpha as u32 * color.3 as u32 / 255) as u8;
+                                temp_buffer[dst_idx] = color.0; // R (changed from BGR to RGB)
+                                temp_buffer[dst_idx + 1] = color.1; // G
+                                temp_buffer[dst_idx + 2] = color.2; // B
+                                temp_buffer[dst_idx + 3] = coverage[alpha as usize];
                             }
                         }
                     }
@@ -311,20 +355,18 @@ hotline::object!({
             }
 
             // Copy the tightly-packed temp buffer into the final, row-padded buffer.
-            for y in 0..height {
-                let src_offset = (y * width * 4) as usize;
-                let dst_offset = (y * row_pitch) as usize;
-                let row_bytes = (width * 4) as usize;
-                if src_offset + row_bytes <= temp_buffer.len() && dst_offset + row_bytes <= buffer.len() {
-                    buffer[dst_offset..dst_offset + row_bytes]
-                        .copy_from_slice(&temp_buffer[src_offset..src_offset + row_bytes]);
-                }
+            let row_bytes = (width * 4) as usize;
+            for y in 0..height as usize {
+                let src_offset = y * row_bytes;
+                let dst_offset = y * row_pitch as usize;
+                buffer[dst_offset..dst_offset + row_bytes]
+                    .copy_from_slice(&temp_buffer[src_offset..src_offset + row_bytes]);
             }
 
             // The new texture width for the GPU is the row_pitch / 4
             let texture_width = row_pitch / 4;
 
-            (buffer, logical_width, texture_width, height)
+            (buffer, width, texture_width, height)
         }
     }
 });
@@ -0,0 +1,163 @@
+hotline::object!({
+    #[derive(Default)]
+    pub struct Dropdown {
+        options: Vec<String>,
+        renderers: Vec<TextRenderer>, // One per option; the header draws the selected one
+        #[setter]
+        #[default(0.0)]
+        x: f64,
+        #[setter]
+        #[default(0.0)]
+        y: f64,
+        #[setter]
+        #[default(150.0)]
+        width: f64,
+        #[setter]
+        #[default(20.0)]
+        item_height: f64,
+        selected: usize,
+        open: bool,
+        hovered: Option<usize>,
+    }
+
+    impl Dropdown {
+        pub fn set_options(&mut self, options: Vec<String>) {
+            self.options = options;
+            self.renderers.clear();
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+            for option in &self.options {
+                self.renderers.push(TextRenderer::new().with_text(option.clone()).with_color((255, 255, 255, 255)));
+            }
+            if self.selected >= self.options.len() {
+                self.selected = 0;
+            }
+            self.hovered = None;
+        }
+
+        pub fn width(&self) -> f64 {
+            self.width
+        }
+
+        pub fn selected(&self) -> usize {
+            self.selected
+        }
+
+        pub fn selected_option(&self) -> Option<String> {
+            self.options.get(self.selected).cloned()
+        }
+
+        pub fn set_selected(&mut self, index: usize) {
+            assert!(
+                index < self.options.len(),
+                "dropdown index {} out of range ({} options)",
+                index,
+                self.options.len()
+            );
+            self.selected = index;
+        }
+
+        pub fn is_open(&self) -> bool {
+            self.open
+        }
+
+        pub fn close(&mut self) {
+            self.open = false;
+            self.hovered = None;
+        }
+
+        // The header plus, while open, the option list below it
+        pub fn contains_point(&self, x: f64, y: f64) -> bool {
+            let rows = if self.open { 1 + self.options.len() } else { 1 };
+            x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + rows as f64 * self.item_height
+        }
+
+        fn option_at(&self, x: f64, y: f64) -> Option<usize> {
+            if !self.open || x < self.x || x >= self.x + self.width {
+                return None;
+            }
+            let row = ((y - self.y) / self.item_height).floor();
+            if row < 1.0 {
+                return None;
+            }
+            let index = row as usize - 1;
+            (index < self.options.len()).then_some(index)
+        }
+
+        // Toggles the list from the header and picks an option from the open list.
+        // Returns the index when the user chose an option so the owner can react to it.
+        // Clicking anywhere else closes the list.
+        pub fn handle_mouse_down(&mut self, x: f64, y: f64) -> Option<usize> {
+            if let Some(index) = self.option_at(x, y) {
+                self.selected = index;
+                self.close();
+                return Some(index);
+            }
+            let on_header = x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + self.item_height;
+            if on_header && !self.open {
+                self.open = true;
+            } else {
+                self.close();
+            }
+            None
+        }
+
+        pub fn handle_mouse_move(&mut self, x: f64, y: f64) {
+            self.hovered = self.option_at(x, y);
+        }
+
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            let theme = self.registry().theme();
+            let (x, y, w, h) = (self.x as f32, self.y as f32, self.width as f32, self.item_height as f32);
+            let border = theme.border;
+
+            // Header showing the current selection
+            gpu_renderer.add_solid_rect(x, y, w, h, theme.panel_header);
+            gpu_renderer.add_solid_rect(x, y, w, 1.0, border);
+            gpu_renderer.add_solid_rect(x, y + h - 1.0, w, 1.0, border);
+            gpu_renderer.add_solid_rect(x, y, 1.0, h, border);
+            gpu_renderer.add_solid_rect(x + w - 1.0, y, 1.0, h, border);
+
+            // Open/closed indicator
+            let (ax, ay) = (x + w - 14.0, y + h / 2.0);
+            if self.open {
+                gpu_renderer.add_line(ax, ay + 2.0, ax + 4.0, ay - 2.0, 1.0, border);
+                gpu_renderer.add_line(ax + 4.0, ay - 2.0, ax + 8.0, ay + 2.0, 1.0, border);
+            } else {
+                gpu_renderer.add_line(ax, ay - 2.0, ax + 4.0, ay + 2.0, 1.0, border);
+                gpu_renderer.add_line(ax + 4.0, ay + 2.0, ax + 8.0, ay - 2.0, 1.0, border);
+            }
+
+            if let Some(tr) = self.renderers.get_mut(self.selected) {
+                tr.set_color(theme.text);
+                tr.set_x(self.x + 6.0);
+                tr.set_y(self.y + 4.0);
+                tr.render_gpu(gpu_renderer);
+            }
+
+            if !self.open {
+                return;
+            }
+
+            let list_h = self.options.len() as f32 * h;
+            gpu_renderer.add_solid_rect(x, y + h, w, list_h, theme.panel);
+            gpu_renderer.add_solid_rect(x, y + h + list_h - 1.0, w, 1.0, border);
+            gpu_renderer.add_solid_rect(x, y + h, 1.0, list_h, border);
+            gpu_renderer.add_solid_rect(x + w - 1.0, y + h, 1.0, list_h, border);
+
+            for (i, tr) in self.renderers.iter_mut().enumerate() {
+                let row_y = self.y + (i + 1) as f64 * self.item_height;
+                if self.hovered == Some(i) {
+                    gpu_renderer.add_solid_rect(x + 1.0, row_y as f32, w - 2.0, h, theme.selection);
+                } else if self.selected == i {
+                    gpu_renderer.add_solid_rect(x + 1.0, row_y as f32, w - 2.0, h, theme.panel_header);
+                }
+                tr.set_color(theme.text);
+                tr.set_x(self.x + 6.0);
+                tr.set_y(row_y + 4.0);
+                tr.render_gpu(gpu_renderer);
+            }
+        }
+    }
+});
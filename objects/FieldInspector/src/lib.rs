@@ -0,0 +1,305 @@
+use hotline::{FieldDescriptor, FieldValue};
+
+// An in-progress drag over a number or color channel; edits are relative to where it started
+#[derive(Clone, Copy)]
+struct FieldScrub {
+    row: usize,
+    channel: usize, // Color channel under the cursor; 0 for numbers
+    start_x: f64,
+    start_value: f64,
+}
+
+hotline::object!({
+    #[derive(Default)]
+    pub struct FieldInspector {
+        title: String,
+        #[serde(skip)]
+        fields: Vec<::hotline::FieldDescriptor>, // Owner refreshes these from the target every frame
+        title_renderer: Option<TextRenderer>,
+        name_renderers: Vec<TextRenderer>,
+        value_renderers: Vec<TextRenderer>,
+        #[setter]
+        #[default(220.0)]
+        x: f64,
+        #[setter]
+        #[default(120.0)]
+        y: f64,
+        #[setter]
+        #[default(280.0)]
+        width: f64,
+        #[setter]
+        #[default(16.0)]
+        row_height: f64,
+        #[default(140.0)]
+        label_width: f64,
+        visible: bool,
+        #[serde(skip)]
+        scrub: Option<FieldScrub>,
+        #[serde(skip)]
+        editing: Option<usize>, // Text row taking typed input until Enter applies it or Escape drops it
+        dragging: Option<(f64, f64)>, // Title bar grab offset
+    }
+
+    impl FieldInspector {
+        pub fn open(&mut self, title: String, fields: Vec<::hotline::FieldDescriptor>) {
+            self.title = title;
+            self.visible = true;
+            self.scrub = None;
+            self.editing = None;
+            self.update_fields(fields);
+        }
+
+        // Scrubbed and half-typed values are kept until the edit ends so a lagging target can't fight the cursor
+        pub fn update_fields(&mut self, fields: Vec<::hotline::FieldDescriptor>) {
+            let row = self.scrub.map(|scrub| scrub.row).or(self.editing);
+            let scrubbed = row.and_then(|row| self.fields.get(row).cloned());
+            self.fields = fields;
+            if self.editing.is_some_and(|row| row >= self.fields.len()) {
+                self.editing = None;
+            }
+            if let Some(scrubbed) = scrubbed {
+                for field in self.fields.iter_mut().filter(|f| f.name == scrubbed.name) {
+                    *field = scrubbed.clone();
+                }
+            }
+            self.ensure_renderers();
+        }
+
+        pub fn close(&mut self) {
+            self.visible = false;
+            self.scrub = None;
+            self.editing = None;
+            self.dragging = None;
+        }
+
+        pub fn is_visible(&self) -> bool {
+            self.visible
+        }
+
+        pub fn contains_point(&self, x: f64, y: f64) -> bool {
+            let height = (self.fields.len() + 1) as f64 * self.row_height;
+            self.visible && x >= self.x && x < self.x + self.width && y >= self.y && y < self.y + height
+        }
+
+        fn ensure_renderers(&mut self) {
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+            if self.title_renderer.is_none() {
+                self.title_renderer = Some(TextRenderer::new().with_color((255, 255, 255, 255)));
+            }
+            if let Some(ref mut tr) = self.title_renderer {
+                tr.set_text(self.title.clone());
+            }
+            while self.name_renderers.len() < self.fields.len() {
+                self.name_renderers.push(TextRenderer::new().with_color((180, 180, 255, 180)));
+                self.value_renderers.push(TextRenderer::new().with_color((255, 255, 255, 255)));
+            }
+            self.name_renderers.truncate(self.fields.len());
+            self.value_renderers.truncate(self.fields.len());
+            let precision = self.registry().precision();
+            for (i, field) in self.fields.iter().enumerate() {
+                self.name_renderers[i].set_text(field.name.clone());
+                let value = match &field.value {
+                    FieldValue::Bool(_) => String::new(),
+                    FieldValue::Number(v) if is_integer(field) => format!("{}", v),
+                    FieldValue::Number(v) => format!("{:.*}", precision.for_name(&field.name), v),
+                    FieldValue::Text(text) if self.editing == Some(i) => format!("{}_", text),
+                    FieldValue::Text(text) => text.clone(),
+                    FieldValue::Color((c0, c1, c2, c3)) => format!("{:>3} {:>3} {:>3} {:>3}", c0, c1, c2, c3),
+                };
+                self.value_renderers[i].set_text(value);
+            }
+        }
+
+        fn row_at(&self, y: f64) -> Option<usize> {
+            let row = ((y - self.y) / self.row_height).floor();
+            if row < 1.0 {
+                return None;
+            }
+            let index = row as usize - 1;
+            (index < self.fields.len()).then_some(index)
+        }
+
+        fn value_x(&self) -> f64 {
+            self.x + self.label_width
+        }
+
+        // Which of the four color channels sits under `x`
+        fn channel_at(&self, x: f64) -> usize {
+            let cell = (self.width - self.label_width - 20.0) / 4.0;
+            (((x - self.value_x()) / cell).floor().max(0.0) as usize).min(3)
+        }
+
+        // Toggles bools, starts scrubbing numbers and color channels and starts typing into text;
+        // the title bar drags the panel. Returns the edit for the owner to apply to the inspected object.
+        pub fn handle_mouse_down(&mut self, x: f64, y: f64) -> Option<(String, ::hotline::FieldValue)> {
+            if !self.contains_point(x, y) {
+                return None;
+            }
+            self.editing = None;
+            let Some(row) = self.row_at(y) else {
+                self.dragging = Some((x - self.x, y - self.y));
+                return None;
+            };
+            if x < self.value_x() {
+                return None;
+            }
+            let field = &mut self.fields[row];
+            match field.value {
+                FieldValue::Bool(ref mut v) => {
+                    *v = !*v;
+                    Some((field.name.clone(), field.value.clone()))
+                }
+                FieldValue::Number(v) => {
+                    self.scrub = Some(FieldScrub { row, channel: 0, start_x: x, start_value: v });
+                    None
+                }
+                FieldValue::Color(color) => {
+                    let channel = self.channel_at(x);
+                    let start_value = [color.0, color.1, color.2, color.3][channel] as f64;
+                    self.scrub = Some(FieldScrub { row, channel, start_x: x, start_value });
+                    None
+                }
+                FieldValue::Text(_) => {
+                    self.editing = Some(row);
+                    self.ensure_renderers();
+                    None
+                }
+            }
+        }
+
+        pub fn is_editing(&self) -> bool {
+            self.editing.is_some()
+        }
+
+        pub fn insert_text(&mut self, text: &str) {
+            if let Some(FieldValue::Text(value)) = self.editing.map(|row| &mut self.fields[row].value) {
+                value.push_str(text);
+                self.ensure_renderers();
+            }
+        }
+
+        pub fn backspace(&mut self) {
+            if let Some(FieldValue::Text(value)) = self.editing.map(|row| &mut self.fields[row].value) {
+                value.pop();
+                self.ensure_renderers();
+            }
+        }
+
+        // Ends typing and returns the text for the owner to apply
+        pub fn commit_edit(&mut self) -> Option<(String, ::hotline::FieldValue)> {
+            let field = &self.fields[self.editing.take()?];
+            let edit = (field.name.clone(), field.value.clone());
+            self.ensure_renderers();
+            Some(edit)
+        }
+
+        // Ends typing without applying it; the next refresh brings back the target's value
+        pub fn cancel_edit(&mut self) {
+            self.editing = None;
+        }
+
+        pub fn handle_mouse_move(&mut self, x: f64, y: f64) -> Option<(String, ::hotline::FieldValue)> {
+            if let Some((dx, dy)) = self.dragging {
+                self.x = x - dx;
+                self.y = y - dy;
+                return None;
+            }
+            let scrub = self.scrub?;
+            let integer = is_integer(&self.fields[scrub.row]);
+            let field = &mut self.fields[scrub.row];
+            let dx = x - scrub.start_x;
+            let value = match field.value {
+                FieldValue::Number(_) if integer => {
+                    FieldValue::Number((scrub.start_value + (dx / 4.0).trunc()).round())
+                }
+                FieldValue::Number(_) => {
+                    let step = scrub.start_value.abs().max(0.01) * 0.005;
+                    FieldValue::Number(scrub.start_value + dx * step)
+                }
+                FieldValue::Color(color) => {
+                    let mut channels = [color.0, color.1, color.2, color.3];
+                    channels[scrub.channel] = (scrub.start_value + dx).round().clamp(0.0, 255.0) as u8;
+                    FieldValue::Color((channels[0], channels[1], channels[2], channels[3]))
+                }
+                _ => return None,
+            };
+            if value == field.value {
+                return None;
+            }
+            field.value = value.clone();
+            let name = field.name.clone();
+            self.ensure_renderers();
+            Some((name, value))
+        }
+
+        pub fn handle_mouse_up(&mut self) {
+            self.scrub = None;
+            self.dragging = None;
+        }
+
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if !self.visible {
+                return;
+            }
+            let theme = self.registry().theme();
+            let (x, y, w, h) = (self.x as f32, self.y as f32, self.width as f32, self.row_height as f32);
+            let rows = self.fields.len() as f32 + 1.0;
+            let border = theme.border;
+
+            gpu_renderer.add_solid_rect(x, y, w, h * rows, theme.panel);
+            gpu_renderer.add_solid_rect(x, y, w, h, theme.panel_header);
+            gpu_renderer.add_solid_rect(x, y, w, 1.0, border);
+            gpu_renderer.add_solid_rect(x, y + h * rows - 1.0, w, 1.0, border);
+            gpu_renderer.add_solid_rect(x, y, 1.0, h * rows, border);
+            gpu_renderer.add_solid_rect(x + w - 1.0, y, 1.0, h * rows, border);
+
+            if let Some(ref mut tr) = self.title_renderer {
+                tr.set_color(theme.text);
+                tr.set_x(self.x + 6.0);
+                tr.set_y(self.y + 3.0);
+                tr.render_gpu(gpu_renderer);
+            }
+
+            let value_x = self.value_x() as f32;
+            for (i, field) in self.fields.iter().enumerate() {
+                let row_y = self.y + (i + 1) as f64 * self.row_height;
+                if self.scrub.is_some_and(|scrub| scrub.row == i) || self.editing == Some(i) {
+                    gpu_renderer.add_solid_rect(x + 1.0, row_y as f32, w - 2.0, h, theme.selection);
+                }
+
+                let name = &mut self.name_renderers[i];
+                name.set_color(theme.header_text);
+                name.set_x(self.x + 6.0);
+                name.set_y(row_y + 3.0);
+                name.render_gpu(gpu_renderer);
+
+                match field.value {
+                    FieldValue::Bool(v) => {
+                        let (bx, by) = (value_x, row_y as f32 + 3.0);
+                        gpu_renderer.add_solid_rect(bx, by, 10.0, 10.0, border);
+                        let fill = if v { theme.accent } else { theme.panel_header };
+                        gpu_renderer.add_solid_rect(bx + 1.0, by + 1.0, 8.0, 8.0, fill);
+                    }
+                    FieldValue::Color((b, g, r, a)) => {
+                        // Shapes store colors as (b, g, r, a)
+                        let swatch = [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0];
+                        gpu_renderer.add_solid_rect(x + w - 16.0, row_y as f32 + 3.0, 10.0, 10.0, swatch);
+                    }
+                    _ => {}
+                }
+
+                let value = &mut self.value_renderers[i];
+                value.set_color(theme.text);
+                value.set_x(value_x as f64);
+                value.set_y(row_y + 3.0);
+                value.render_gpu(gpu_renderer);
+            }
+        }
+    }
+});
+
+fn is_integer(field: &FieldDescriptor) -> bool {
+    !matches!(field.type_name.as_str(), "f32" | "f64")
+}
@@ -1,3 +1,6 @@
+#[cfg(test)]
+mod test_replay;
+
 hotline::object!({
     #[derive(Clone, Debug)]
     pub enum RenderCommand {
@@ -28,6 +31,15 @@ hotline::object!({
             thickness: f64,
             color: (u8, u8, u8, u8),
         },
+        // Later commands draw only inside this rect until the matching PopClip. It is already
+        // intersected with any enclosing clip, so consumers can apply it as is
+        PushClip {
+            x: f64,
+            y: f64,
+            width: f64,
+            height: f64,
+        },
+        PopClip,
     }
 
     #[derive(Clone, Debug)]
@@ -50,11 +62,13 @@ hotline::object!({
         commands: Vec<RenderCommand>,
         atlases: Vec<AtlasData>,
         next_atlas_id: u32,
+        clip_stack: Vec<(f64, f64, f64, f64)>, // Clips pushed and not yet popped, intersected
     }
 
     impl GPURenderer {
         pub fn clear_commands(&mut self) {
             self.commands.clear();
+            self.clip_stack.clear();
             // Don't clear atlases - they should be persistent across frames
         }
 
@@ -74,6 +88,26 @@ hotline::object!({
             self.commands.push(command);
         }
 
+        // Nested clips intersect rather than replace, like the GPU renderer's scissor stack
+        pub fn push_clip_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+            let (mut x0, mut y0, mut x1, mut y1) = (x, y, x + width, y + height);
+            if let Some(&(px, py, pw, ph)) = self.clip_stack.last() {
+                x0 = x0.max(px);
+                y0 = y0.max(py);
+                x1 = x1.min(px + pw);
+                y1 = y1.min(py + ph);
+            }
+            let clip = (x0, y0, (x1 - x0).max(0.0), (y1 - y0).max(0.0));
+            self.clip_stack.push(clip);
+            self.commands.push(RenderCommand::PushClip { x: clip.0, y: clip.1, width: clip.2, height: clip.3 });
+        }
+
+        pub fn pop_clip_rect(&mut self) {
+            if self.clip_stack.pop().is_some() {
+                self.commands.push(RenderCommand::PopClip);
+            }
+        }
+
         pub fn register_atlas(&mut self, data: Vec<u8>, width: u32, height: u32, format: AtlasFormat) -> u32 {
             let id = self.next_atlas_id;
             self.next_atlas_id += 1;
@@ -83,6 +117,56 @@ hotline::object!({
             id
         }
 
+        // Replay the recorded commands through a GPU context, uploading each atlas the first time it's drawn
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            let object_id = ::hotline::HotlineObject::object_id(self);
+            let atlases = &self.atlases;
+            let texture = |gpu: &mut dyn ::hotline::GpuRenderingContext, atlas_id: u32| {
+                let atlas = atlases.iter().find(|atlas| atlas.id == atlas_id)?;
+                let purpose = format!("atlas {}", atlas_id);
+                let tex = ::hotline::acquire_texture(gpu, object_id, &purpose, |gpu| match atlas.format {
+                    AtlasFormat::RGBA => gpu.create_rgba_texture(&atlas.data, atlas.width, atlas.height),
+                    AtlasFormat::GrayscaleAlpha => {
+                        let rgba: Vec<u8> = atlas.data.chunks(2).flat_map(|p| [p[0], p[0], p[0], p[1]]).collect();
+                        gpu.create_rgba_texture(&rgba, atlas.width, atlas.height)
+                    }
+                });
+                tex.ok().map(|tex| (tex, atlas.width as f32, atlas.height as f32))
+            };
+            let rgba = |(r, g, b, a): (u8, u8, u8, u8)| {
+                [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0, a as f32 / 255.0]
+            };
+
+            for command in &self.commands {
+                match *command {
+                    RenderCommand::Atlas { texture_id, src_x, src_y, src_width, src_height, dest_x, dest_y, color } => {
+                        if let Some((tex, aw, ah)) = texture(gpu_renderer, texture_id) {
+                            let (u0, v0) = (src_x as f32 / aw, src_y as f32 / ah);
+                            let (u1, v1) = ((src_x + src_width) as f32 / aw, (src_y + src_height) as f32 / ah);
+                            let (w, h) = (src_width as f32, src_height as f32);
+                            let (x, y) = (dest_x as f32, dest_y as f32);
+                            gpu_renderer.add_textured_rect_with_coords(x, y, w, h, tex, u0, v0, u1, v1, rgba(color));
+                        }
+                    }
+                    // The context has no rotated rects, so rotation is dropped; the widgets using this pass 0
+                    RenderCommand::Rect { texture_id, dest_x, dest_y, dest_width, dest_height, color, .. } => {
+                        if let Some((tex, _, _)) = texture(gpu_renderer, texture_id) {
+                            let (x, y, w, h) = (dest_x as f32, dest_y as f32, dest_width as f32, dest_height as f32);
+                            gpu_renderer.add_textured_rect(x, y, w, h, tex, rgba(color));
+                        }
+                    }
+                    RenderCommand::Line { x1, y1, x2, y2, thickness, color } => {
+                        let (x1, y1, x2, y2) = (x1 as f32, y1 as f32, x2 as f32, y2 as f32);
+                        gpu_renderer.add_line(x1, y1, x2, y2, thickness as f32, rgba(color));
+                    }
+                    RenderCommand::PushClip { x, y, width, height } => {
+                        gpu_renderer.push_clip_rect(x as f32, y as f32, width as f32, height as f32)
+                    }
+                    RenderCommand::PopClip => gpu_renderer.pop_clip_rect(),
+                }
+            }
+        }
+
         pub fn render_via(&self, _app: &mut Like<Application>) -> Result<(), String> {
             // TODO: Update this to work with new SDL3 GPU API
             // For now, just return success
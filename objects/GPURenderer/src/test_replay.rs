@@ -0,0 +1,96 @@
+use crate::{AtlasFormat, GPURenderer, RenderCommand};
+
+type Clip = (f32, f32, f32, f32);
+
+// Records what the replay draws, with the clip each draw landed in
+#[derive(Default)]
+struct Recorder {
+    textures: u32,
+    clips: Vec<Clip>,
+    draws: Vec<(u32, Option<Clip>)>,
+}
+
+impl hotline::GpuRenderingContext for Recorder {
+    fn create_rgba_texture(&mut self, data: &[u8], width: u32, height: u32) -> Result<u32, String> {
+        assert_eq!(data.len(), (width * height * 4) as usize);
+        self.textures += 1;
+        Ok(self.textures)
+    }
+    fn add_textured_rect(&mut self, _x: f32, _y: f32, _w: f32, _h: f32, tex_id: u32, _color: [f32; 4]) {
+        self.draws.push((tex_id, self.clips.last().copied()));
+    }
+    fn add_textured_rect_with_coords(
+        &mut self,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        tex_id: u32,
+        _u0: f32,
+        _v0: f32,
+        _u1: f32,
+        _v1: f32,
+        color: [f32; 4],
+    ) {
+        self.add_textured_rect(x, y, w, h, tex_id, color);
+    }
+    fn add_solid_rect(&mut self, _x: f32, _y: f32, _w: f32, _h: f32, _color: [f32; 4]) {}
+    fn add_line(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _thickness: f32, _color: [f32; 4]) {}
+    fn add_circle(&mut self, _cx: f32, _cy: f32, _radius: f32, _color: [f32; 4]) {}
+    fn add_ring(&mut self, _cx: f32, _cy: f32, _radius: f32, _thickness: f32, _color: [f32; 4]) {}
+    fn add_rounded_rect_corners(&mut self, _x: f32, _y: f32, _w: f32, _h: f32, _radii: [f32; 4], _color: [f32; 4]) {}
+    fn has_texture(&self, tex_id: u32) -> bool {
+        tex_id <= self.textures
+    }
+    fn push_clip_rect(&mut self, x: f32, y: f32, w: f32, h: f32) {
+        self.clips.push((x, y, w, h));
+    }
+    fn pop_clip_rect(&mut self) {
+        self.clips.pop();
+    }
+}
+
+fn rect(texture_id: u32) -> RenderCommand {
+    RenderCommand::Rect {
+        texture_id,
+        dest_x: 0.0,
+        dest_y: 0.0,
+        dest_width: 10.0,
+        dest_height: 10.0,
+        rotation: 0.0,
+        color: (255, 255, 255, 255),
+    }
+}
+
+#[test]
+fn test_replay_clips_to_intersection() {
+    let mut renderer = GPURenderer::default();
+    let white = renderer.register_atlas(vec![255; 4], 1, 1, AtlasFormat::RGBA);
+    let glyphs = renderer.register_atlas(vec![255; 2 * 4], 2, 2, AtlasFormat::GrayscaleAlpha);
+
+    renderer.push_clip_rect(0.0, 0.0, 100.0, 50.0);
+    renderer.add_command(rect(white));
+    renderer.push_clip_rect(80.0, 10.0, 40.0, 100.0);
+    renderer.add_command(RenderCommand::Atlas {
+        texture_id: glyphs,
+        src_x: 0,
+        src_y: 0,
+        src_width: 2,
+        src_height: 2,
+        dest_x: 90.0,
+        dest_y: 20.0,
+        color: (255, 255, 255, 255),
+    });
+    renderer.pop_clip_rect();
+    renderer.pop_clip_rect();
+    renderer.add_command(rect(white));
+
+    let mut recorder = Recorder::default();
+    renderer.render_gpu(&mut recorder);
+
+    assert_eq!(recorder.textures, 2);
+    assert_eq!(recorder.draws[0].1, Some((0.0, 0.0, 100.0, 50.0)));
+    assert_eq!(recorder.draws[1].1, Some((80.0, 10.0, 20.0, 40.0)));
+    assert_eq!(recorder.draws[2].1, None);
+    assert!(recorder.clips.is_empty());
+}
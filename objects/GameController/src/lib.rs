@@ -12,8 +12,13 @@ hotline::object!({
         axis_labels: Vec<TextRenderer>,
         #[serde(skip)]
         controller_id: Option<u32>,
-        circle_atlas_id: Option<u32>,
-        filled_circle_atlas_id: Option<u32>,
+        #[setter]
+        #[default(0.0)]
+        update_interval: f64, // Seconds between redraws; 0 redraws every frame
+        #[setter]
+        debug_name: String, // Tells instances apart in logs, e.g. "p1"; empty logs the type name alone
+        #[setter]
+        clip_to_bounds: bool, // Cuts labels and sticks off at the rect instead of letting them spill out
     }
 
     impl GameController {
@@ -39,12 +44,23 @@ hotline::object!({
             }
         }
 
+        // Prefix and rate-limit key for this instance's logs, so named controllers don't silence each other
+        fn log_label(&self) -> String {
+            if self.debug_name.is_empty() {
+                "GameController".to_string()
+            } else {
+                format!("GameController {}", self.debug_name)
+            }
+        }
+
         pub fn set_rect(&mut self, rect: Rect) {
             let (x, y, w, h) = rect.bounds();
+            let label = self.log_label();
             hotline::debug_rate_limited!(
-                "gc_set_rect",
+                &format!("{} set_rect", label),
                 1000,
-                "GameController set_rect called with bounds: x={}, y={}, w={}, h={}",
+                "{} set_rect called with bounds: x={}, y={}, w={}, h={}",
+                label,
                 x,
                 y,
                 w,
@@ -72,13 +88,18 @@ hotline::object!({
                 self.axes[axis as usize] = value;
 
                 // Update label
+                let precision = self.registry().precision();
                 if let Some(label) = self.axis_labels.get_mut(axis as usize) {
                     let names = ["LX", "LY", "RX", "RY", "LT", "RT"];
-                    label.set_text(format!("{}: {:.2}", names[axis as usize], value));
+                    label.set_text(format!("{}: {:.*}", names[axis as usize], precision.number, value));
                 }
             }
         }
 
+        pub fn update_interval(&self) -> f64 {
+            self.update_interval
+        }
+
         pub fn axis_values(&self) -> (f32, f32, f32, f32) {
             (self.axes[0], self.axes[1], self.axes[2], self.axes[3])
         }
@@ -87,75 +108,22 @@ hotline::object!({
             (self.axes[4], self.axes[5]) // LT, RT
         }
 
+        pub fn shoulder_values(&self) -> (bool, bool) {
+            (self.buttons[9], self.buttons[10]) // LB, RB
+        }
+
+        pub fn button_pressed(&self, button: u8) -> bool {
+            self.buttons.get(button as usize).copied().unwrap_or(false)
+        }
+
         pub fn update_button(&mut self, button: u8, pressed: bool) {
             if (button as usize) < self.buttons.len() {
                 self.buttons[button as usize] = pressed;
             }
         }
 
-        pub fn setup_gpu_rendering(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
-            // Circle outline texture
-            if self.circle_atlas_id.is_none() {
-                let size = 64;
-                let radius = (size / 2) as f32 - 1.0;
-                let center = (size / 2) as f32;
-                let mut circle_data = vec![0u8; size * size * 4];
-
-                for y in 0..size {
-                    for x in 0..size {
-                        let dx = x as f32 - center;
-                        let dy = y as f32 - center;
-                        let dist = (dx * dx + dy * dy).sqrt();
-
-                        let idx = (y * size + x) * 4;
-                        if (dist - radius).abs() < 2.0 {
-                            // White outline
-                            circle_data[idx] = 255;
-                            circle_data[idx + 1] = 255;
-                            circle_data[idx + 2] = 255;
-                            circle_data[idx + 3] = 255;
-                        }
-                    }
-                }
-
-                let id = gpu_renderer.create_rgba_texture(&circle_data, size as u32, size as u32).unwrap();
-                self.circle_atlas_id = Some(id);
-                hotline::debug_rate_limited!("gc_register_circle", 1000, "Created circle texture with id: {}", id);
-            }
-
-            // Filled circle texture
-            if self.filled_circle_atlas_id.is_none() {
-                let size = 16;
-                let radius = (size / 2) as f32 - 0.5;
-                let center = (size / 2) as f32;
-                let mut circle_data = vec![0u8; size * size * 4];
-
-                for y in 0..size {
-                    for x in 0..size {
-                        let dx = x as f32 - center;
-                        let dy = y as f32 - center;
-                        let dist = (dx * dx + dy * dy).sqrt();
-
-                        let idx = (y * size + x) * 4;
-                        if dist <= radius {
-                            // Pink filled circle - RGBA format
-                            circle_data[idx] = 255; // R
-                            circle_data[idx + 1] = 105; // G
-                            circle_data[idx + 2] = 180; // B
-                            circle_data[idx + 3] = 255; // A
-                        }
-                    }
-                }
-
-                let id = gpu_renderer.create_rgba_texture(&circle_data, size as u32, size as u32).unwrap();
-                self.filled_circle_atlas_id = Some(id);
-                hotline::debug_rate_limited!(
-                    "gc_register_filled_circle",
-                    1000,
-                    "Created filled circle texture with id: {}",
-                    id
-                );
-            }
+        pub fn setup_gpu_rendering(&mut self, _gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            // The stick circles are drawn as geometry, so there are no textures to prepare
 
             // TODO: Update TextRenderer to use new GPU API
             // for label in &mut self.labels {
@@ -167,27 +135,19 @@ hotline::object!({
         }
 
         pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
-            // Initialize textures if needed
-            if self.circle_atlas_id.is_none() || self.filled_circle_atlas_id.is_none() {
-                hotline::debug_rate_limited!("gc_reregister", 1000, "Re-initializing textures");
-                self.setup_gpu_rendering(gpu_renderer);
-            }
-
+            let theme = self.registry().theme();
             if let Some(rect) = &self.rect {
                 let (x, y, w, h) = rect.clone().bounds();
+                if self.clip_to_bounds {
+                    gpu_renderer.push_clip_rect(x as f32, y as f32, w as f32, h as f32);
+                }
 
                 // Draw background
-                gpu_renderer.add_solid_rect(
-                    x as f32,
-                    y as f32,
-                    w as f32,
-                    h as f32,
-                    [50.0 / 255.0, 50.0 / 255.0, 50.0 / 255.0, 1.0], // Dark gray
-                );
+                gpu_renderer.add_solid_rect(x as f32, y as f32, w as f32, h as f32, theme.panel);
 
                 // Draw border (4 rectangles)
-                let border_color = [128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0, 1.0]; // Gray
-                                                                                       // Top
+                let border_color = theme.border;
+                // Top
                 gpu_renderer.add_solid_rect(x as f32, y as f32, w as f32, 1.0, border_color);
                 // Bottom
                 gpu_renderer.add_solid_rect(x as f32, (y + h - 1.0) as f32, w as f32, 1.0, border_color);
@@ -196,9 +156,12 @@ hotline::object!({
                 // Right
                 gpu_renderer.add_solid_rect((x + w - 1.0) as f32, y as f32, 1.0, h as f32, border_color);
 
-                // Render text labels
+                // Render text labels; the status line keeps its connected green
                 let mut label_y = y + 10.0;
-                for label in &mut self.labels {
+                for (i, label) in self.labels.iter_mut().enumerate() {
+                    if i != 1 || !self.connected {
+                        label.set_color(if i == 1 { theme.dim_text } else { theme.text });
+                    }
                     label.set_x(x + 10.0);
                     label.set_y(label_y);
                     label.render_gpu(gpu_renderer);
@@ -208,6 +171,7 @@ hotline::object!({
                 // Render axis visualizations
                 label_y = y + 60.0;
                 for (i, label) in self.axis_labels.iter_mut().enumerate() {
+                    label.set_color(theme.text);
                     label.set_x(x + 10.0);
                     label.set_y(label_y + i as f64 * 20.0);
                     label.render_gpu(gpu_renderer);
@@ -224,12 +188,12 @@ hotline::object!({
                         vis_y as f32,
                         vis_w as f32,
                         vis_h as f32,
-                        [64.0 / 255.0, 64.0 / 255.0, 64.0 / 255.0, 1.0], // Dark gray
+                        theme.panel_header,
                     );
 
                     // Value bar
                     let value = self.axes[i];
-                    let bar_color = [255.0 / 255.0, 105.0 / 255.0, 180.0 / 255.0, 1.0]; // Pink
+                    let bar_color = theme.accent;
                     {
                         if i < 4 {
                             // Sticks: -1 to 1, draw from center
@@ -275,11 +239,7 @@ hotline::object!({
                 for (i, _name) in button_names.iter().enumerate() {
                     if i < self.buttons.len() {
                         let is_pressed = self.buttons[i];
-                        let button_color = if is_pressed {
-                            [255.0 / 255.0, 105.0 / 255.0, 180.0 / 255.0, 1.0] // Pink when pressed
-                        } else {
-                            [100.0 / 255.0, 100.0 / 255.0, 100.0 / 255.0, 1.0] // Gray when not pressed
-                        };
+                        let button_color = if is_pressed { theme.accent } else { theme.border };
 
                         // Draw button indicator (small square)
                         gpu_renderer.add_solid_rect(button_x as f32, button_row_y as f32, 10.0, 10.0, button_color);
@@ -295,7 +255,9 @@ hotline::object!({
                 // Draw analog stick visualizations
                 // Place them in the middle of the controller area
                 let stick_radius = 40.0;
-                let stick_dot_radius = 8.0; // Match half of texture size (16/2)
+                let stick_dot_radius = 8.0;
+                let dot_color = [1.0, 105.0 / 255.0, 180.0 / 255.0, 1.0]; // Pink
+                let (ring_radius, outline) = (stick_radius as f32 - 1.0, [1.0, 1.0, 1.0, 1.0]);
 
                 // Left stick - put it in visible area
                 let left_stick_x = x + 60.0;
@@ -303,17 +265,8 @@ hotline::object!({
 
                 // Drawing sticks
 
-                // Draw left stick circle outline using circle texture
-                if let Some(circle_id) = self.circle_atlas_id {
-                    gpu_renderer.add_textured_rect(
-                        (left_stick_x - stick_radius) as f32,
-                        (left_stick_y - stick_radius) as f32,
-                        (stick_radius * 2.0) as f32,
-                        (stick_radius * 2.0) as f32,
-                        circle_id,
-                        [1.0, 1.0, 1.0, 1.0],
-                    );
-                }
+                // Draw left stick circle outline
+                gpu_renderer.add_ring(left_stick_x as f32, left_stick_y as f32, ring_radius, 2.0, outline);
 
                 // Draw left stick position
                 let lx = self.axes[0] as f64; // -1 to 1
@@ -322,32 +275,14 @@ hotline::object!({
                 let left_dot_y = left_stick_y + ly * (stick_radius - stick_dot_radius);
 
                 // Draw filled circle
-                if let Some(filled_circle_id) = self.filled_circle_atlas_id {
-                    gpu_renderer.add_textured_rect(
-                        (left_dot_x - stick_dot_radius) as f32,
-                        (left_dot_y - stick_dot_radius) as f32,
-                        (stick_dot_radius * 2.0) as f32,
-                        (stick_dot_radius * 2.0) as f32,
-                        filled_circle_id,
-                        [1.0, 1.0, 1.0, 1.0],
-                    );
-                }
+                gpu_renderer.add_circle(left_dot_x as f32, left_dot_y as f32, stick_dot_radius as f32, dot_color);
 
                 // Right stick
                 let right_stick_x = x + 140.0;
                 let right_stick_y = y + 200.0; // Same height as left stick
 
-                // Draw right stick circle outline using circle texture
-                if let Some(circle_id) = self.circle_atlas_id {
-                    gpu_renderer.add_textured_rect(
-                        (right_stick_x - stick_radius) as f32,
-                        (right_stick_y - stick_radius) as f32,
-                        (stick_radius * 2.0) as f32,
-                        (stick_radius * 2.0) as f32,
-                        circle_id,
-                        [1.0, 1.0, 1.0, 1.0],
-                    );
-                }
+                // Draw right stick circle outline
+                gpu_renderer.add_ring(right_stick_x as f32, right_stick_y as f32, ring_radius, 2.0, outline);
 
                 // Draw right stick position
                 let rx = self.axes[2] as f64; // -1 to 1
@@ -356,16 +291,7 @@ hotline::object!({
                 let right_dot_y = right_stick_y + ry * (stick_radius - stick_dot_radius);
 
                 // Draw filled circle
-                if let Some(filled_circle_id) = self.filled_circle_atlas_id {
-                    gpu_renderer.add_textured_rect(
-                        (right_dot_x - stick_dot_radius) as f32,
-                        (right_dot_y - stick_dot_radius) as f32,
-                        (stick_dot_radius * 2.0) as f32,
-                        (stick_dot_radius * 2.0) as f32,
-                        filled_circle_id,
-                        [1.0, 1.0, 1.0, 1.0],
-                    );
-                }
+                gpu_renderer.add_circle(right_dot_x as f32, right_dot_y as f32, stick_dot_radius as f32, dot_color);
 
                 // Draw controllable rectangle (controlled by left stick)
                 let rect_area_y = y + 280.0; // Below the analog sticks
@@ -374,8 +300,8 @@ hotline::object!({
                 let rect_area_h = 80.0;
 
                 // Draw play area border
-                let border_color = [128.0 / 255.0, 128.0 / 255.0, 128.0 / 255.0, 1.0]; // Gray
-                                                                                       // Top
+                let border_color = theme.border;
+                // Top
                 gpu_renderer.add_solid_rect(
                     rect_area_x as f32,
                     rect_area_y as f32,
@@ -420,7 +346,7 @@ hotline::object!({
                     rect_y as f32,
                     rect_size as f32,
                     rect_size as f32,
-                    [255.0 / 255.0, 105.0 / 255.0, 180.0 / 255.0, 1.0], // Pink
+                    theme.accent,
                 );
 
                 // Draw trigger visualization bars at bottom
@@ -437,7 +363,7 @@ hotline::object!({
                     trigger_y as f32,
                     trigger_w as f32,
                     trigger_h as f32,
-                    [64.0 / 255.0, 64.0 / 255.0, 64.0 / 255.0, 1.0], // Dark gray
+                    theme.panel_header,
                 );
 
                 // Left trigger value
@@ -446,7 +372,7 @@ hotline::object!({
                     trigger_y as f32,
                     (lt_value * trigger_w) as f32,
                     trigger_h as f32,
-                    [255.0 / 255.0, 105.0 / 255.0, 180.0 / 255.0, 1.0], // Pink
+                    theme.accent,
                 );
 
                 // Right trigger
@@ -458,7 +384,7 @@ hotline::object!({
                     trigger_y as f32,
                     trigger_w as f32,
                     trigger_h as f32,
-                    [64.0 / 255.0, 64.0 / 255.0, 64.0 / 255.0, 1.0], // Dark gray
+                    theme.panel_header,
                 );
 
                 // Right trigger value
@@ -467,8 +393,12 @@ hotline::object!({
                     trigger_y as f32,
                     (rt_value * trigger_w) as f32,
                     trigger_h as f32,
-                    [255.0 / 255.0, 105.0 / 255.0, 180.0 / 255.0, 1.0], // Pink
+                    theme.accent,
                 );
+
+                if self.clip_to_bounds {
+                    gpu_renderer.pop_clip_rect();
+                }
             }
         }
 
@@ -0,0 +1,223 @@
+#[cfg(test)]
+mod test_headless;
+
+// Stands in for the GPU renderer when there is no window or device. Rects are expanded into
+// vertices and texture batches the same way, so headless runs measure the submission path.
+#[derive(Default)]
+struct HeadlessContext {
+    vertices: Vec<[f32; 8]>,           // pos, tex_coord, color
+    batches: Vec<(u32, usize, usize)>, // texture id, first vertex, vertex count
+    textures: std::collections::HashSet<u32>,
+}
+
+impl HeadlessContext {
+    fn begin_frame(&mut self) {
+        self.vertices.clear();
+        self.batches.clear();
+    }
+
+    fn push_quad(&mut self, corners: [[f32; 2]; 4], uv: [f32; 4], tex_id: u32, color: [f32; 4]) {
+        let [u0, v0, u1, v1] = uv;
+        let [c0, c1, c2, c3] = corners;
+        let vertex = |p: [f32; 2], u: f32, v: f32| [p[0], p[1], u, v, color[0], color[1], color[2], color[3]];
+        let start = self.vertices.len();
+        self.vertices.extend_from_slice(&[
+            vertex(c0, u0, v0),
+            vertex(c1, u1, v0),
+            vertex(c3, u0, v1),
+            vertex(c1, u1, v0),
+            vertex(c2, u1, v1),
+            vertex(c3, u0, v1),
+        ]);
+        match self.batches.last_mut() {
+            Some(batch) if batch.0 == tex_id => batch.2 += 6,
+            _ => self.batches.push((tex_id, start, 6)),
+        }
+    }
+
+    // Shapes are feathered over one unit, as the GPU renderer does at a pixel scale of 1
+    fn push_coverage_triangles(&mut self, triangles: Vec<([f32; 2], f32)>, color: [f32; 4]) {
+        let start = self.vertices.len();
+        for ([x, y], coverage) in triangles {
+            self.vertices.push([x, y, 0.0, 0.0, color[0], color[1], color[2], color[3] * coverage]);
+        }
+        let count = self.vertices.len() - start;
+        match self.batches.last_mut() {
+            _ if count == 0 => {}
+            Some(batch) if batch.0 == 0 => batch.2 += count,
+            _ => self.batches.push((0, start, count)),
+        }
+    }
+}
+
+impl ::hotline::GpuRenderingContext for HeadlessContext {
+    fn create_rgba_texture(&mut self, data: &[u8], width: u32, height: u32) -> Result<u32, String> {
+        if data.len() != (width * height * 4) as usize {
+            return Err(format!("texture data is {} bytes, expected {}x{}x4", data.len(), width, height));
+        }
+        let id = self.textures.len() as u32 + 1; // 0 is the implicit white texture
+        self.textures.insert(id);
+        Ok(id)
+    }
+
+    fn add_textured_rect(&mut self, x: f32, y: f32, w: f32, h: f32, tex_id: u32, color: [f32; 4]) {
+        self.add_textured_rect_with_coords(x, y, w, h, tex_id, 0.0, 0.0, 1.0, 1.0, color);
+    }
+
+    fn add_textured_rect_with_coords(
+        &mut self,
+        x: f32,
+        y: f32,
+        w: f32,
+        h: f32,
+        tex_id: u32,
+        u0: f32,
+        v0: f32,
+        u1: f32,
+        v1: f32,
+        color: [f32; 4],
+    ) {
+        self.push_quad([[x, y], [x + w, y], [x + w, y + h], [x, y + h]], [u0, v0, u1, v1], tex_id, color);
+    }
+
+    fn add_solid_rect(&mut self, x: f32, y: f32, w: f32, h: f32, color: [f32; 4]) {
+        self.add_textured_rect(x, y, w, h, 0, color);
+    }
+
+    fn add_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: [f32; 4]) {
+        let (dx, dy) = (x2 - x1, y2 - y1);
+        let len = (dx * dx + dy * dy).sqrt();
+        if len < 0.001 {
+            return;
+        }
+        let (px, py) = (-dy / len * thickness / 2.0, dx / len * thickness / 2.0);
+        self.push_quad(
+            [[x1 - px, y1 - py], [x2 - px, y2 - py], [x2 + px, y2 + py], [x1 + px, y1 + py]],
+            [0.0, 0.0, 1.0, 1.0],
+            0,
+            color,
+        );
+    }
+
+    fn add_circle(&mut self, cx: f32, cy: f32, radius: f32, color: [f32; 4]) {
+        self.push_coverage_triangles(::hotline::annulus_triangles(cx, cy, 0.0, radius, 1.0), color);
+    }
+
+    fn add_ring(&mut self, cx: f32, cy: f32, radius: f32, thickness: f32, color: [f32; 4]) {
+        let half = thickness / 2.0;
+        let triangles = ::hotline::annulus_triangles(cx, cy, (radius - half).max(0.0), radius + half, 1.0);
+        self.push_coverage_triangles(triangles, color);
+    }
+
+    fn add_rounded_rect_corners(&mut self, x: f32, y: f32, w: f32, h: f32, radii: [f32; 4], color: [f32; 4]) {
+        self.push_coverage_triangles(::hotline::rounded_rect_triangles(x, y, w, h, radii, 1.0), color);
+    }
+
+    fn has_texture(&self, tex_id: u32) -> bool {
+        tex_id == 0 || self.textures.contains(&tex_id)
+    }
+}
+
+hotline::object!({
+    #[derive(Default)]
+    pub struct GpuBenchmark {
+        #[setter]
+        #[default(10_000)]
+        rect_count: usize,
+        #[setter]
+        #[default(false)]
+        textured: bool, // Sample a checker texture instead of the white one
+        #[setter]
+        #[default(false)]
+        interleave: bool, // Alternate solid and textured rects so every rect starts a new batch
+        #[setter]
+        #[default(1920.0)]
+        width: f64,
+        #[setter]
+        #[default(1080.0)]
+        height: f64,
+        frame: u64,
+        #[serde(skip)]
+        frame_times: std::collections::VecDeque<std::time::Instant>,
+    }
+
+    impl GpuBenchmark {
+        // Emit one frame's worth of rects, laid out as a grid that drifts each frame
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            let checker = if self.textured || self.interleave {
+                let object_id = ::hotline::HotlineObject::object_id(self);
+                let tex = ::hotline::acquire_texture(gpu_renderer, object_id, "checker", |gpu| {
+                    let data: Vec<u8> = (0..64)
+                        .flat_map(|i| if (i % 8 + i / 8) % 2 == 0 { [255u8; 4] } else { [96, 96, 96, 255] })
+                        .collect();
+                    gpu.create_rgba_texture(&data, 8, 8)
+                });
+                Some(tex.unwrap_or_else(|e| panic!("GpuBenchmark failed to create checker texture: {}", e)))
+            } else {
+                None
+            };
+
+            let n = self.rect_count.max(1);
+            let cols = ((n as f64 * self.width / self.height).sqrt().ceil() as usize).max(1);
+            let cell = (self.width / cols as f64) as f32;
+            let size = (cell * 0.8).max(1.0);
+            let drift = (self.frame % 64) as f32;
+            for i in 0..self.rect_count {
+                let x = ((i % cols) as f32 * cell + drift) % self.width as f32;
+                let y = (i / cols) as f32 * cell;
+                let color = [(i % 7) as f32 / 6.0, (i % 5) as f32 / 4.0, (i % 3) as f32 / 2.0, 1.0];
+                let use_texture = if self.interleave { i % 2 == 1 } else { self.textured };
+                match checker.filter(|_| use_texture) {
+                    Some(tex) => gpu_renderer.add_textured_rect(x, y, size, size, tex, color),
+                    None => gpu_renderer.add_solid_rect(x, y, size, size, color),
+                }
+            }
+
+            self.frame += 1;
+            self.frame_times.push_back(std::time::Instant::now());
+            if self.frame_times.len() > 120 {
+                self.frame_times.pop_front();
+            }
+        }
+
+        // Frames per second over the recent render_gpu calls
+        pub fn fps(&self) -> f64 {
+            match (self.frame_times.front(), self.frame_times.back()) {
+                (Some(first), Some(last)) if self.frame_times.len() > 1 => {
+                    (self.frame_times.len() - 1) as f64 / last.duration_since(*first).as_secs_f64()
+                }
+                _ => 0.0,
+            }
+        }
+
+        // Render `frames` frames into an in-memory context and report the achievable rate
+        pub fn run_headless(&mut self, frames: u32) -> String {
+            assert!(frames > 0, "GpuBenchmark needs at least one frame");
+            let mut context: HeadlessContext = Default::default();
+            let mut batches = 0;
+            let start = std::time::Instant::now();
+            for _ in 0..frames {
+                context.begin_frame();
+                self.render_gpu(&mut context);
+                batches = context.batches.len();
+            }
+            let elapsed = start.elapsed().as_secs_f64();
+            let per_frame = elapsed / frames as f64;
+            let mode = match (self.textured, self.interleave) {
+                (_, true) => "interleaved",
+                (true, false) => "textured",
+                (false, false) => "solid",
+            };
+            format!(
+                "GpuBenchmark (headless): {} {} rects x {} frames: {:.2} ms/frame, {:.1} fps, {:.2}M rects/s, {} batches/frame",
+                self.rect_count,
+                mode,
+                frames,
+                per_frame * 1000.0,
+                1.0 / per_frame,
+                self.rect_count as f64 / per_frame / 1e6,
+                batches
+            )
+        }
+    }
+});
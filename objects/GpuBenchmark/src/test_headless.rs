@@ -0,0 +1,31 @@
+use crate::GpuBenchmark;
+
+// Throughput report for the headless renderer, run with
+// `HOTLINE_GPU_BENCHMARK=<rect count> cargo test -p GpuBenchmark -- --ignored --nocapture`
+#[test]
+#[ignore]
+fn test_headless_benchmark() {
+    let rects = std::env::var("HOTLINE_GPU_BENCHMARK").map_or(10_000, |value| {
+        value.parse().unwrap_or_else(|e| panic!("invalid HOTLINE_GPU_BENCHMARK '{}': {}", value, e))
+    });
+    let mut bench = GpuBenchmark::default();
+    bench.set_rect_count(rects);
+    for (textured, interleave, mode) in
+        [(false, false, "solid"), (true, false, "textured"), (false, true, "interleaved")]
+    {
+        bench.set_textured(textured);
+        bench.set_interleave(interleave);
+        let report = bench.run_headless(60);
+        println!("{}", report);
+        assert!(report.contains(&format!("{} {} rects x 60 frames", rects, mode)), "{}", report);
+    }
+}
+
+#[test]
+fn test_interleaved_batches() {
+    let mut bench = GpuBenchmark::default();
+    bench.set_rect_count(4);
+    bench.set_interleave(true);
+    let report = bench.run_headless(1);
+    assert!(report.ends_with(", 4 batches/frame"), "{}", report);
+}
@@ -7,7 +7,14 @@ hotline::object!({
         y: f64,
         width: u32,
         height: u32,
+        #[default(1.0)]
+        scale: f64, // Applied about the center
         data: Vec<u8>,
+        #[setter]
+        #[default(true)]
+        visible: bool, // Hidden shapes keep their place in the scene but neither draw nor take clicks
+        #[setter]
+        z_index: i64, // Draw order within the scene, higher on top; ties keep creation order
     }
 
     impl Image {
@@ -47,15 +54,20 @@ hotline::object!({
         }
 
         pub fn render(&mut self, buffer: &mut [u8], bw: i64, bh: i64, pitch: i64) {
-            let x_start = self.x.max(0.0) as i64;
-            let y_start = self.y.max(0.0) as i64;
-            let x_end = (self.x + self.width as f64).min(bw as f64) as i64;
-            let y_end = (self.y + self.height as f64).min(bh as f64) as i64;
+            if !self.visible {
+                return;
+            }
+            let (ox, oy, w, h) = self.bounds();
+            let x_start = ox.max(0.0) as i64;
+            let y_start = oy.max(0.0) as i64;
+            let x_end = (ox + w).min(bw as f64) as i64;
+            let y_end = (oy + h).min(bh as f64) as i64;
 
             for y in y_start..y_end {
                 for x in x_start..x_end {
-                    let src_x = (x - self.x as i64) as usize;
-                    let src_y = (y - self.y as i64) as usize;
+                    // Nearest-neighbor sample of the unscaled image
+                    let src_x = (((x as f64 - ox) / self.scale) as usize).min(self.width as usize - 1);
+                    let src_y = (((y as f64 - oy) / self.scale) as usize).min(self.height as usize - 1);
                     let src_off = (src_y * self.width as usize + src_x) * 4;
                     let dst_off = (y * pitch as i64 + x * 4) as usize;
                     if src_off + 3 < self.data.len() && dst_off + 3 < buffer.len() {
@@ -65,8 +77,57 @@ hotline::object!({
             }
         }
 
-        pub fn bounds(&mut self) -> (f64, f64, f64, f64) {
-            (self.x, self.y, self.width as f64, self.height as f64)
+        pub fn bounds(&self) -> (f64, f64, f64, f64) {
+            let (cx, cy) = self.center();
+            let w = self.width as f64 * self.scale;
+            let h = self.height as f64 * self.scale;
+            (cx - w / 2.0, cy - h / 2.0, w, h)
+        }
+
+        pub fn position(&self) -> (f64, f64) {
+            (self.x, self.y)
+        }
+
+        pub fn center(&self) -> (f64, f64) {
+            (self.x + self.width as f64 / 2.0, self.y + self.height as f64 / 2.0)
+        }
+
+        pub fn contains_point(&self, x: f64, y: f64) -> bool {
+            let (bx, by, w, h) = self.bounds();
+            self.visible && x >= bx && x <= bx + w && y >= by && y <= by + h
+        }
+
+        pub fn set_scale(&mut self, scale: f64) {
+            self.scale = scale;
+        }
+
+        pub fn scale(&self) -> f64 {
+            self.scale
+        }
+
+        pub fn is_visible(&self) -> bool {
+            self.visible
+        }
+
+        pub fn z_index(&self) -> i64 {
+            self.z_index
+        }
+
+        pub fn move_by(&mut self, dx: f64, dy: f64) {
+            self.x += dx;
+            self.y += dy;
+        }
+
+        pub fn info_lines(&self) -> Vec<String> {
+            let precision = self.registry().precision();
+            vec![
+                "Image".to_string(),
+                format!("  x: {:.*}", precision.length, self.x),
+                format!("  y: {:.*}", precision.length, self.y),
+                format!("  width: {}", self.width),
+                format!("  height: {}", self.height),
+                format!("  scale: {:.*}", precision.number, self.scale),
+            ]
         }
     }
 });
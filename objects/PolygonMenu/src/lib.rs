@@ -60,6 +60,34 @@ hotline::object!({
             }
         }
 
+        // Move the hover highlight by `delta` items from the keyboard, wrapping around
+        pub fn move_hover(&mut self, delta: i64) {
+            if !self.visible || self.renderers.is_empty() {
+                return;
+            }
+            let len = self.renderers.len() as i64;
+            let next = match self.hover {
+                Some(i) => (i as i64 + delta).rem_euclid(len),
+                None if delta >= 0 => 0,
+                None => len - 1,
+            };
+            self.hover = Some(next as usize);
+        }
+
+        // Choose the hovered side count, closing the menu
+        pub fn activate(&mut self) -> Option<i64> {
+            if !self.visible {
+                return None;
+            }
+            let sides = self.preview_sides();
+            self.close();
+            sides
+        }
+
+        pub fn position(&self) -> (f64, f64) {
+            (self.x, self.y)
+        }
+
         fn preview_sides(&self) -> Option<i64> {
             self.hover.map(|i| i as i64 + 3)
         }
@@ -84,6 +112,37 @@ hotline::object!({
             }
         }
 
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if !self.visible {
+                return;
+            }
+            let item_height = 16.0;
+            let (x, y, w) = (self.x as f32, self.y as f32, 100.0);
+            let h = self.renderers.len() as f32 * item_height as f32;
+            gpu_renderer.add_solid_rect(x, y, w, h, [0.0, 0.0, 0.0, 0.5]);
+            if let Some(idx) = self.hover {
+                let row_y = y + idx as f32 * item_height as f32;
+                gpu_renderer.add_solid_rect(x, row_y, w, item_height as f32, [0.25, 0.25, 0.5, 0.78]);
+            }
+            for (i, r) in self.renderers.iter_mut().enumerate() {
+                r.set_x(self.x);
+                r.set_y(self.y + i as f64 * item_height);
+                r.render_gpu(gpu_renderer);
+            }
+            // Outline preview of the hovered side count beside its row
+            if let (Some(sides), Some(idx)) = (self.preview_sides(), self.hover) {
+                let (cx, cy) = (self.x + 120.0, self.y + idx as f64 * item_height + item_height / 2.0);
+                let vertex = |i: i64| {
+                    let angle = 2.0 * std::f64::consts::PI * i as f64 / sides as f64;
+                    ((cx + 20.0 * angle.cos()) as f32, (cy + 20.0 * angle.sin()) as f32)
+                };
+                for i in 0..sides {
+                    let ((x0, y0), (x1, y1)) = (vertex(i), vertex(i + 1));
+                    gpu_renderer.add_line(x0, y0, x1, y1, 1.0, [1.0, 0.0, 0.0, 1.0]);
+                }
+            }
+        }
+
         pub fn is_visible(&self) -> bool {
             self.visible
         }
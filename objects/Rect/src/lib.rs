@@ -7,7 +7,14 @@ hotline::object!({
         height: f64,
         #[default(0.0)]
         rotation: f64, // radians
+        #[default(1.0)]
+        scale: f64, // Applied about the center, on top of width/height
         atlas_id: Option<u32>,
+        #[setter]
+        #[default(true)]
+        visible: bool, // Hidden shapes keep their place in the scene but neither draw nor take clicks
+        #[setter]
+        z_index: i64, // Draw order within the scene, higher on top; ties keep creation order
     }
 
     impl Rect {
@@ -26,7 +33,8 @@ hotline::object!({
             let dy = point_y - cy;
             let rx = dx * cos_r + dy * sin_r;
             let ry = -dx * sin_r + dy * cos_r;
-            rx.abs() <= self.width / 2.0 && ry.abs() <= self.height / 2.0
+            let (hw, hh) = self.half_extents();
+            self.visible && rx.abs() <= hw && ry.abs() <= hh
         }
 
         pub fn position(&self) -> (f64, f64) {
@@ -57,14 +65,34 @@ hotline::object!({
             self.rotation
         }
 
+        pub fn set_scale(&mut self, scale: f64) {
+            self.scale = scale;
+        }
+
+        pub fn scale(&self) -> f64 {
+            self.scale
+        }
+
+        pub fn is_visible(&self) -> bool {
+            self.visible
+        }
+
+        pub fn z_index(&self) -> i64 {
+            self.z_index
+        }
+
+        // Half the on-screen width and height, after scaling
+        fn half_extents(&self) -> (f64, f64) {
+            (self.width * self.scale / 2.0, self.height * self.scale / 2.0)
+        }
+
         pub fn center(&self) -> (f64, f64) {
             (self.x + self.width / 2.0, self.y + self.height / 2.0)
         }
 
         pub fn corners(&self) -> [(f64, f64); 4] {
             let (cx, cy) = self.center();
-            let hw = self.width / 2.0;
-            let hh = self.height / 2.0;
+            let (hw, hh) = self.half_extents();
             let (sin_r, cos_r) = self.rotation.sin_cos();
             let rot = |dx: f64, dy: f64| -> (f64, f64) {
                 let rx = dx * cos_r - dy * sin_r;
@@ -74,26 +102,35 @@ hotline::object!({
             [rot(-hw, -hh), rot(hw, -hh), rot(hw, hh), rot(-hw, hh)]
         }
 
+        // Takes the on-screen rect; the unscaled size is kept centered on it
         pub fn resize(&mut self, x: f64, y: f64, width: f64, height: f64) {
-            self.x = x;
-            self.y = y;
-            self.width = width;
-            self.height = height;
+            self.width = width / self.scale;
+            self.height = height / self.scale;
+            self.x = x + width / 2.0 - self.width / 2.0;
+            self.y = y + height / 2.0 - self.height / 2.0;
         }
 
         pub fn info_lines(&self) -> Vec<String> {
+            let precision = self.registry().precision();
             vec![
                 "Rect".to_string(),
-                format!("  x: {:.1}", self.x),
-                format!("  y: {:.1}", self.y),
-                format!("  width: {:.1}", self.width),
-                format!("  height: {:.1}", self.height),
-                format!("  rotation: {:.2}", self.rotation),
+                "  Position".to_string(),
+                format!("    x: {:.*}", precision.length, self.x),
+                format!("    y: {:.*}", precision.length, self.y),
+                "  Size".to_string(),
+                format!("    width: {:.*}", precision.length, self.width),
+                format!("    height: {:.*}", precision.length, self.height),
+                "  Transform".to_string(),
+                format!("    rotation: {:.*}", precision.angle, self.rotation),
+                format!("    scale: {:.*}", precision.number, self.scale),
             ]
         }
 
         pub fn render(&mut self, buffer: &mut [u8], buffer_width: i64, buffer_height: i64, pitch: i64) {
             // CPU rendering fallback - only used if GPU rendering isn't available
+            if !self.visible {
+                return;
+            }
             let t = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis();
 
             let (bx, by, bw, bh) = self.bounds();
@@ -104,6 +141,7 @@ hotline::object!({
 
             let (cx, cy) = self.center();
             let (sin_r, cos_r) = self.rotation.sin_cos();
+            let (hw, hh) = self.half_extents();
 
             for y in y_start..y_end {
                 for x in x_start..x_end {
@@ -111,7 +149,7 @@ hotline::object!({
                     let dy = y as f64 - cy;
                     let rx = dx * cos_r + dy * sin_r;
                     let ry = -dx * sin_r + dy * cos_r;
-                    if rx.abs() <= self.width / 2.0 && ry.abs() <= self.height / 2.0 {
+                    if rx.abs() <= hw && ry.abs() <= hh {
                         let offset = (y * (pitch as u32) + x * 4) as usize;
                         if offset + 3 < buffer.len() {
                             buffer[offset] = (t / 6 % 255) as u8; // B
@@ -134,6 +172,9 @@ hotline::object!({
         }
 
         pub fn generate_commands(&mut self, gpu_renderer: &mut GPURenderer) {
+            if !self.visible {
+                return;
+            }
             // Make sure we have an atlas
             if self.atlas_id.is_none() {
                 self.register_atlas(gpu_renderer);
@@ -151,12 +192,14 @@ hotline::object!({
                 let a = 255u8;
 
                 // Use color modulation instead of creating new atlases
+                let (cx, cy) = self.center();
+                let (hw, hh) = self.half_extents();
                 gpu_renderer.add_command(RenderCommand::Rect {
                     texture_id: atlas_id,
-                    dest_x: self.x,
-                    dest_y: self.y,
-                    dest_width: self.width,
-                    dest_height: self.height,
+                    dest_x: cx - hw,
+                    dest_y: cy - hh,
+                    dest_width: hw * 2.0,
+                    dest_height: hh * 2.0,
                     rotation: self.rotation,
                     color: (a, b, g, r), // ABGR order
                 });
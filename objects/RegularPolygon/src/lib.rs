@@ -1,3 +1,8 @@
+#[cfg(test)]
+mod test_fields;
+#[cfg(test)]
+mod test_state;
+
 hotline::object!({
     #[derive(Default, Clone)]
     pub struct RegularPolygon {
@@ -17,8 +22,16 @@ hotline::object!({
         #[default(0.0)]
         rotation: f64,
         #[setter]
+        #[default(1.0)]
+        scale: f64, // Multiplies radius
+        #[setter]
         #[default((255,0,0,255))]
         color: (u8, u8, u8, u8),
+        #[setter]
+        #[default(true)]
+        visible: bool, // Hidden shapes keep their place in the scene but neither draw nor take clicks
+        #[setter]
+        z_index: i64, // Draw order within the scene, higher on top; ties keep creation order
     }
 
     impl RegularPolygon {
@@ -42,10 +55,30 @@ hotline::object!({
             (self.x, self.y)
         }
 
+        pub fn center(&self) -> (f64, f64) {
+            (self.x, self.y)
+        }
+
         pub fn rotation(&self) -> f64 {
             self.rotation
         }
 
+        pub fn scale(&self) -> f64 {
+            self.scale
+        }
+
+        pub fn radius(&self) -> f64 {
+            self.radius
+        }
+
+        pub fn is_visible(&self) -> bool {
+            self.visible
+        }
+
+        pub fn z_index(&self) -> i64 {
+            self.z_index
+        }
+
         pub fn move_by(&mut self, dx: f64, dy: f64) {
             self.x += dx;
             self.y += dy;
@@ -54,16 +87,17 @@ hotline::object!({
         pub fn resize(&mut self, x: f64, y: f64, width: f64, height: f64) {
             self.x = x + width / 2.0;
             self.y = y + height / 2.0;
-            self.radius = width.max(height) / 2.0;
+            self.radius = width.max(height) / 2.0 / self.scale;
         }
 
         fn vertices(&self) -> Vec<(f64, f64)> {
             let mut verts = Vec::new();
             let sides = self.sides.max(3) as usize;
+            let radius = self.radius * self.scale;
             for i in 0..sides {
                 let angle = 2.0 * std::f64::consts::PI * (i as f64) / (sides as f64) + self.rotation;
-                let vx = self.x + self.radius * angle.cos();
-                let vy = self.y + self.radius * angle.sin();
+                let vx = self.x + radius * angle.cos();
+                let vy = self.y + radius * angle.sin();
                 verts.push((vx, vy));
             }
             verts
@@ -71,18 +105,20 @@ hotline::object!({
 
         pub fn contains_point(&self, x: f64, y: f64) -> bool {
             let verts = self.vertices();
-            self.point_in_polygon(x, y, &verts)
+            self.visible && self.point_in_polygon(x, y, &verts)
         }
 
         pub fn info_lines(&mut self) -> Vec<String> {
             let (b, g, r, a) = self.color;
+            let precision = self.registry().precision();
             vec![
                 "RegularPolygon".to_string(),
-                format!("  x: {:.1}", self.x),
-                format!("  y: {:.1}", self.y),
-                format!("  radius: {:.1}", self.radius),
+                format!("  x: {:.*}", precision.length, self.x),
+                format!("  y: {:.*}", precision.length, self.y),
+                format!("  radius: {:.*}", precision.length, self.radius),
                 format!("  sides: {}", self.sides),
-                format!("  rotation: {:.2}", self.rotation),
+                format!("  rotation: {:.*}", precision.angle, self.rotation),
+                format!("  scale: {:.*}", precision.number, self.scale),
                 format!("  color: ({},{},{},{})", b, g, r, a),
             ]
         }
@@ -103,7 +139,7 @@ hotline::object!({
         }
 
         pub fn render(&mut self, buffer: &mut [u8], buffer_width: i64, buffer_height: i64, pitch: i64) {
-            if self.radius <= 0.0 {
+            if self.radius <= 0.0 || !self.visible {
                 return;
             }
             let verts = self.vertices();
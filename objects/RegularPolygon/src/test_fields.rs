@@ -0,0 +1,16 @@
+use crate::RegularPolygon;
+use hotline::{FieldValue, HotlineObject};
+
+#[test]
+fn test_set_field_rejects_unrepresentable_integers() {
+    let mut polygon = RegularPolygon::default();
+    polygon.initialize(0.0, 0.0, 10.0, 5);
+
+    assert!(polygon.set_field("sides", FieldValue::Number(f64::NAN)).is_err());
+    assert!(polygon.set_field("sides", FieldValue::Number(f64::INFINITY)).is_err());
+    assert!(polygon.set_field("sides", FieldValue::Number(1e30)).is_err());
+    assert_eq!(polygon.sides, 5);
+
+    polygon.set_field("sides", FieldValue::Number(6.4)).unwrap();
+    assert_eq!(polygon.sides, 6);
+}
@@ -0,0 +1,54 @@
+use crate::RegularPolygon;
+use hotline::{HotlineObject, StateFormat};
+
+#[test]
+fn test_state_round_trip() {
+    let mut heptagon = RegularPolygon::default();
+    heptagon.initialize(120.0, 80.0, 42.5, 7);
+    heptagon.set_rotation(0.73);
+    heptagon.set_scale(1.5);
+    heptagon.set_color((10, 20, 30, 200));
+
+    let data = heptagon.serialize_state().unwrap();
+    let mut restored = RegularPolygon::default();
+    restored.deserialize_state(&data).unwrap();
+
+    assert_eq!(restored.sides, 7);
+    assert_eq!(restored.radius, 42.5);
+    assert_eq!(restored.rotation, 0.73);
+    assert_eq!(restored.scale, 1.5);
+    assert_eq!(restored.position(), (120.0, 80.0));
+    assert_eq!(restored.color, (10, 20, 30, 200));
+    assert_eq!(restored.bounds(), heptagon.bounds());
+}
+
+#[test]
+fn test_json_state_round_trip() {
+    let mut pentagon = RegularPolygon::default();
+    pentagon.initialize(10.0, 20.0, 5.0, 5);
+
+    let data = pentagon.serialize_state_as(StateFormat::Json).unwrap();
+    let text = String::from_utf8(data.clone()).unwrap();
+    assert!(text.contains("\n  \"sides\": 5"));
+
+    let mut restored = RegularPolygon::default();
+    restored.deserialize_state(&data).unwrap();
+    assert_eq!(restored.sides, 5);
+    assert_eq!(restored.bounds(), pentagon.bounds());
+}
+
+#[test]
+fn test_hidden_state_round_trip() {
+    let mut square = RegularPolygon::default();
+    square.initialize(50.0, 50.0, 20.0, 4);
+    assert!(square.contains_point(50.0, 50.0));
+
+    square.set_visible(false);
+    assert!(!square.contains_point(50.0, 50.0));
+
+    let data = square.serialize_state().unwrap();
+    let mut restored = RegularPolygon::default();
+    restored.deserialize_state(&data).unwrap();
+    assert!(!restored.is_visible());
+    assert!(!restored.contains_point(50.0, 50.0));
+}
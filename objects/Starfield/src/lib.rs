@@ -1,6 +1,9 @@
+#[cfg(test)]
+mod test_validate;
+
 hotline::object!({
     use rand::Rng;
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
     use std::path::{Path, PathBuf};
 
     // 3D star representation
@@ -13,16 +16,35 @@ hotline::object!({
     }
 
     // Code poster representation
-    #[derive(Clone, Debug)]
+    #[derive(Clone, Debug, ::hotline::serde::Serialize, ::hotline::serde::Deserialize)]
+    #[serde(crate = "::hotline::serde")]
     struct CodePoster {
         pos: (f32, f32, f32),    // World position
         file_path: PathBuf,      // Path to the source file
         display_name: String,    // Short name to display
-        content: Option<String>, // Cached file content
         lines_to_show: usize,    // How many lines to display based on distance
         color: (u8, u8, u8, u8), // RGBA color based on file type
         width: f32,              // Poster width in world units
         height: f32,             // Poster height in world units
+        show_signatures: bool,   // Preview declarations rather than the top of the file
+        // Cached file content, reread when a poster in range needs it rather than saved
+        #[serde(skip)]
+        content: Option<String>,
+        #[serde(skip)]
+        signatures: Vec<String>, // Declaration lines of content, found when it's read
+        #[serde(skip)]
+        modified: Option<std::time::SystemTime>, // File modification time when content was read
+    }
+
+    // Placement of the posters spawned around the origin
+    #[derive(Clone, Copy, Debug, PartialEq, Default)]
+    pub enum PosterLayout {
+        #[default]
+        Cylinder, // Ring around the Z axis, biased toward +Z in front of the camera
+        Sphere, // Shell around the origin
+        Disc,   // Flat ring in the XZ plane
+        Grid,   // Regular cubic lattice centered on the origin
+        Tree,   // One cluster per folder, placed near its parent folder's cluster
     }
 
     #[derive(Default, Clone)]
@@ -34,15 +56,17 @@ hotline::object!({
         code_posters: Vec<CodePoster>,
         all_source_files: Vec<PathBuf>, // All discovered source files
         cpu_text_renderer: Option<CpuTextRenderer>,
-        line_texture_cache: HashMap<u64, (u32, f32, f32, f32)>, // hash -> (tex_id, logical_w, texture_w, h)
+        #[serde(skip)]
+        line_texture_cache: HashMap<u64, Vec<(u32, f32, f32, f32)>>, // hash -> (tex_id, logical_w, texture_w, h) tiles
 
         // Camera state
         camera_pos: (f32, f32, f32),      // Camera position in world space
         camera_velocity: (f32, f32, f32), // Current velocity
         camera_yaw: f32,                  // Rotation around Y axis
         camera_pitch: f32,                // Rotation around X axis
+        camera_roll: f32,                 // Rotation around the forward axis, positive rolls right; 6DOF only
 
-        // Camera basis vectors (calculated from yaw/pitch)
+        // Camera basis vectors (calculated from yaw/pitch/roll)
         camera_forward: (f32, f32, f32),
         camera_right: (f32, f32, f32),
         camera_up: (f32, f32, f32),
@@ -50,11 +74,23 @@ hotline::object!({
         // Control inputs
         forward_accel: f32,          // LT - RT (LT forward, RT backward)
         strafe_velocity: (f32, f32), // Left stick X/Y
+        look_input: (f32, f32),      // Right stick X/Y
+        roll_input: f32,             // Shoulder buttons or Q/E, -1 rolls left and 1 rolls right
+        roll_speed: f32,             // Radians per second at full roll input
+        look_sensitivity: f32,       // Radians turned per update at full right stick deflection
+        look_smoothing: f32,         // 0 = raw stick, towards 1 = heavier smoothing of look deltas
+        smoothed_look: (f32, f32),   // Filtered yaw/pitch deltas from the previous update
+        invert_pitch: bool,          // Pushing the right stick up looks down
+        invert_yaw: bool,            // Pushing the right stick right looks left
         six_dof_mode: bool,          // True for space sim, false for FPS-style
 
         // Rendering
         rect: Option<Rect>,
+        // GPU textures, recreated by the next render_gpu rather than saved
+        #[serde(skip)]
         atlas_ids: Vec<Option<u32>>,
+        #[serde(skip)]
+        bloom_atlas_id: Option<u32>, // Soft radial falloff scaled under each blooming star
 
         // Movement parameters
         acceleration_multiplier: f32,
@@ -69,22 +105,49 @@ hotline::object!({
         max_render_distance: f32,
         streak_velocity_threshold: f32,
         streak_length_multiplier: f32,
+        streaks_enabled: bool,           // Quality toggle; off draws no velocity streaks at all
+        max_streaks: usize,              // Only the nearest stars get streaks past this many
+        brightness_gamma: f32,           // Exponent on the distance fade; 1 is linear, higher dims far stars faster
+        bloom_enabled: bool,             // Quality toggle; glow around stars brighter than bloom_threshold
+        bloom_threshold: f32,            // Brightness (0-1) where the glow starts
+        bloom_intensity: f32,            // Glow opacity at full brightness
+        transparent_background: bool,    // Skips the black fill so the window clear color shows, for overlays
+        star_cull_margin: Option<f64>,   // Offscreen pixels before culling stars; None derives from FOV
+        poster_cull_margin: Option<f64>, // Offscreen pixels before culling posters; None derives from FOV
+        clip_to_bounds: bool,            // Keeps posters and panel text inside the rect
+        click_through: bool,             // Presses outside the panel reach the objects underneath
 
         // Star field parameters
         star_density: f32,   // Stars per cubic unit
         spawn_radius: f32,   // Radius around camera to spawn stars
         despawn_radius: f32, // Radius beyond which to remove stars
 
+        // Larger shortfalls fill in over several frames; 0 spawns them all at once
+        max_star_spawns_per_frame: usize,
+        star_refill: bool, // Filling the whole volume after a density increase, not just the edge
+
         // Code poster parameters
-        poster_spawn_radius: f32,   // Radius around camera to spawn posters
-        poster_despawn_radius: f32, // Radius beyond which to remove posters
-        poster_density: f32,        // Posters per cubic unit
-        max_poster_distance: f32,   // Maximum distance to render text
-        poster_scale: f32,          // Base scale for posters
+        poster_spawn_radius: f32,     // Radius around camera to spawn posters
+        poster_despawn_radius: f32,   // Radius beyond which to remove posters
+        poster_density: f32,          // Posters per cubic unit
+        max_active_posters: usize,    // Hard cap on live posters, whatever the density
+        max_poster_distance: f32,     // Maximum distance to render text
+        poster_fade_fraction: f32,    // Last part of max_poster_distance that posters fade out over; 0 cuts off
+        poster_scale: f32,            // Base scale for posters
+        truncate_poster_titles: bool, // Middle-ellipsize titles wider than their poster
+        poster_layout: PosterLayout,  // Placement used by spawn_initial_posters
+        poster_background_tint: f32,  // Fraction of the file color used for the poster background
+        poster_text_contrast: bool,   // Pick light or dark code text from the background luminance
+        poster_signatures: bool,      // New posters preview declarations rather than the top of the file
+        poster_refresh_interval: f64, // Seconds between checks of loaded posters' files for edits; 0 disables
+        max_poster_file_bytes: u64,   // Larger sources are left out of the poster pool; 0 takes any size
+        #[serde(skip)]
+        last_poster_refresh: Option<std::time::Instant>,
 
         // UI elements
         speed_display: Option<TextRenderer>,
         param_displays: Vec<TextRenderer>,
+        mode_dropdown: Option<Dropdown>, // Movement mode picker on the "Mode:" panel row
         panel_visible: bool,
         selected_param: Option<usize>,
         hovered_param: Option<usize>,
@@ -99,9 +162,7 @@ hotline::object!({
         dragging_param: bool,
         drag_start_x: f64,
         drag_start_value: f32,
-
-        // Frame timing
-        last_update_time: f64,
+        param_drag_sensitivity: f64, // Fraction of a parameter's range per pixel dragged
 
         // Random state for consistent star generation
         seed: u64,
@@ -115,25 +176,34 @@ hotline::object!({
             // Scan different directories
             let dirs_to_scan = ["objects", "hotline", "runtime", "hotline-macros"];
 
+            let mut oversized = 0;
             for dir in &dirs_to_scan {
                 if let Ok(_entries) = std::fs::read_dir(dir) {
-                    self.scan_directory_recursive(&Path::new(dir));
+                    self.scan_directory_recursive(&Path::new(dir), &mut oversized);
                 }
             }
 
+            if oversized > 0 {
+                eprintln!("Skipped {} source files over {} bytes for posters", oversized, self.max_poster_file_bytes);
+            }
             if self.all_source_files.is_empty() {
                 eprintln!("WARNING: No source files found! Searched dirs: {:?}", dirs_to_scan);
             }
         }
 
-        fn scan_directory_recursive(&mut self, dir: &Path) {
+        fn scan_directory_recursive(&mut self, dir: &Path, oversized: &mut usize) {
             if let Ok(entries) = std::fs::read_dir(dir) {
                 for entry in entries.flatten() {
                     let path = entry.path();
                     if path.is_dir() {
-                        self.scan_directory_recursive(&path);
+                        self.scan_directory_recursive(&path, oversized);
                     } else if path.extension().and_then(|s| s.to_str()) == Some("rs") {
-                        self.all_source_files.push(path);
+                        let len = entry.metadata().map_or(0, |m| m.len());
+                        if self.max_poster_file_bytes > 0 && len > self.max_poster_file_bytes {
+                            *oversized += 1;
+                        } else {
+                            self.all_source_files.push(path);
+                        }
                     }
                 }
             }
@@ -164,6 +234,7 @@ hotline::object!({
             self.camera_velocity = (0.0, 0.0, 0.0);
             self.camera_yaw = 0.0;
             self.camera_pitch = 0.0;
+            self.camera_roll = 0.0;
 
             // Calculate initial camera basis vectors
             self.update_camera_basis();
@@ -176,6 +247,8 @@ hotline::object!({
             self.strafe_speed = 25.0; // Slightly reduced for better control
             self.max_velocity = 300.0; // Increased for more exciting movement
             self.damping = 0.98; // Less damping for more responsive feel
+            self.roll_speed = 1.5;
+            self.look_sensitivity = 0.015;
 
             // Visual parameters
             self.fov = std::f32::consts::PI / 3.0; // 60 degrees
@@ -184,18 +257,36 @@ hotline::object!({
             self.max_render_distance = 1000.0;
             self.streak_velocity_threshold = 50.0;
             self.streak_length_multiplier = 0.5;
+            self.streaks_enabled = true;
+            self.max_streaks = 2000;
+            self.brightness_gamma = 1.0;
+            self.bloom_enabled = false;
+            self.bloom_threshold = 0.6;
+            self.bloom_intensity = 0.35;
+            self.transparent_background = false;
+            self.clip_to_bounds = false;
+            self.click_through = true;
 
             // Star field parameters
             self.star_density = 0.0001; // Stars per cubic unit (reduced for performance)
             self.spawn_radius = 300.0;
             self.despawn_radius = 400.0;
+            self.max_star_spawns_per_frame = 500;
+            self.star_refill = false;
 
             // Code poster parameters
             self.poster_spawn_radius = 200.0;
             self.poster_despawn_radius = 300.0;
             self.poster_density = 0.0001; // Increased to spawn ~3-4 posters
+            self.max_active_posters = 64;
             self.max_poster_distance = 150.0;
+            self.poster_fade_fraction = 0.25;
             self.poster_scale = 30.0; // Base size of posters
+            self.truncate_poster_titles = true;
+            self.poster_background_tint = 0.3;
+            self.poster_text_contrast = true;
+            self.poster_refresh_interval = 1.0;
+            self.max_poster_file_bytes = 256 * 1024;
 
             // UI state
             self.panel_visible = true;
@@ -207,14 +298,11 @@ hotline::object!({
             self.resize_mode = None;
             self.drag_offset = (0.0, 0.0);
             self.dragging_param = false;
+            self.param_drag_sensitivity = 0.01;
 
             // Initialize random seed from current time
             self.seed = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs();
 
-            // Set initial time
-            self.last_update_time =
-                std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs_f64();
-
             // Initialize speed display
             if let Some(registry) = self.get_registry() {
                 ::hotline::set_library_registry(registry);
@@ -243,7 +331,7 @@ hotline::object!({
             // Camera info
             let mut cam_header = TextRenderer::new();
             cam_header.set_text("-- Camera --".to_string());
-            cam_header.set_color((255, 200, 200, 255));
+            cam_header.set_color((200, 200, 255, 255));
             self.param_displays.push(cam_header);
 
             // Create displays for new parameters
@@ -254,7 +342,7 @@ hotline::object!({
                 "Pitch: 0.0°",
                 "",
                 "-- Movement --",
-                "Mode: 6DOF Space",
+                "Mode:",
                 "Acceleration: 40.0",
                 "Strafe Speed: 25.0",
                 "Max Velocity: 300.0",
@@ -279,6 +367,12 @@ hotline::object!({
                 self.param_displays.push(param_display);
             }
 
+            let mut mode_dropdown = Dropdown::new();
+            mode_dropdown.set_options(vec!["6DOF Space".to_string(), "FPS Style".to_string()]);
+            mode_dropdown.set_width(140.0);
+            mode_dropdown.set_item_height(self.param_height);
+            self.mode_dropdown = Some(mode_dropdown);
+
             // Spawn initial stars around origin
             self.spawn_initial_stars();
 
@@ -287,6 +381,39 @@ hotline::object!({
             self.spawn_initial_posters();
         }
 
+        // Runs instead of `initialize` after a hot reload; the camera and tuned parameters were
+        // restored, and the stars and posters are rebuilt so edits to how they're made show up.
+        // Poster text, line textures and atlases aren't saved and are rebuilt as they're drawn.
+        fn after_reload(&mut self) {
+            self.reinitialize_preserving_camera();
+        }
+
+        // Respawns the stars around the camera and the posters from a fresh source scan, leaving
+        // camera_pos, camera_velocity, camera_yaw, camera_pitch and camera_roll where they were
+        pub fn reinitialize_preserving_camera(&mut self) {
+            self.update_camera_basis();
+            self.stars.clear();
+            self.star_refill = false;
+            self.spawn_initial_stars();
+            self.code_posters.clear();
+            self.scan_source_files();
+            self.spawn_initial_posters();
+        }
+
+        fn validate(&self) -> Result<(), String> {
+            let basis = [("forward", self.camera_forward), ("right", self.camera_right), ("up", self.camera_up)];
+            for (name, (x, y, z)) in basis {
+                let length = (x * x + y * y + z * z).sqrt();
+                if !length.is_finite() || (length - 1.0).abs() > 1e-3 {
+                    return Err(format!("camera {} vector has length {}, expected 1", name, length));
+                }
+            }
+            if !(0.0..1.0).contains(&self.look_smoothing) {
+                return Err(format!("look smoothing must be in [0, 1), got {}", self.look_smoothing));
+            }
+            Ok(())
+        }
+
         pub fn set_rect(&mut self, rect: Rect) {
             let (x, _y, w, _h) = rect.bounds();
             self.rect = Some(rect);
@@ -319,9 +446,16 @@ hotline::object!({
                 self.camera_right.2 * self.camera_forward.0 - self.camera_right.0 * self.camera_forward.2,
                 self.camera_right.0 * self.camera_forward.1 - self.camera_right.1 * self.camera_forward.0,
             );
+
+            // Roll turns right and up around forward; both stay unit length and perpendicular
+            let roll = if self.six_dof_mode { self.camera_roll } else { 0.0 };
+            let (sin, cos) = roll.sin_cos();
+            let (right, up) = (self.camera_right, self.camera_up);
+            self.camera_right = (right.0 * cos - up.0 * sin, right.1 * cos - up.1 * sin, right.2 * cos - up.2 * sin);
+            self.camera_up = (up.0 * cos + right.0 * sin, up.1 * cos + right.1 * sin, up.2 * cos + right.2 * sin);
         }
 
-        // Spawn initial stars in a sphere around origin
+        // Spawn initial stars in a sphere around the camera
         fn spawn_initial_stars(&mut self) {
             let mut rng = rand::rng();
             let volume = (4.0 / 3.0) * std::f32::consts::PI * self.spawn_radius.powi(3);
@@ -333,9 +467,9 @@ hotline::object!({
                 let phi = rng.random_range(0.0..std::f32::consts::PI);
                 let r = rng.random_range(0.0..self.spawn_radius);
 
-                let x = r * phi.sin() * theta.cos();
-                let y = r * phi.sin() * theta.sin();
-                let z = r * phi.cos();
+                let x = self.camera_pos.0 + r * phi.sin() * theta.cos();
+                let y = self.camera_pos.1 + r * phi.sin() * theta.sin();
+                let z = self.camera_pos.2 + r * phi.cos();
 
                 let brightness = rng.random_range(100..255);
                 let size = rng.random_range(0.5..2.0);
@@ -348,7 +482,9 @@ hotline::object!({
         fn spawn_initial_posters(&mut self) {
             let mut rng = rand::rng();
             let volume = (4.0 / 3.0) * std::f32::consts::PI * self.poster_spawn_radius.powi(3);
-            let poster_count = ((volume * self.poster_density) as usize).min(self.all_source_files.len());
+            let poster_count = ((volume * self.poster_density) as usize)
+                .min(self.all_source_files.len())
+                .min(self.max_active_posters.saturating_sub(self.code_posters.len()));
 
             if poster_count == 0 && !self.all_source_files.is_empty() {
                 eprintln!(
@@ -373,36 +509,61 @@ hotline::object!({
             }
 
             // Create posters for selected files
-            for file_path in selected_files.iter() {
-                // Spawn in a cone in front of the camera (positive Z region)
-                // Use cylindrical coordinates for better distribution
-                let angle = rng.random_range(0.0..std::f32::consts::TAU); // Full circle around Z axis
-                let radius = rng.random_range(20.0..80.0); // Lateral distance from Z axis
-                let z = rng.random_range(40.0..120.0); // Positive Z (in front of camera)
-
-                // Convert to Cartesian
-                let x = radius * angle.cos();
-                let y = radius * angle.sin();
-                // z is already set
-
-                // Add some vertical variation
-                let y_offset = rng.random_range(-20.0..20.0);
-                let y = y + y_offset;
-
-                // Create display name
-                let display_name = file_path.strip_prefix(".").unwrap_or(&file_path).to_string_lossy().to_string();
-
-                let poster = CodePoster {
-                    pos: (x, y, z),
-                    file_path: file_path.clone(),
-                    display_name,
-                    content: None,
-                    lines_to_show: 0,
-                    color: Self::get_file_color(&file_path),
-                    width: self.poster_scale,
-                    height: self.poster_scale * 1.5,
+            let grid_side = (selected_files.len() as f32).cbrt().ceil().max(1.0) as usize;
+            let cluster_centers =
+                if self.poster_layout == PosterLayout::Tree { self.tree_cluster_centers() } else { HashMap::new() };
+            let mut cluster_sizes: HashMap<PathBuf, usize> = HashMap::new();
+            for file_path in &selected_files {
+                *cluster_sizes.entry(tree_dir(file_path)).or_default() += 1;
+            }
+            let mut cluster_slots: HashMap<PathBuf, usize> = HashMap::new();
+            for (i, file_path) in selected_files.iter().enumerate() {
+                let (x, y, z) = match self.poster_layout {
+                    PosterLayout::Cylinder => {
+                        // Spawn in a cone in front of the camera (positive Z region)
+                        // Use cylindrical coordinates for better distribution
+                        let angle = rng.random_range(0.0..std::f32::consts::TAU); // Full circle around Z axis
+                        let radius = rng.random_range(20.0..80.0); // Lateral distance from Z axis
+                        let z = rng.random_range(40.0..120.0); // Positive Z (in front of camera)
+
+                        // Convert to Cartesian, with some vertical variation
+                        let y_offset = rng.random_range(-20.0..20.0);
+                        (radius * angle.cos(), radius * angle.sin() + y_offset, z)
+                    }
+                    PosterLayout::Sphere => {
+                        // Uniform direction: uniform height on the unit sphere and uniform azimuth
+                        let cos_theta: f32 = rng.random_range(-1.0..1.0);
+                        let sin_theta = (1.0 - cos_theta * cos_theta).sqrt();
+                        let angle = rng.random_range(0.0..std::f32::consts::TAU);
+                        let radius = rng.random_range(40.0..120.0);
+                        (radius * sin_theta * angle.cos(), radius * cos_theta, radius * sin_theta * angle.sin())
+                    }
+                    PosterLayout::Disc => {
+                        let angle = rng.random_range(0.0..std::f32::consts::TAU);
+                        let radius = rng.random_range(20.0..120.0);
+                        (radius * angle.cos(), 0.0, radius * angle.sin())
+                    }
+                    PosterLayout::Grid => {
+                        let spacing = self.poster_scale * 3.0;
+                        let center = (grid_side - 1) as f32 / 2.0;
+                        let cell = |n: usize| ((n % grid_side) as f32 - center) * spacing;
+                        (cell(i), cell(i / grid_side), cell(i / (grid_side * grid_side)))
+                    }
+                    PosterLayout::Tree => {
+                        // A small upright grid of the folder's posters around its cluster center
+                        let dir = tree_dir(file_path);
+                        let (cx, cy, cz) = cluster_centers[&dir];
+                        let side = (cluster_sizes[&dir] as f32).sqrt().ceil() as usize;
+                        let slot = cluster_slots.entry(dir).or_default();
+                        let (col, row) = (*slot % side, *slot / side);
+                        *slot += 1;
+                        let spacing = self.poster_scale * 1.2;
+                        let offset = |n: usize| (n as f32 - (side - 1) as f32 / 2.0) * spacing;
+                        (cx + offset(col), cy - offset(row) * 1.5, cz)
+                    }
                 };
 
+                let poster = self.new_poster(file_path, (x, y, z));
                 self.code_posters.push(poster);
             }
 
@@ -414,6 +575,62 @@ hotline::object!({
             }
         }
 
+        fn new_poster(&self, file_path: &Path, pos: (f32, f32, f32)) -> CodePoster {
+            CodePoster {
+                pos,
+                file_path: file_path.to_path_buf(),
+                display_name: file_path.strip_prefix(".").unwrap_or(file_path).to_string_lossy().to_string(),
+                content: None,
+                modified: None,
+                signatures: Vec::new(),
+                lines_to_show: 0,
+                color: Self::get_file_color(file_path),
+                width: self.poster_scale,
+                height: self.poster_scale * 1.5,
+                show_signatures: self.poster_signatures,
+            }
+        }
+
+        // Each folder's center is offset from its parent's, with siblings spread evenly over a sphere
+        // whose radius shrinks with depth, so a subtree stays together and apart from its neighbors
+        fn tree_cluster_centers(&self) -> HashMap<PathBuf, (f32, f32, f32)> {
+            let mut children: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
+            for file_path in &self.all_source_files {
+                let mut dir = PathBuf::new();
+                for component in tree_dir(file_path).components() {
+                    let child = dir.join(component);
+                    let siblings = children.entry(dir).or_default();
+                    if !siblings.contains(&child) {
+                        siblings.push(child.clone());
+                    }
+                    dir = child;
+                }
+            }
+
+            let mut centers = HashMap::from([(PathBuf::new(), (0.0, 0.0, 0.0))]);
+            let mut pending = vec![(PathBuf::new(), 0)];
+            while let Some((dir, depth)) = pending.pop() {
+                let Some(mut kids) = children.remove(&dir) else {
+                    continue;
+                };
+                kids.sort(); // Stable placement whatever order the scan found them in
+                let center = centers[&dir];
+                let count = kids.len() as f32;
+                let reach = self.poster_scale * 3.0 * count.sqrt() * 0.5f32.powi(depth);
+                for (i, kid) in kids.into_iter().enumerate() {
+                    // Fibonacci sphere: even heights, azimuth advancing by the golden angle
+                    let y = 1.0 - 2.0 * (i as f32 + 0.5) / count;
+                    let r = (1.0 - y * y).sqrt();
+                    let angle = i as f32 * 2.399_963;
+                    let kid_center =
+                        (center.0 + reach * r * angle.cos(), center.1 + reach * y, center.2 + reach * r * angle.sin());
+                    centers.insert(kid.clone(), kid_center);
+                    pending.push((kid, depth + 1));
+                }
+            }
+            centers
+        }
+
         pub fn update_controller(
             &mut self,
             left_x: f32,
@@ -427,10 +644,24 @@ hotline::object!({
             self.strafe_velocity = (left_x, left_y);
             self.forward_accel = left_trigger - right_trigger; // Swapped: LT forward, RT backward
 
+            self.look_input = (right_x, right_y);
+
             // Update camera rotation from right stick
-            let rotation_speed = 0.015; // Doubled for more responsive feel
-            self.camera_yaw += right_x * rotation_speed;
-            self.camera_pitch -= right_y * rotation_speed; // Invert for intuitive control
+            let rotation_speed = self.look_sensitivity;
+            // Stick Y is positive downward, so pitch is flipped unless the user asked for inverted look
+            let yaw_sign = if self.invert_yaw { -1.0 } else { 1.0 };
+            let pitch_sign = if self.invert_pitch { 1.0 } else { -1.0 };
+            let raw = (yaw_sign * right_x * rotation_speed, pitch_sign * right_y * rotation_speed);
+
+            // Exponential smoothing of the deltas to filter out stick jitter
+            let k = self.look_smoothing;
+            self.smoothed_look =
+                (self.smoothed_look.0 * k + raw.0 * (1.0 - k), self.smoothed_look.1 * k + raw.1 * (1.0 - k));
+            // Look deltas are relative to the rolled view, so pushing right turns towards the screen's right
+            let (sin, cos) = if self.six_dof_mode { self.camera_roll.sin_cos() } else { (0.0, 1.0) };
+            let (yaw_delta, pitch_delta) = self.smoothed_look;
+            self.camera_yaw += yaw_delta * cos - pitch_delta * sin;
+            self.camera_pitch += yaw_delta * sin + pitch_delta * cos;
 
             // Clamp pitch to prevent gimbal lock
             self.camera_pitch =
@@ -449,8 +680,9 @@ hotline::object!({
 
         pub fn set_acceleration_multiplier(&mut self, multiplier: f32) {
             self.acceleration_multiplier = multiplier;
+            let precision = self.registry().precision();
             if let Some(ref mut display) = self.speed_display {
-                display.set_text(format!("Speed: {:.1}x", multiplier));
+                display.set_text(format!("Speed: {:.*}x", precision.number, multiplier));
             }
         }
 
@@ -458,6 +690,311 @@ hotline::object!({
             self.acceleration_multiplier
         }
 
+        // True while the camera is moving or being steered, i.e. the next frame will look different
+        pub fn is_animating(&self) -> bool {
+            let active = |v: f32| v.abs() > 0.05; // Ignore stick drift
+            let (vx, vy, vz) = self.camera_velocity;
+            (vx * vx + vy * vy + vz * vz).sqrt() > 0.01
+                || active(self.forward_accel)
+                || active(self.strafe_velocity.0)
+                || active(self.strafe_velocity.1)
+                || active(self.look_input.0)
+                || active(self.look_input.1)
+                || (self.six_dof_mode && self.roll_input != 0.0)
+                || self.smoothed_look.0.abs() > 1e-4
+                || self.smoothed_look.1.abs() > 1e-4
+        }
+
+        pub fn set_look_sensitivity(&mut self, sensitivity: f32) {
+            assert!(
+                sensitivity.is_finite() && sensitivity > 0.0,
+                "look sensitivity must be positive, got {}",
+                sensitivity
+            );
+            self.look_sensitivity = sensitivity;
+        }
+
+        pub fn set_param_drag_sensitivity(&mut self, sensitivity: f64) {
+            assert!(
+                sensitivity.is_finite() && sensitivity > 0.0,
+                "param drag sensitivity must be positive, got {}",
+                sensitivity
+            );
+            self.param_drag_sensitivity = sensitivity;
+        }
+
+        // Smoothing factor for controller look input in [0, 1); 0 disables smoothing
+        pub fn set_look_smoothing(&mut self, smoothing: f32) {
+            assert!((0.0..1.0).contains(&smoothing), "look smoothing must be in [0, 1), got {}", smoothing);
+            self.look_smoothing = smoothing;
+        }
+
+        // -1 rolls left, 1 rolls right; the caller sets it every frame from whatever is held
+        pub fn set_roll_input(&mut self, roll: f32) {
+            assert!((-1.0..=1.0).contains(&roll), "roll input must be in [-1, 1], got {}", roll);
+            self.roll_input = roll;
+        }
+
+        pub fn set_roll_speed(&mut self, speed: f32) {
+            assert!(speed.is_finite() && speed >= 0.0, "roll speed must be non-negative, got {}", speed);
+            self.roll_speed = speed;
+        }
+
+        pub fn set_invert_pitch(&mut self, invert: bool) {
+            self.invert_pitch = invert;
+        }
+
+        pub fn set_invert_yaw(&mut self, invert: bool) {
+            self.invert_yaw = invert;
+        }
+
+        pub fn set_truncate_poster_titles(&mut self, truncate: bool) {
+            self.truncate_poster_titles = truncate;
+        }
+
+        // Shorten a path to fit `max_width` pixels by replacing the middle of its directory part
+        // with "…", always keeping the file name
+        fn fit_title(&self, name: &str, max_width: f64) -> String {
+            let renderer = self.cpu_text_renderer.as_ref().expect("CpuTextRenderer not initialized");
+            if !self.truncate_poster_titles || renderer.measure_text(name) <= max_width {
+                return name.to_string();
+            }
+            let split = name.rfind('/').unwrap_or(0);
+            let (dir, file) = name.split_at(split);
+            let mut head: Vec<char> = dir.chars().collect();
+            loop {
+                let candidate = format!("{}…{}", head.iter().collect::<String>(), file);
+                if head.is_empty() || renderer.measure_text(&candidate) <= max_width {
+                    return candidate;
+                }
+                head.pop();
+            }
+        }
+
+        // Lowering the cap drops posters beyond it right away
+        pub fn set_max_active_posters(&mut self, max: usize) {
+            self.max_active_posters = max;
+            self.code_posters.truncate(max);
+        }
+
+        pub fn set_streaks_enabled(&mut self, enabled: bool) {
+            self.streaks_enabled = enabled;
+        }
+
+        pub fn streaks_enabled(&self) -> bool {
+            self.streaks_enabled
+        }
+
+        pub fn set_max_streaks(&mut self, max: usize) {
+            self.max_streaks = max;
+        }
+
+        pub fn set_brightness_gamma(&mut self, gamma: f32) {
+            assert!(gamma.is_finite() && gamma > 0.0, "brightness gamma must be positive, got {}", gamma);
+            self.brightness_gamma = gamma;
+        }
+
+        pub fn set_bloom_enabled(&mut self, enabled: bool) {
+            self.bloom_enabled = enabled;
+        }
+
+        pub fn bloom_enabled(&self) -> bool {
+            self.bloom_enabled
+        }
+
+        pub fn set_bloom_threshold(&mut self, threshold: f32) {
+            assert!((0.0..1.0).contains(&threshold), "bloom threshold must be in [0, 1), got {}", threshold);
+            self.bloom_threshold = threshold;
+        }
+
+        pub fn set_bloom_intensity(&mut self, intensity: f32) {
+            assert!((0.0..=1.0).contains(&intensity), "bloom intensity must be in [0, 1], got {}", intensity);
+            self.bloom_intensity = intensity;
+        }
+
+        pub fn set_transparent_background(&mut self, transparent: bool) {
+            self.transparent_background = transparent;
+        }
+
+        pub fn set_clip_to_bounds(&mut self, clip: bool) {
+            self.clip_to_bounds = clip;
+        }
+
+        pub fn set_click_through(&mut self, enabled: bool) {
+            self.click_through = enabled;
+        }
+
+        // Whether a press at (x, y) is the starfield's: the panel and mode picker always are, and an
+        // open picker sees every press so it can close. The rest of the rect is only while
+        // click_through is off and it can be dragged or resized.
+        pub fn hit_test(&self, x: f64, y: f64) -> bool {
+            let Some(rect) = &self.rect else {
+                return false;
+            };
+            if self.panel_visible {
+                let dropdown = self.mode_dropdown.as_ref();
+                if dropdown.is_some_and(|dropdown| dropdown.is_open() || dropdown.contains_point(x, y)) {
+                    return true;
+                }
+                if x >= self.panel_x && x <= self.panel_x + self.panel_width {
+                    return true;
+                }
+            }
+            if self.click_through {
+                return false;
+            }
+            let (rx, ry, rw, rh) = rect.bounds();
+            self.get_resize_edge(x, y).is_some() || (x >= rx && x <= rx + rw && y >= ry && y <= ry + rh)
+        }
+
+        pub fn poster_layout(&self) -> PosterLayout {
+            self.poster_layout
+        }
+
+        // Re-places all posters with the new layout
+        pub fn set_poster_layout(&mut self, layout: PosterLayout) {
+            self.poster_layout = layout;
+            self.code_posters.clear();
+            self.spawn_initial_posters();
+        }
+
+        pub fn cycle_poster_layout(&mut self) {
+            let next = match self.poster_layout {
+                PosterLayout::Cylinder => PosterLayout::Sphere,
+                PosterLayout::Sphere => PosterLayout::Disc,
+                PosterLayout::Disc => PosterLayout::Grid,
+                PosterLayout::Grid => PosterLayout::Tree,
+                PosterLayout::Tree => PosterLayout::Cylinder,
+            };
+            self.set_poster_layout(next);
+        }
+
+        // Jump to the closest poster, stopping poster.width * 2 short of it on the side the camera
+        // was on, and face it
+        pub fn warp_to_nearest_poster(&mut self) {
+            let cam = self.camera_pos;
+            let dist_sq = |p: &CodePoster| {
+                let (dx, dy, dz) = (p.pos.0 - cam.0, p.pos.1 - cam.1, p.pos.2 - cam.2);
+                dx * dx + dy * dy + dz * dz
+            };
+            let Some(poster) = self.code_posters.iter().min_by(|a, b| dist_sq(a).total_cmp(&dist_sq(b))) else {
+                return;
+            };
+
+            // From the poster back towards the camera; a camera sitting on the poster backs off along its view
+            let (dx, dy, dz) = (cam.0 - poster.pos.0, cam.1 - poster.pos.1, cam.2 - poster.pos.2);
+            let len = (dx * dx + dy * dy + dz * dz).sqrt();
+            let away = if len > 1e-4 {
+                (dx / len, dy / len, dz / len)
+            } else {
+                (-self.camera_forward.0, -self.camera_forward.1, -self.camera_forward.2)
+            };
+            let standoff = poster.width * 2.0;
+            self.camera_pos =
+                (poster.pos.0 + away.0 * standoff, poster.pos.1 + away.1 * standoff, poster.pos.2 + away.2 * standoff);
+
+            // Inverse of the forward vector in update_camera_basis, looking along -away
+            let limit = std::f32::consts::PI / 2.0 - 0.1;
+            self.camera_pitch = (-away.1).asin().clamp(-limit, limit);
+            self.camera_yaw = away.0.atan2(away.2);
+            self.camera_velocity = (0.0, 0.0, 0.0);
+            self.smoothed_look = (0.0, 0.0);
+            self.update_camera_basis();
+        }
+
+        fn poster_background(&self, poster: &CodePoster) -> [f32; 4] {
+            let tint = self.poster_background_tint;
+            [
+                poster.color.0 as f32 / 255.0 * tint,
+                poster.color.1 as f32 / 255.0 * tint,
+                poster.color.2 as f32 / 255.0 * tint,
+                0.95,
+            ]
+        }
+
+        pub fn set_poster_background_tint(&mut self, tint: f32) {
+            assert!((0.0..=1.0).contains(&tint), "poster background tint must be in [0, 1], got {}", tint);
+            self.poster_background_tint = tint;
+        }
+
+        pub fn set_poster_fade_fraction(&mut self, fraction: f32) {
+            assert!((0.0..=1.0).contains(&fraction), "poster fade fraction must be in [0, 1], got {}", fraction);
+            self.poster_fade_fraction = fraction;
+        }
+
+        // Alpha for a poster at `dist`, ramping to 0 across the last poster_fade_fraction of the range
+        fn poster_fade(&self, dist: f32) -> f32 {
+            let fade_start = self.max_poster_distance * (1.0 - self.poster_fade_fraction);
+            if dist >= self.max_poster_distance {
+                0.0
+            } else if dist <= fade_start {
+                1.0
+            } else {
+                (self.max_poster_distance - dist) / (self.max_poster_distance - fade_start)
+            }
+        }
+
+        pub fn set_poster_text_contrast(&mut self, enabled: bool) {
+            self.poster_text_contrast = enabled;
+        }
+
+        // Poster text coverage correction; cached line textures are redrawn with it
+        pub fn set_text_gamma(&mut self, gamma: f32) {
+            if let Some(ref mut renderer) = self.cpu_text_renderer {
+                renderer.set_gamma(gamma);
+            }
+            self.line_texture_cache.clear();
+        }
+
+        pub fn poster_signatures(&self) -> bool {
+            self.poster_signatures
+        }
+
+        // Sets every poster, and the default for ones spawned later
+        pub fn set_poster_signatures(&mut self, enabled: bool) {
+            self.poster_signatures = enabled;
+            for poster in self.code_posters.iter_mut() {
+                poster.show_signatures = enabled;
+            }
+        }
+
+        // Just the live posters of `path`, as spawned from the source scan; returns whether any matched
+        pub fn set_poster_signatures_for(&mut self, path: &Path, enabled: bool) -> bool {
+            let mut matched = false;
+            for poster in self.code_posters.iter_mut().filter(|p| p.file_path == path) {
+                poster.show_signatures = enabled;
+                matched = true;
+            }
+            matched
+        }
+
+        // Rescans and drops live posters of files now over the limit; respawning fills the gaps
+        pub fn set_max_poster_file_bytes(&mut self, bytes: u64) {
+            self.max_poster_file_bytes = bytes;
+            self.scan_source_files();
+            let pool = &self.all_source_files;
+            self.code_posters.retain(|poster| pool.contains(&poster.file_path));
+        }
+
+        pub fn active_poster_count(&self) -> usize {
+            self.code_posters.len()
+        }
+
+        pub fn set_star_cull_margin(&mut self, margin: Option<f64>) {
+            self.star_cull_margin = margin;
+        }
+
+        pub fn set_poster_cull_margin(&mut self, margin: Option<f64>) {
+            self.poster_cull_margin = margin;
+        }
+
+        // (star, poster) culling margins in pixels. The defaults are tuned for the 60° FOV and
+        // grow with tan(fov / 2), since wider FOVs sweep objects across the edges faster.
+        fn cull_margins(&self) -> (f64, f64) {
+            let fov_factor = ((self.fov / 2.0).tan() / (std::f32::consts::PI / 6.0).tan()) as f64;
+            (self.star_cull_margin.unwrap_or(50.0 * fov_factor), self.poster_cull_margin.unwrap_or(100.0 * fov_factor))
+        }
+
         pub fn randomize_params(&mut self) {
             let mut rng = rand::rng();
 
@@ -476,9 +1013,19 @@ hotline::object!({
             // Star field parameters
             self.star_density = rng.random_range(0.0001..0.01);
 
-            // Respawn stars with new density
-            self.stars.clear();
-            self.spawn_initial_stars();
+            self.star_density_changed();
+        }
+
+        // Drops the excess right away; a shortfall is left for update_star_field to fill in gradually
+        fn star_density_changed(&mut self) {
+            let volume = (4.0 / 3.0) * std::f32::consts::PI * self.spawn_radius.powi(3);
+            let target_count = (volume * self.star_density) as usize;
+            self.stars.truncate(target_count);
+            self.star_refill = self.stars.len() < target_count;
+        }
+
+        pub fn set_max_star_spawns_per_frame(&mut self, max: usize) {
+            self.max_star_spawns_per_frame = max;
         }
 
         pub fn toggle_panel(&mut self) {
@@ -487,6 +1034,15 @@ hotline::object!({
 
         pub fn toggle_movement_mode(&mut self) {
             self.six_dof_mode = !self.six_dof_mode;
+            self.sync_mode_dropdown();
+            self.update_camera_basis();
+        }
+
+        fn sync_mode_dropdown(&mut self) {
+            let index = if self.six_dof_mode { 0 } else { 1 };
+            if let Some(ref mut dropdown) = self.mode_dropdown {
+                dropdown.set_selected(index);
+            }
         }
 
         fn get_param_value(&self, index: usize) -> Option<f32> {
@@ -516,8 +1072,7 @@ hotline::object!({
                 7 => self.streak_length_multiplier = value.clamp(0.1, 5.0),
                 8 => {
                     self.star_density = (value / 10000.0).clamp(0.0001, 0.01);
-                    self.stars.clear();
-                    self.spawn_initial_stars();
+                    self.star_density_changed();
                 }
                 _ => {}
             }
@@ -538,12 +1093,20 @@ hotline::object!({
             }
         }
 
-        pub fn update(&mut self, _delta_time: f64) {
-            // Calculate actual delta time
-            let current_time =
-                std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs_f64();
-            let dt = (current_time - self.last_update_time).min(0.1) as f32; // Cap at 100ms
-            self.last_update_time = current_time;
+        // The host clamps delta_time, so a stalled frame can't fling the camera
+        pub fn update(&mut self, delta_time: f64) {
+            let dt = delta_time as f32;
+
+            if self.six_dof_mode && self.roll_input != 0.0 {
+                self.camera_roll += self.roll_input * self.roll_speed * dt;
+                // Wrap roll to [-PI, PI]
+                if self.camera_roll > std::f32::consts::PI {
+                    self.camera_roll -= std::f32::consts::TAU;
+                } else if self.camera_roll < -std::f32::consts::PI {
+                    self.camera_roll += std::f32::consts::TAU;
+                }
+                self.update_camera_basis();
+            }
 
             // Apply acceleration to velocity
             let accel_x = self.camera_forward.0 * self.forward_accel * self.acceleration_multiplier;
@@ -604,25 +1167,22 @@ hotline::object!({
             self.camera_pos.2 += self.camera_velocity.2 * dt;
 
             // Update speed display
+            let precision = self.registry().precision();
             if let Some(ref mut display) = self.speed_display {
-                display.set_text(format!("Speed: {:.1}", vel_mag));
+                display.set_text(format!("Speed: {:.*}", precision.rate, vel_mag));
             }
 
             // Update parameter displays
             if self.param_displays.len() > 4 {
-                self.param_displays[2].set_text(format!(
-                    "Position: ({:.0}, {:.0}, {:.0})",
-                    self.camera_pos.0, self.camera_pos.1, self.camera_pos.2
+                let (x, y, z, places) = (self.camera_pos.0, self.camera_pos.1, self.camera_pos.2, precision.length);
+                self.param_displays[2].set_text(format!("Position: ({x:.places$}, {y:.places$}, {z:.places$})"));
+                self.param_displays[3].set_text(format!("Velocity: {:.*}", precision.rate, vel_mag));
+                self.param_displays[4].set_text(format!("Yaw: {:.*}°", precision.angle, self.camera_yaw.to_degrees()));
+                self.param_displays[5].set_text(format!(
+                    "Pitch: {:.*}°",
+                    precision.angle,
+                    self.camera_pitch.to_degrees()
                 ));
-                self.param_displays[3].set_text(format!("Velocity: {:.1}", vel_mag));
-                self.param_displays[4].set_text(format!("Yaw: {:.1}°", self.camera_yaw.to_degrees()));
-                self.param_displays[5].set_text(format!("Pitch: {:.1}°", self.camera_pitch.to_degrees()));
-
-                // Update movement mode display
-                if self.param_displays.len() > 8 {
-                    self.param_displays[8]
-                        .set_text(format!("Mode: {}", if self.six_dof_mode { "6DOF Space" } else { "FPS Style" }));
-                }
 
                 if self.param_displays.len() > 21 {
                     self.param_displays[21].set_text(format!("Star Count: {}", self.stars.len()));
@@ -664,13 +1224,18 @@ hotline::object!({
             // Spawn new stars if needed
             if self.stars.len() < target_count {
                 let mut rng = rand::rng();
-                let to_spawn = target_count - self.stars.len();
+                let mut to_spawn = target_count - self.stars.len();
+                if self.max_star_spawns_per_frame > 0 {
+                    to_spawn = to_spawn.min(self.max_star_spawns_per_frame);
+                }
+                // Replacements for stars left behind appear at the edge; a denser field fills in throughout
+                let min_radius = if self.star_refill { 0.0 } else { self.spawn_radius * 0.8 };
 
                 for _ in 0..to_spawn {
                     // Random position in sphere around camera
                     let theta = rng.random_range(0.0..std::f32::consts::TAU);
                     let phi = rng.random_range(0.0..std::f32::consts::PI);
-                    let r = rng.random_range(self.spawn_radius * 0.8..self.spawn_radius);
+                    let r = rng.random_range(min_radius..self.spawn_radius);
 
                     let x = self.camera_pos.0 + r * phi.sin() * theta.cos();
                     let y = self.camera_pos.1 + r * phi.sin() * theta.sin();
@@ -682,6 +1247,9 @@ hotline::object!({
                     self.stars.push(StarData { pos: (x, y, z), brightness, size });
                 }
             }
+            if self.stars.len() >= target_count {
+                self.star_refill = false;
+            }
         }
 
         // Load content for a poster if needed
@@ -695,7 +1263,9 @@ hotline::object!({
                 let start = std::time::Instant::now();
                 match std::fs::read_to_string(&poster.file_path) {
                     Ok(content) => {
+                        poster.signatures = signature_lines(&content);
                         poster.content = Some(content);
+                        poster.modified = std::fs::metadata(&poster.file_path).and_then(|m| m.modified()).ok();
                     }
                     Err(e) => {
                         eprintln!("WARNING: Failed to load content for {}: {}", poster.display_name, e);
@@ -712,9 +1282,77 @@ hotline::object!({
             }
         }
 
+        // Drop cached content whose file changed on disk so the next pass reloads it
+        fn refresh_changed_posters(&mut self) {
+            let due = self
+                .last_poster_refresh
+                .is_none_or(|last| last.elapsed().as_secs_f64() >= self.poster_refresh_interval);
+            if self.poster_refresh_interval <= 0.0 || !due {
+                return;
+            }
+            self.last_poster_refresh = Some(std::time::Instant::now());
+            for poster in self.code_posters.iter_mut().filter(|p| p.content.is_some()) {
+                let modified = std::fs::metadata(&poster.file_path).and_then(|m| m.modified()).ok();
+                if modified != poster.modified {
+                    poster.content = None;
+                }
+            }
+        }
+
+        // Reload every poster's content on the next update, whatever the file times say
+        pub fn refresh_posters(&mut self) {
+            for poster in self.code_posters.iter_mut() {
+                poster.content = None;
+            }
+        }
+
+        pub fn set_poster_refresh_interval(&mut self, seconds: f64) {
+            assert!(seconds >= 0.0, "poster refresh interval must be non-negative, got {}", seconds);
+            self.poster_refresh_interval = seconds;
+        }
+
+        // Drops posters left behind and brings in files not on show near the edge of the spawn radius,
+        // so flying away from the initial cluster still passes code
+        fn recycle_code_posters(&mut self) {
+            let (cx, cy, cz) = self.camera_pos;
+            let despawn_sq = self.poster_despawn_radius * self.poster_despawn_radius;
+            let mut i = 0;
+            while i < self.code_posters.len() {
+                let (x, y, z) = self.code_posters[i].pos;
+                if (x - cx).powi(2) + (y - cy).powi(2) + (z - cz).powi(2) > despawn_sq {
+                    self.code_posters.swap_remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+
+            let volume = (4.0 / 3.0) * std::f32::consts::PI * self.poster_spawn_radius.powi(3);
+            let target_count =
+                ((volume * self.poster_density) as usize).min(self.all_source_files.len()).min(self.max_active_posters);
+            if self.code_posters.len() >= target_count {
+                return;
+            }
+
+            let shown: HashSet<&PathBuf> = self.code_posters.iter().map(|p| &p.file_path).collect();
+            let mut available: Vec<PathBuf> =
+                self.all_source_files.iter().filter(|path| !shown.contains(path)).cloned().collect();
+            let mut rng = rand::rng();
+            while self.code_posters.len() < target_count && !available.is_empty() {
+                let file_path = available.swap_remove(rng.random_range(0..available.len()));
+                let theta = rng.random_range(0.0..std::f32::consts::TAU);
+                let phi = rng.random_range(0.0..std::f32::consts::PI);
+                let r = rng.random_range(self.poster_spawn_radius * 0.8..self.poster_spawn_radius);
+                let pos = (cx + r * phi.sin() * theta.cos(), cy + r * phi.sin() * theta.sin(), cz + r * phi.cos());
+                let poster = self.new_poster(&file_path, pos);
+                self.code_posters.push(poster);
+            }
+        }
+
         // Update code posters based on camera position
         fn update_code_posters(&mut self) {
             let start = std::time::Instant::now();
+            self.refresh_changed_posters();
+            self.recycle_code_posters();
 
             // First pass: determine which posters need content based on distance
             let mut needs_content = Vec::new();
@@ -755,7 +1393,8 @@ hotline::object!({
 
                 if dist < self.max_poster_distance {
                     let visibility = 1.0 - (dist / self.max_poster_distance);
-                    poster.lines_to_show = (visibility * 50.0) as usize; // Max 50 lines
+                    // Max 50 lines, keeping one so the poster stays drawn until it has faded out
+                    poster.lines_to_show = ((visibility * 50.0) as usize).max(1);
                 } else {
                     poster.lines_to_show = 0;
                 }
@@ -765,8 +1404,6 @@ hotline::object!({
             if elapsed.as_millis() > 16 {
                 eprintln!("WARNING: update_code_posters took {}ms (>16ms frame budget)", elapsed.as_millis());
             }
-
-            // TODO: Add spawning/despawning logic similar to stars
         }
 
         pub fn setup_gpu_rendering(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
@@ -797,6 +1434,22 @@ hotline::object!({
                 }
             }
 
+            // Soft radial falloff for bloom
+            if self.bloom_atlas_id.is_none() {
+                const GLOW_SIZE: u32 = 16;
+                let mut texture_data = Vec::with_capacity((GLOW_SIZE * GLOW_SIZE * 4) as usize);
+                for y in 0..GLOW_SIZE {
+                    for x in 0..GLOW_SIZE {
+                        let dx = (x as f32 + 0.5) / GLOW_SIZE as f32 * 2.0 - 1.0;
+                        let dy = (y as f32 + 0.5) / GLOW_SIZE as f32 * 2.0 - 1.0;
+                        let falloff = (1.0 - (dx * dx + dy * dy).sqrt()).max(0.0);
+                        texture_data.extend_from_slice(&[255, 255, 255, (falloff * falloff * 255.0) as u8]);
+                    }
+                }
+                let id = gpu_renderer.create_rgba_texture(&texture_data, GLOW_SIZE, GLOW_SIZE).unwrap();
+                self.bloom_atlas_id = Some(id);
+            }
+
             // TODO: Update TextRenderer to use new GPU API
             // if let Some(ref mut display) = self.speed_display {
             //     display.register_atlas(gpu_renderer);
@@ -812,11 +1465,14 @@ hotline::object!({
                 return;
             }
 
-            // Update simulation
-            self.update(0.016);
-
-            // Make sure atlases are registered
-            if self.atlas_ids.is_empty() {
+            // Make sure atlases are registered (and weren't evicted by the renderer)
+            if self.atlas_ids.iter().any(|id| !id.is_some_and(|id| gpu_renderer.has_texture(id))) {
+                self.atlas_ids.clear();
+            }
+            if self.bloom_atlas_id.is_some_and(|id| !gpu_renderer.has_texture(id)) {
+                self.bloom_atlas_id = None;
+            }
+            if self.atlas_ids.is_empty() || self.bloom_atlas_id.is_none() {
                 self.setup_gpu_rendering(gpu_renderer);
             }
 
@@ -824,10 +1480,13 @@ hotline::object!({
                 let (rx, ry, rw, rh) = rect.bounds();
                 let screen_center_x = rx + rw / 2.0;
                 let screen_center_y = ry + rh / 2.0;
+                if self.clip_to_bounds {
+                    gpu_renderer.push_clip_rect(rx as f32, ry as f32, rw as f32, rh as f32);
+                }
 
                 // Draw background
                 let bg_atlas = self.atlas_ids.get(0).and_then(|id| *id);
-                if let Some(atlas_id) = bg_atlas {
+                if let Some(atlas_id) = bg_atlas.filter(|_| !self.transparent_background) {
                     // Black background
                     gpu_renderer.add_textured_rect(
                         rx as f32,
@@ -894,6 +1553,7 @@ hotline::object!({
 
                 // Calculate FOV scale
                 let fov_scale = (rh / 2.0) / (self.fov / 2.0).tan() as f64;
+                let (star_margin, _) = self.cull_margins();
 
                 // Sort stars by distance for proper rendering order (far to near)
                 let mut star_render_data: Vec<(f32, f64, f64, StarData)> = Vec::new();
@@ -925,10 +1585,10 @@ hotline::object!({
                     let screen_y = screen_center_y + (view_y / view_z) as f64 * fov_scale;
 
                     // Check if on screen
-                    if screen_x >= rx - 50.0
-                        && screen_x <= rx + rw + 50.0
-                        && screen_y >= ry - 50.0
-                        && screen_y <= ry + rh + 50.0
+                    if screen_x >= rx - star_margin
+                        && screen_x <= rx + rw + star_margin
+                        && screen_y >= ry - star_margin
+                        && screen_y <= ry + rh + star_margin
                     {
                         _visible_count += 1;
                         star_render_data.push((view_z, screen_x, screen_y, *star));
@@ -944,8 +1604,15 @@ hotline::object!({
                     star_render_data.truncate(MAX_VISIBLE_STARS);
                 }
 
+                // Stars are sorted far to near, so the last max_streaks are the nearest and brightest
+                let streak_start = if self.streaks_enabled && vel_mag > self.streak_velocity_threshold {
+                    star_render_data.len().saturating_sub(self.max_streaks)
+                } else {
+                    usize::MAX
+                };
+
                 // Render stars
-                for (view_z, screen_x, screen_y, star) in star_render_data {
+                for (i, (view_z, screen_x, screen_y, star)) in star_render_data.into_iter().enumerate() {
                     // Choose atlas based on size
                     let atlas_idx = if star.size > 1.5 {
                         2
@@ -960,12 +1627,13 @@ hotline::object!({
                         let size = (star.size * self.star_size_base / view_z.sqrt()) as f64;
 
                         // Calculate brightness based on distance
-                        let distance_fade = (1.0 - view_z / self.max_render_distance).max(0.0);
+                        let distance_fade =
+                            (1.0 - view_z / self.max_render_distance).max(0.0).powf(self.brightness_gamma);
                         let brightness =
                             (star.brightness as f32 * distance_fade * self.star_brightness_base / 255.0).min(1.0);
 
                         // Draw velocity streaks if moving fast
-                        if vel_mag > self.streak_velocity_threshold {
+                        if i >= streak_start {
                             // Calculate streak based on velocity direction in screen space
                             let velocity_screen_x = self.camera_velocity.0 * self.camera_right.0
                                 + self.camera_velocity.1 * self.camera_right.1
@@ -994,6 +1662,26 @@ hotline::object!({
                             );
                         }
 
+                        // Bright stars bleed into their neighbors through two widening glow layers. The
+                        // renderer only alpha blends, which over the dark sky reads much like additive light.
+                        if let Some(glow_id) = self.bloom_atlas_id.filter(|_| self.bloom_enabled) {
+                            let excess = (brightness - self.bloom_threshold) / (1.0 - self.bloom_threshold);
+                            if excess > 0.0 {
+                                let alpha = excess * self.bloom_intensity;
+                                for (scale, layer_alpha) in [(3.0, alpha), (6.0, alpha * 0.5)] {
+                                    let glow = size.max(1.0) * scale;
+                                    gpu_renderer.add_textured_rect(
+                                        (screen_x - glow / 2.0) as f32,
+                                        (screen_y - glow / 2.0) as f32,
+                                        glow as f32,
+                                        glow as f32,
+                                        glow_id,
+                                        [brightness, brightness, brightness, layer_alpha],
+                                    );
+                                }
+                            }
+                        }
+
                         // Draw star dot
                         gpu_renderer.add_textured_rect(
                             (screen_x - size / 2.0) as f32,
@@ -1009,8 +1697,11 @@ hotline::object!({
                 // Draw code posters
                 self.render_code_posters(gpu_renderer, rx, ry, rw, rh, screen_center_x, screen_center_y, fov_scale);
 
+                let theme = self.registry().theme();
+
                 // Draw speed display
                 if let Some(ref mut display) = self.speed_display {
+                    display.set_color(theme.text);
                     display.set_x(rx + 10.0);
                     display.set_y(ry + rh - 20.0);
                     display.render_gpu(gpu_renderer);
@@ -1020,29 +1711,12 @@ hotline::object!({
                 if self.panel_visible {
                     let panel_y = ry + 10.0;
 
-                    // Use the 1x1 white star texture for solid rectangles
-                    if let Some(Some(white_tex)) = self.atlas_ids.get(0) {
-                        // Draw panel background
-                        gpu_renderer.add_textured_rect(
-                            self.panel_x as f32,
-                            panel_y as f32,
-                            self.panel_width as f32,
-                            (rh - 20.0) as f32,
-                            *white_tex,
-                            [0.156, 0.156, 0.156, 0.784], // Semi-transparent dark background
-                        );
-
-                        // Draw panel border
-                        // Left border
-                        gpu_renderer.add_textured_rect(
-                            self.panel_x as f32,
-                            panel_y as f32,
-                            1.0,
-                            (rh - 20.0) as f32,
-                            *white_tex,
-                            [0.5, 0.5, 0.5, 1.0], // Gray border
-                        );
-                    }
+                    // Draw panel background, with the left border along its straight edge between the corners
+                    let (px, py, pw, ph) =
+                        (self.panel_x as f32, panel_y as f32, self.panel_width as f32, (rh - 20.0) as f32);
+                    let radius = 8.0;
+                    gpu_renderer.add_rounded_rect(px, py, pw, ph, radius, theme.panel);
+                    gpu_renderer.add_solid_rect(px, py + radius, 1.0, (ph - 2.0 * radius).max(0.0), theme.border);
 
                     // Update and draw parameter displays
                     let mut y_offset = panel_y + 10.0;
@@ -1076,6 +1750,8 @@ hotline::object!({
 
                     // First, collect all the data we need
                     let mut display_updates = Vec::new();
+                    let precision = self.registry().precision();
+
                     let param_names = [
                         "Acceleration",     // 0
                         "Strafe Speed",     // 1
@@ -1093,24 +1769,22 @@ hotline::object!({
                             if let Some(value) = self.get_param_value(*idx) {
                                 let name = param_names.get(*idx).unwrap_or(&"Unknown");
 
-                                // Special formatting for parameters
+                                // Places follow the kind of value named, e.g. damping and density are fine
+                                let places = precision.for_name(name);
                                 let value_str = match idx {
-                                    2 => format!("{:.0}", value),  // max velocity
-                                    3 => format!("{:.3}", value),  // damping (needs precision)
-                                    4 => format!("{:.0}°", value), // FOV in degrees
-                                    8 => format!("{:.4}", value),  // density (small number)
-                                    _ => format!("{:.1}", value),
+                                    4 => format!("{:.*}°", places, value), // FOV in degrees
+                                    _ => format!("{:.*}", places, value),
                                 };
 
                                 let text = format!("{}: {}", name, value_str);
 
                                 // Determine color
                                 let color = if self.selected_param == Some(*idx) {
-                                    (255, 255, 200, 255)
+                                    theme.header_text
                                 } else if self.hovered_param == Some(*idx) {
-                                    (220, 220, 255, 255)
+                                    theme.accent_text()
                                 } else {
-                                    (200, 200, 200, 255)
+                                    theme.text
                                 };
 
                                 Some((text, color))
@@ -1142,10 +1816,14 @@ hotline::object!({
                             display.set_x(self.panel_x + 10.0);
                             display.set_y(y_offset);
 
-                            // Apply the text and color
+                            // Apply the text and color; rows without a parameter are the title, headers and readouts
                             if let Some((text, color)) = text_and_color {
                                 display.set_text(text);
                                 display.set_color(color);
+                            } else if matches!(display_idx, 1 | 7 | 14 | 21) {
+                                display.set_color(theme.header_text);
+                            } else {
+                                display.set_color(theme.text);
                             }
 
                             display.render_gpu(gpu_renderer);
@@ -1165,7 +1843,7 @@ hotline::object!({
                                         bar_width as f32,
                                         bar_height as f32,
                                         *white_tex,
-                                        [60.0 / 255.0, 60.0 / 255.0, 60.0 / 255.0, 1.0], // Dark gray
+                                        theme.panel_header,
                                     );
 
                                     // Value bar
@@ -1175,7 +1853,7 @@ hotline::object!({
                                         (bar_width * normalized as f64) as f32,
                                         bar_height as f32,
                                         *white_tex,
-                                        [255.0 / 255.0, 105.0 / 255.0, 180.0 / 255.0, 1.0], // Pink
+                                        theme.accent,
                                     );
                                 }
                             }
@@ -1183,8 +1861,54 @@ hotline::object!({
                             y_offset += self.param_height;
                         }
                     }
+
+                    // Drawn last so the open list covers the rows below it
+                    if let Some(ref mut dropdown) = self.mode_dropdown {
+                        dropdown.set_x(self.panel_x + 60.0);
+                        dropdown.set_y(panel_y + 10.0 + 8.0 * self.param_height - 3.0);
+                        dropdown.render_gpu(gpu_renderer);
+                    }
+                }
+
+                if self.clip_to_bounds {
+                    gpu_renderer.pop_clip_rect();
+                }
+            }
+        }
+
+        // Textures for one line of poster text, rendered on first use. Lines wider than the text
+        // renderer's texture limits come back as several tiles, left to right.
+        fn line_textures(
+            cache: &mut HashMap<u64, Vec<(u32, f32, f32, f32)>>,
+            text_renderer: &CpuTextRenderer,
+            gpu_renderer: &mut dyn ::hotline::GpuRenderingContext,
+            text: &str,
+            color: (u8, u8, u8, u8),
+        ) -> Option<Vec<(u32, f32, f32, f32)>> {
+            let hash = {
+                let mut hasher = std::collections::hash_map::DefaultHasher::new();
+                std::hash::Hash::hash(text, &mut hasher);
+                std::hash::Hash::hash(&color, &mut hasher);
+                std::hash::Hasher::finish(&hasher)
+            };
+            if let Some(cached) = cache.get(&hash) {
+                if cached.iter().all(|tile| gpu_renderer.has_texture(tile.0)) {
+                    return Some(cached.clone());
+                }
+            }
+
+            let mut tiles = Vec::new();
+            for (rgba_data, logical_w, tex_w, h) in text_renderer.render_line_tiles(text.to_string(), color) {
+                match gpu_renderer.create_rgba_texture(&rgba_data, tex_w, h) {
+                    Ok(tex_id) => tiles.push((tex_id, logical_w as f32, tex_w as f32, h as f32)),
+                    Err(e) => {
+                        eprintln!("Failed to create texture for line: {}", e);
+                        return None;
+                    }
                 }
             }
+            cache.insert(hash, tiles.clone());
+            Some(tiles)
         }
 
         fn render_code_posters(
@@ -1216,6 +1940,7 @@ hotline::object!({
             // Sort posters by distance (far to near) for proper rendering
             let sorting_start = std::time::Instant::now();
             let mut poster_render_data: Vec<(usize, f32, f64, f64)> = Vec::new();
+            let (_, poster_margin) = self.cull_margins();
 
             for (idx, poster) in self.code_posters.iter().enumerate() {
                 if poster.lines_to_show == 0 || poster.content.is_none() {
@@ -1242,11 +1967,10 @@ hotline::object!({
                 let screen_y = screen_center_y + (view_y / view_z) as f64 * fov_scale;
 
                 // Check if poster would be on screen (with some margin)
-                let margin = 100.0;
-                if screen_x >= rx - margin
-                    && screen_x <= rx + rw + margin
-                    && screen_y >= ry - margin
-                    && screen_y <= ry + rh + margin
+                if screen_x >= rx - poster_margin
+                    && screen_x <= rx + rw + poster_margin
+                    && screen_y >= ry - poster_margin
+                    && screen_y <= ry + rh + poster_margin
                 {
                     poster_render_data.push((idx, view_z, screen_x, screen_y));
                 }
@@ -1281,6 +2005,12 @@ hotline::object!({
             for (idx, view_z, screen_x, screen_y) in poster_render_data {
                 let _poster_start = std::time::Instant::now();
                 let poster = &self.code_posters[idx];
+                let (dx, dy, dz) = (
+                    poster.pos.0 - self.camera_pos.0,
+                    poster.pos.1 - self.camera_pos.1,
+                    poster.pos.2 - self.camera_pos.2,
+                );
+                let fade = self.poster_fade((dx * dx + dy * dy + dz * dz).sqrt());
 
                 // Calculate poster size based on distance
                 let scale = fov_scale / view_z as f64;
@@ -1290,12 +2020,8 @@ hotline::object!({
                 // Use the 1x1 white star texture for solid rectangles
                 if let Some(Some(white_tex)) = self.atlas_ids.get(0) {
                     // Draw poster background
-                    let bg_color = [
-                        poster.color.0 as f32 / 255.0 * 0.3,
-                        poster.color.1 as f32 / 255.0 * 0.3,
-                        poster.color.2 as f32 / 255.0 * 0.3,
-                        0.95,
-                    ];
+                    let mut bg_color = self.poster_background(poster);
+                    bg_color[3] *= fade;
 
                     gpu_renderer.add_textured_rect(
                         (screen_x - poster_width as f64 / 2.0) as f32,
@@ -1311,7 +2037,7 @@ hotline::object!({
                         poster.color.0 as f32 / 255.0,
                         poster.color.1 as f32 / 255.0,
                         poster.color.2 as f32 / 255.0,
-                        poster.color.3 as f32 / 255.0,
+                        poster.color.3 as f32 / 255.0 * fade,
                     ];
 
                     let border_thickness = (scale as f32 / 5.0).max(2.0); // Increased thickness
@@ -1344,101 +2070,52 @@ hotline::object!({
 
                 // Calculate line height and opacity
                 let line_height = 14.0 * scale as f64 / 20.0;
-                let opacity = (1.0 - view_z / self.max_poster_distance) as f32;
+                let opacity = (1.0 - view_z / self.max_poster_distance) * fade;
                 let title_y = screen_y - poster_height as f64 / 2.0 + 5.0;
 
                 // Render title
-                let title_hash = {
-                    let mut hasher = std::collections::hash_map::DefaultHasher::new();
-                    std::hash::Hash::hash(&poster.display_name, &mut hasher);
-                    std::hash::Hash::hash(&poster.color, &mut hasher);
-                    std::hash::Hasher::finish(&hasher)
-                };
-
-                let (title_tex_id, logical_width, texture_width, title_height) = if let Some(&cached) =
-                    self.line_texture_cache.get(&title_hash)
-                {
-                    cached
-                } else {
-                    let (rgba_data, logical_w, tex_w, h) =
-                        self.cpu_text_renderer.as_ref().unwrap().render_line(poster.display_name.clone(), poster.color);
-                    match gpu_renderer.create_rgba_texture(&rgba_data, tex_w, h) {
-                        Ok(tex_id) => {
-                            let cached_data = (tex_id, logical_w as f32, tex_w as f32, h as f32);
-                            self.line_texture_cache.insert(title_hash, cached_data);
-                            cached_data
-                        }
-                        Err(e) => {
-                            eprintln!("Failed to create texture for title: {}", e);
-                            continue;
-                        }
-                    }
+                let title = self.fit_title(&poster.display_name, (poster_width - 10.0) as f64);
+                let Some(tiles) = Self::line_textures(
+                    &mut self.line_texture_cache,
+                    self.cpu_text_renderer.as_ref().unwrap(),
+                    gpu_renderer,
+                    &title,
+                    poster.color,
+                ) else {
+                    continue;
                 };
-
-                // Use logical_width for the quad size, and texture_width for texture coords.
-                let u1 = logical_width / texture_width;
-                gpu_renderer.add_textured_rect_with_coords(
-                    (screen_x - poster_width as f64 / 2.0 + 5.0) as f32,
-                    title_y as f32,
-                    logical_width,
-                    title_height,
-                    title_tex_id,
-                    0.0,
-                    0.0,
-                    u1,
-                    1.0,
-                    [1.0, 1.0, 1.0, opacity],
-                );
+                let title_x = (screen_x - poster_width as f64 / 2.0 + 5.0) as f32;
+                draw_line_tiles(gpu_renderer, &tiles, title_x, title_y as f32, opacity);
 
                 // Render code lines
                 if let Some(content) = &poster.content {
-                    let lines: Vec<&str> = content.lines().take(poster.lines_to_show).collect();
+                    // Files without declarations, like docs and configs, keep showing their top lines
+                    let lines: Vec<&str> = if poster.show_signatures && !poster.signatures.is_empty() {
+                        poster.signatures.iter().map(String::as_str).take(poster.lines_to_show).collect()
+                    } else {
+                        content.lines().take(poster.lines_to_show).collect()
+                    };
                     let start_y = title_y + 20.0 * scale as f64 / 20.0;
-                    let line_color = (200, 200, 200, 255); // Light gray for code
+                    let line_color = if self.poster_text_contrast {
+                        contrasting_text_color(self.poster_background(poster))
+                    } else {
+                        (200, 200, 200, 255) // Light gray for code
+                    };
 
                     for (i, line) in lines.iter().enumerate() {
                         let line_y = start_y + i as f64 * line_height;
 
-                        // Hash line content + color
-                        let line_hash = {
-                            let mut hasher = std::collections::hash_map::DefaultHasher::new();
-                            std::hash::Hash::hash(line, &mut hasher);
-                            std::hash::Hash::hash(&line_color, &mut hasher);
-                            std::hash::Hasher::finish(&hasher)
+                        let Some(tiles) = Self::line_textures(
+                            &mut self.line_texture_cache,
+                            self.cpu_text_renderer.as_ref().unwrap(),
+                            gpu_renderer,
+                            line,
+                            line_color,
+                        ) else {
+                            continue;
                         };
-
-                        let (tex_id, logical_width, texture_width, height) =
-                            if let Some(&cached) = self.line_texture_cache.get(&line_hash) {
-                                cached
-                            } else {
-                                let (rgba_data, logical_w, tex_w, h) =
-                                    self.cpu_text_renderer.as_ref().unwrap().render_line(line.to_string(), line_color);
-                                match gpu_renderer.create_rgba_texture(&rgba_data, tex_w, h) {
-                                    Ok(tex_id) => {
-                                        let cached_data = (tex_id, logical_w as f32, tex_w as f32, h as f32);
-                                        self.line_texture_cache.insert(line_hash, cached_data);
-                                        cached_data
-                                    }
-                                    Err(e) => {
-                                        eprintln!("Failed to create texture for line: {}", e);
-                                        continue;
-                                    }
-                                }
-                            };
-
-                        let u1 = logical_width / texture_width;
-                        gpu_renderer.add_textured_rect_with_coords(
-                            (screen_x - poster_width as f64 / 2.0 + 5.0) as f32,
-                            line_y as f32,
-                            logical_width,
-                            height,
-                            tex_id,
-                            0.0,
-                            0.0,
-                            u1,
-                            1.0,
-                            [1.0, 1.0, 1.0, opacity],
-                        );
+                        let line_x = (screen_x - poster_width as f64 / 2.0 + 5.0) as f32;
+                        draw_line_tiles(gpu_renderer, &tiles, line_x, line_y as f32, opacity);
                     }
                 }
             }
@@ -1484,6 +2161,18 @@ hotline::object!({
             if let Some(rect) = &self.rect {
                 let (rx, ry, rw, rh) = rect.bounds();
 
+                // The mode picker's open list can extend past the row it sits on
+                if let Some(dropdown) = self.mode_dropdown.as_mut().filter(|_| self.panel_visible) {
+                    let inside = dropdown.contains_point(x, y);
+                    if let Some(index) = dropdown.handle_mouse_down(x, y) {
+                        self.six_dof_mode = index == 0;
+                        self.update_camera_basis();
+                    }
+                    if inside {
+                        return true;
+                    }
+                }
+
                 // Check if click is in parameter panel
                 if self.panel_visible && x >= self.panel_x && x <= self.panel_x + self.panel_width {
                     let panel_y = ry + 10.0;
@@ -1537,6 +2226,11 @@ hotline::object!({
                     return true;
                 }
 
+                // Closing the mode picker doesn't stop the press reaching what's underneath
+                if self.click_through {
+                    return false;
+                }
+
                 // Check if we're on a resize edge
                 if let Some(edge) = self.get_resize_edge(x, y) {
                     self.resize_mode = Some(edge);
@@ -1570,11 +2264,9 @@ hotline::object!({
                 if self.dragging_param {
                     if let Some(idx) = self.selected_param {
                         let dx = x - self.drag_start_x;
-                        let sensitivity = 0.01;
-
                         if let Some((min, max)) = self.get_param_range(idx) {
                             let range = max - min;
-                            let delta = (dx * sensitivity * range as f64) as f32;
+                            let delta = (dx * self.param_drag_sensitivity * range as f64) as f32;
                             let new_value = self.drag_start_value + delta;
                             self.set_param_value(idx, new_value);
                         }
@@ -1582,6 +2274,10 @@ hotline::object!({
                     return true;
                 }
 
+                if let Some(ref mut dropdown) = self.mode_dropdown {
+                    dropdown.handle_mouse_move(x, y);
+                }
+
                 // Update hovered parameter
                 if self.panel_visible && x >= self.panel_x && x <= self.panel_x + self.panel_width {
                     let panel_y = ry + 10.0;
@@ -1664,3 +2360,69 @@ hotline::object!({
         }
     }
 });
+
+// Light gray code text on dark backgrounds and near-black on light ones, by relative luminance
+fn contrasting_text_color(background: [f32; 4]) -> (u8, u8, u8, u8) {
+    let luminance = 0.2126 * background[0] + 0.7152 * background[1] + 0.0722 * background[2];
+    if luminance > 0.4 { (30, 30, 30, 255) } else { (200, 200, 200, 255) }
+}
+
+// Lay a line's tiles end to end; logical_width sizes each quad and texture_width maps its texture coords
+fn draw_line_tiles(
+    gpu_renderer: &mut dyn ::hotline::GpuRenderingContext,
+    tiles: &[(u32, f32, f32, f32)],
+    x: f32,
+    y: f32,
+    opacity: f32,
+) {
+    let mut tile_x = x;
+    for &(tex_id, logical_width, texture_width, height) in tiles {
+        let u1 = logical_width / texture_width;
+        gpu_renderer.add_textured_rect_with_coords(
+            tile_x,
+            y,
+            logical_width,
+            height,
+            tex_id,
+            0.0,
+            0.0,
+            u1,
+            1.0,
+            [1.0, 1.0, 1.0, opacity],
+        );
+        tile_x += logical_width;
+    }
+}
+
+// Folder a poster's cluster belongs to, without the leading "./" of scanned paths
+fn tree_dir(file_path: &Path) -> PathBuf {
+    let parent = file_path.parent().unwrap_or(Path::new(""));
+    parent.components().filter(|c| !matches!(c, std::path::Component::CurDir)).collect()
+}
+
+// Lines that open a function, type or impl, by a light per-line keyword scan that covers Rust and the
+// common scripting languages without parsing anything
+fn signature_lines(content: &str) -> Vec<String> {
+    const MODIFIERS: [&str; 7] = ["pub(crate) ", "pub(super) ", "pub ", "export ", "async ", "unsafe ", "const "];
+    const KEYWORDS: [&str; 11] = [
+        "fn ",
+        "struct ",
+        "enum ",
+        "trait ",
+        "impl ",
+        "impl<",
+        "macro_rules!",
+        "class ",
+        "def ",
+        "function ",
+        "interface ",
+    ];
+    let is_signature = |line: &str| {
+        let mut rest = line.trim_start();
+        while let Some(stripped) = MODIFIERS.iter().find_map(|m| rest.strip_prefix(m)) {
+            rest = stripped;
+        }
+        KEYWORDS.iter().any(|k| rest.starts_with(k))
+    };
+    content.lines().filter(|line| is_signature(line)).map(str::to_string).collect()
+}
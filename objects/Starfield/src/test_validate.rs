@@ -0,0 +1,31 @@
+use crate::Starfield;
+use hotline::serde_json::{self, json};
+use hotline::{HotlineObject, StateFormat};
+
+fn oriented() -> Starfield {
+    let mut starfield = Starfield::default();
+    starfield.update_camera_basis();
+    starfield
+}
+
+// Restores an oriented Starfield from its saved state with one field replaced
+fn restored_with(field: &str, value: serde_json::Value) -> Starfield {
+    let saved = oriented().serialize_state_as(StateFormat::Json).unwrap();
+    let mut state: serde_json::Value = serde_json::from_slice(&saved).unwrap();
+    state[field] = value;
+    let mut restored = Starfield::default();
+    restored.deserialize_state(&serde_json::to_vec(&state).unwrap()).unwrap();
+    restored
+}
+
+#[test]
+fn test_restored_basis_is_valid() {
+    assert_eq!(HotlineObject::validate(&restored_with("camera_pos", json!([1.0, 2.0, 3.0]))), Ok(()));
+}
+
+#[test]
+fn test_restored_bad_basis_is_invalid() {
+    let stretched = restored_with("camera_forward", json!([2.0, 0.0, 0.0]));
+    let err = HotlineObject::validate(&stretched).unwrap_err();
+    assert!(err.contains("forward"), "{}", err);
+}
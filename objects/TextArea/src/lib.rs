@@ -1,8 +1,17 @@
+#[cfg(test)]
+mod test_spans;
+
+// Gap between wheel notches that still counts as one flick for scroll acceleration
+const RAPID_SCROLL_MS: u128 = 150;
+
 hotline::object!({
     #[derive(Clone, Default)]
     pub struct TextArea {
-        #[setter]
-        text: String,
+        text: String, // Edits go through text_changed so line_index stays in step
+        #[serde(skip)]
+        line_index: std::sync::OnceLock<Vec<(usize, usize)>>, // (char, byte) offset of each line start
+        #[serde(skip)]
+        visual_rows: std::sync::OnceLock<Vec<(usize, usize, bool)>>, // Reset with the text, width or wrapping
         rect: Option<Rect>,
         focused: bool,
         text_renderer: Option<TextRenderer>,
@@ -19,17 +28,63 @@ hotline::object!({
         #[default(0.0)]
         scroll_velocity: f64,
         #[setter]
+        #[default(20.0)]
+        scroll_multiplier: f64, // Velocity per wheel notch
+        #[setter]
+        #[default(0.5)]
+        scroll_acceleration: f64, // Boost gained per notch of rapid scrolling; 0 disables
+        #[setter]
+        #[default(8.0)]
+        max_scroll_boost: f64,
+        #[serde(skip)]
+        last_wheel: Option<(std::time::Instant, f64)>, // When the last notch arrived and the boost it used
+        #[serde(skip)]
+        last_wheel_horizontal: Option<(std::time::Instant, f64)>, // The same for the horizontal wheel
+        #[setter]
+        #[default(0.0)]
+        horizontal_scroll_offset: f64,
+        #[setter]
         #[default(40)]
         background_color: u8,
         #[setter]
         #[default(true)]
         show_cursor: bool,
+        #[default(false)]
+        word_wrap: bool,
+        cursor_color: Option<(u8, u8, u8, u8)>, // None follows text_color
+        #[setter]
+        #[default((60, 60, 120, 255))]
+        selection_color: (u8, u8, u8, u8), // Tints the white atlas, so it can change without re-registering
+        #[default(2.0)]
+        cursor_width: f64,
         #[setter]
         #[default(true)]
         editable: bool,
+        #[setter]
+        #[default(400)]
+        double_click_ms: u64,
+        #[serde(skip)]
+        last_click: Option<(std::time::Instant, usize)>,
+        #[setter]
+        highlights: Vec<(usize, usize)>,
+        #[setter]
+        active_highlight: Option<usize>,
+        #[setter]
+        #[default(true)]
+        highlight_selected_word: bool, // Faintly box the visible copies of a selected whole word
+        #[setter]
+        #[default((70, 70, 90, 255))]
+        word_match_color: (u8, u8, u8, u8),
+        #[serde(skip)]
+        colored_spans: Vec<::hotline::ColorSpan>, // Sorted char ranges drawn in their own color
+        #[serde(skip)]
+        unchanged_ends: Option<(usize, usize)>, // Bytes at the start and end untouched since take_unchanged_ends
         background_atlas_id: Option<u32>,
         selection_atlas_id: Option<u32>,
+        cursor_atlas_id: Option<u32>,
         shared_white_atlas_id: Option<u32>,
+        #[serde(skip)]
+        gpu_commands: Option<GPURenderer>, // Records generate_commands for render_gpu, holding the atlases above
     }
 
     impl TextArea {
@@ -43,16 +98,25 @@ hotline::object!({
                 tr.set_color(self.text_color);
                 tr.initialize();
                 self.text_renderer = Some(tr);
+                self.visual_rows = Default::default();
             }
             self.cursor = self.text.chars().count();
             self.selection = None;
         }
 
         pub fn set_rect(&mut self, rect: Rect) {
+            if self.rect.as_ref().map(|r| r.bounds().2) != Some(rect.bounds().2) {
+                self.visual_rows = Default::default();
+            }
             self.rect = Some(rect);
             self.initialize();
         }
 
+        pub fn set_word_wrap(&mut self, word_wrap: bool) {
+            self.word_wrap = word_wrap;
+            self.visual_rows = Default::default();
+        }
+
         pub fn update_text_color(&mut self, color: (u8, u8, u8, u8)) {
             self.text_color = color;
             if let Some(ref mut tr) = self.text_renderer {
@@ -60,6 +124,15 @@ hotline::object!({
             }
         }
 
+        pub fn set_cursor_color(&mut self, color: (u8, u8, u8, u8)) {
+            self.cursor_color = Some(color);
+        }
+
+        pub fn set_cursor_width(&mut self, width: f64) {
+            assert!(width > 0.0, "cursor width must be positive, got {}", width);
+            self.cursor_width = width;
+        }
+
         pub fn is_focused(&self) -> bool {
             self.focused
         }
@@ -76,9 +149,22 @@ hotline::object!({
             if let Some(ref r) = self.rect {
                 self.focused = r.clone().contains_point(x, y);
                 if self.focused && self.editable && self.is_near_text(x, y) {
-                    self.cursor = self.index_at_position(x, y);
-                    self.selection = Some((self.cursor, self.cursor));
-                    self.dragging = true;
+                    let idx = self.index_at_position(x, y);
+                    let now = std::time::Instant::now();
+                    let is_double = self.last_click.is_some_and(|(t, last_idx)| {
+                        last_idx == idx && now.duration_since(t).as_millis() <= self.double_click_ms as u128
+                    });
+
+                    if is_double {
+                        self.last_click = None;
+                        self.select_word_at(idx);
+                        self.dragging = false;
+                    } else {
+                        self.last_click = Some((now, idx));
+                        self.cursor = idx;
+                        self.selection = Some((self.cursor, self.cursor));
+                        self.dragging = true;
+                    }
                     return true;
                 } else {
                     self.dragging = false;
@@ -122,7 +208,7 @@ hotline::object!({
                 }
                 let line_text = lines[line];
                 let text_width = line_text.chars().count() as f64 * 8.0;
-                let text_x0 = rx + 10.0;
+                let text_x0 = rx + 10.0 - self.horizontal_scroll_offset;
                 let text_x1 = text_x0 + text_width;
                 x >= text_x0 - 5.0 && x <= text_x1 + 5.0
             } else {
@@ -134,12 +220,73 @@ hotline::object!({
             self.text.clone()
         }
 
+        pub fn set_text(&mut self, text: String) {
+            // Only the stretch that differs counts as edited, so swapping in a close version stays cheap downstream
+            let (old, new) = (self.text.as_bytes(), text.as_bytes());
+            let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+            let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+            let (removed, inserted) = (old.len() - prefix - suffix, new.len() - prefix - suffix);
+            self.text = text;
+            self.text_changed(prefix, removed, inserted);
+        }
+
+        pub fn byte_len(&self) -> usize {
+            self.text.len()
+        }
+
+        // The text between two byte offsets, widened to whole chars
+        pub fn text_in_bytes(&self, start: usize, end: usize) -> String {
+            let len = self.text.len();
+            let end = (end.min(len)..=len).find(|&b| self.text.is_char_boundary(b)).unwrap_or(len);
+            let start = (0..=start.min(end)).rev().find(|&b| self.text.is_char_boundary(b)).unwrap_or(0);
+            self.text[start..end].to_string()
+        }
+
+        // How many bytes at the start and end of the text no edit has touched since the last call, or None
+        // when nothing changed; lets a highlighter redo just the middle
+        pub fn take_unchanged_ends(&mut self) -> Option<(usize, usize)> {
+            self.unchanged_ends.take()
+        }
+
+        // Call after replacing `removed` bytes at byte `at` with `inserted` new ones
+        fn text_changed(&mut self, at: usize, removed: usize, inserted: usize) {
+            let len_before = self.text.len() + removed - inserted;
+            let (prefix, suffix) = self.unchanged_ends.unwrap_or((len_before, len_before));
+            self.unchanged_ends = Some((prefix.min(at), suffix.min(len_before - at - removed)));
+            self.line_index = Default::default();
+            self.visual_rows = Default::default();
+            self.colored_spans.clear(); // Spans from the old text would color the wrong chars
+        }
+
+        // Built on first use after an edit, so a burst of edits costs one scan
+        fn line_index(&self) -> &[(usize, usize)] {
+            self.line_index.get_or_init(|| {
+                let mut starts = vec![(0, 0)];
+                for (char_idx, (byte_idx, ch)) in self.text.char_indices().enumerate() {
+                    if ch == '\n' {
+                        starts.push((char_idx + 1, byte_idx + 1));
+                    }
+                }
+                starts
+            })
+        }
+
+        // Line holding char index `idx`
+        fn line_of(&self, idx: usize) -> usize {
+            self.line_index().partition_point(|&(start, _)| start <= idx) - 1
+        }
+
+        fn char_count(&self) -> usize {
+            let &(char_start, byte_start) = self.line_index().last().expect("line index always has line 0");
+            char_start + self.text[byte_start..].chars().count()
+        }
+
         pub fn get_cursor(&self) -> usize {
             self.cursor
         }
 
         pub fn set_cursor(&mut self, cursor: usize) {
-            self.cursor = cursor.min(self.text.chars().count());
+            self.cursor = cursor.min(self.char_count());
         }
 
         pub fn get_selection(&self) -> Option<(usize, usize)> {
@@ -154,14 +301,85 @@ hotline::object!({
             self.selection = None;
         }
 
+        // Char index ranges of every non-overlapping occurrence of `query`
+        pub fn find_all(&self, query: &str) -> Vec<(usize, usize)> {
+            if query.is_empty() {
+                return Vec::new();
+            }
+            let query_len = query.chars().count();
+            let mut matches = Vec::new();
+            let mut char_idx = 0usize;
+            let mut last_byte = 0usize;
+            for (byte_idx, _) in self.text.match_indices(query) {
+                char_idx += self.text[last_byte..byte_idx].chars().count();
+                matches.push((char_idx, char_idx + query_len));
+                char_idx += query_len;
+                last_byte = byte_idx + query.len();
+            }
+            matches
+        }
+
         fn char_to_byte(&self, idx: usize) -> usize {
-            self.text.char_indices().nth(idx).map(|(b, _)| b).unwrap_or_else(|| self.text.len())
+            let (char_start, byte_start) = self.line_index()[self.line_of(idx)];
+            self.text[byte_start..]
+                .char_indices()
+                .nth(idx - char_start)
+                .map_or(self.text.len(), |(b, _)| byte_start + b)
+        }
+
+        // A byte inside a multi-byte char maps to that char
+        fn byte_to_char(&self, byte: usize) -> usize {
+            let mut byte = byte.min(self.text.len());
+            while !self.text.is_char_boundary(byte) {
+                byte -= 1;
+            }
+            let index = self.line_index();
+            let (char_start, byte_start) = index[index.partition_point(|&(_, b)| b <= byte) - 1];
+            char_start + self.text[byte_start..byte].chars().count()
+        }
+
+        // Byte ranges of the current text to draw in their own color, as from a syntax highlighter.
+        // Ranges should be sorted and not overlap; the rest of the text keeps text_color.
+        pub fn set_colored_spans(&mut self, spans: Vec<::hotline::ColorSpan>) {
+            let mut colored = Vec::with_capacity(spans.len());
+            let mut last_end = 0;
+            for (start, end, color) in spans {
+                let (start, end) = (self.byte_to_char(start).max(last_end), self.byte_to_char(end));
+                if start < end {
+                    colored.push((start, end, color));
+                    last_end = end;
+                }
+            }
+            self.colored_spans = colored;
+        }
+
+        // A row split into runs of one color each, filling the gaps between spans with text_color
+        fn color_runs(&self, row_start: usize, row_end: usize) -> Vec<::hotline::ColorSpan> {
+            let mut runs = Vec::new();
+            let mut pos = row_start;
+            let first = self.colored_spans.partition_point(|&(_, end, _)| end <= row_start);
+            for &(start, end, color) in &self.colored_spans[first..] {
+                if start >= row_end {
+                    break;
+                }
+                if start > pos {
+                    runs.push((pos, start, self.text_color));
+                }
+                let (start, end) = (start.max(pos), end.min(row_end));
+                runs.push((start, end, color));
+                pos = end;
+            }
+            if pos < row_end {
+                runs.push((pos, row_end, self.text_color));
+            }
+            runs
         }
 
         fn delete_range(&mut self, start: usize, end: usize) {
             let b_start = self.char_to_byte(start);
             let b_end = self.char_to_byte(end);
             self.text.replace_range(b_start..b_end, "");
+            self.text_changed(b_start, b_end - b_start, 0);
         }
 
         fn line_height(&self) -> f64 {
@@ -176,37 +394,60 @@ hotline::object!({
             self.shared_white_atlas_id = Some(atlas_id);
         }
 
-        fn index_to_line_col(&self, idx: usize) -> (usize, usize) {
-            let mut line = 0usize;
-            let mut col = 0usize;
-            for ch in self.text.chars().take(idx) {
-                if ch == '\n' {
-                    line += 1;
-                    col = 0;
-                } else {
-                    col += 1;
-                }
-            }
-            (line, col)
-        }
-
-        fn cursor_line_col(&self) -> (usize, usize) {
-            self.index_to_line_col(self.cursor)
+        // Visual rows as (start, end) char ranges, flagged when the row ends at a soft wrap.
+        // Without word_wrap every logical line is one row. Measured once per text, width or wrap change.
+        fn visual_rows(&self) -> &[(usize, usize, bool)] {
+            self.visual_rows.get_or_init(|| self.measure_visual_rows())
         }
 
-        fn line_start_index(&self, line: usize) -> usize {
-            let mut idx = 0usize;
-            for (i, l) in self.text.split('\n').enumerate() {
-                if i == line {
-                    break;
+        fn measure_visual_rows(&self) -> Vec<(usize, usize, bool)> {
+            let wrap = match (self.rect.as_ref(), self.text_renderer.as_ref()) {
+                (Some(r), Some(tr)) if self.word_wrap => Some((r.bounds().2 - 20.0 - 8.0, tr)), // margins + scroll bar
+                _ => None,
+            };
+            let mut rows = Vec::new();
+            let mut line_start = 0usize;
+            for line in self.text.split('\n') {
+                let chars: Vec<char> = line.chars().collect();
+                let mut start = 0usize;
+                if let Some((max_width, tr)) = wrap {
+                    let fits = |start: usize, end: usize| {
+                        tr.measure_text(&chars[start..end].iter().collect::<String>()) <= max_width
+                    };
+                    while !fits(start, chars.len()) {
+                        // Longest prefix that fits, always taking at least one char
+                        let (mut lo, mut hi) = (start + 1, chars.len());
+                        while lo < hi {
+                            let mid = (lo + hi).div_ceil(2);
+                            if fits(start, mid) { lo = mid } else { hi = mid - 1 }
+                        }
+                        // Break after the last space in it, or mid-word if there is none
+                        let end = chars[start..lo]
+                            .iter()
+                            .rposition(|c| c.is_whitespace())
+                            .map(|p| start + p + 1)
+                            .unwrap_or(lo);
+                        rows.push((line_start + start, line_start + end, true));
+                        start = end;
+                    }
                 }
-                idx += l.chars().count() + 1;
+                rows.push((line_start + start, line_start + chars.len(), false));
+                line_start += chars.len() + 1;
             }
-            idx
+            rows
         }
 
-        fn line_length(&self, line: usize) -> usize {
-            self.text.split('\n').nth(line).map(|l| l.chars().count()).unwrap_or(0)
+        // Row holding char index `idx`; an index on a soft wrap belongs to the following row
+        fn row_of(rows: &[(usize, usize, bool)], idx: usize) -> usize {
+            rows.iter().rposition(|&(start, _, _)| start <= idx).unwrap_or(0)
+        }
+
+        // `col` chars into `row`, clamped to its end; a soft-wrapped row stops short of the wrap,
+        // which belongs to the row after
+        fn index_in_row(rows: &[(usize, usize, bool)], row: usize, col: usize) -> usize {
+            let (start, end, soft) = rows[row];
+            let last = if soft && end > start { end - 1 } else { end };
+            (start + col).min(last)
         }
 
         fn index_at_position(&self, x: f64, y: f64) -> usize {
@@ -216,13 +457,11 @@ hotline::object!({
                 let cy = y.clamp(ry, ry + rh);
                 let local_y = cy - (ry + 10.0) + self.scroll_offset;
                 let line_height = self.line_height();
-                let mut line = (local_y / line_height).floor() as usize;
-                let lines: Vec<&str> = self.text.split('\n').collect();
-                if line >= lines.len() {
-                    line = lines.len().saturating_sub(1);
-                }
-                let local_x = cx - (rx + 10.0);
-                let line_text = lines.get(line).copied().unwrap_or("");
+                let rows = self.visual_rows();
+                let row = ((local_y / line_height).floor().max(0.0) as usize).min(rows.len() - 1);
+                let (row_start, row_end, _) = rows[row];
+                let local_x = cx - (rx + 10.0) + self.horizontal_scroll_offset;
+                let line_text = &self.text[self.char_to_byte(row_start)..self.char_to_byte(row_end)];
 
                 // Find character position by measuring text width
                 let col = if let Some(ref tr) = self.text_renderer {
@@ -259,7 +498,7 @@ hotline::object!({
                     ((local_x / 8.0).round() as usize).min(line_text.chars().count())
                 };
 
-                self.line_start_index(line) + col
+                row_start + col
             } else {
                 self.cursor
             }
@@ -273,6 +512,7 @@ hotline::object!({
                 }
                 let b = self.char_to_byte(self.cursor);
                 self.text.insert(b, ch);
+                self.text_changed(b, 0, ch.len_utf8());
                 self.cursor += 1;
             }
         }
@@ -285,14 +525,98 @@ hotline::object!({
                 }
                 let b = self.char_to_byte(self.cursor);
                 self.text.insert_str(b, text);
+                self.text_changed(b, 0, text.len());
                 self.cursor += text.chars().count();
             }
         }
 
+        // The selected text, newlines included; None when nothing is selected
+        pub fn copy_selection(&self) -> Option<String> {
+            let (s, e) = self.selection?;
+            let (start, end) = (s.min(e), s.max(e));
+            (start < end).then(|| self.text[self.char_to_byte(start)..self.char_to_byte(end)].to_string())
+        }
+
+        // Removes the selected text and returns it, like copy_selection followed by a delete
+        pub fn cut_selection(&mut self) -> Option<String> {
+            if !self.focused || !self.editable {
+                return None;
+            }
+            let text = self.copy_selection()?;
+            let (s, e) = self.selection.take()?;
+            self.delete_range(s.min(e), s.max(e));
+            self.cursor = s.min(e);
+            Some(text)
+        }
+
         pub fn insert_newline(&mut self) {
             self.insert_char('\n');
         }
 
+        // Copies the selection after itself and selects the copy, or the cursor's line below itself
+        pub fn duplicate_line(&mut self) {
+            if !self.focused || !self.editable {
+                return;
+            }
+            let chars: Vec<char> = self.text.chars().collect();
+            if let Some((s, e)) = self.selection.take() {
+                let (start, end) = (s.min(e), s.max(e));
+                let copy: String = chars[start..end].iter().collect();
+                self.cursor = end;
+                self.insert_text(&copy);
+                self.selection = Some((end, self.cursor));
+            } else {
+                let line_start = chars[..self.cursor].iter().rposition(|&c| c == '\n').map_or(0, |i| i + 1);
+                let line_end =
+                    chars[self.cursor..].iter().position(|&c| c == '\n').map_or(chars.len(), |i| self.cursor + i);
+                let column = self.cursor - line_start;
+                let copy: String = std::iter::once('\n').chain(chars[line_start..line_end].iter().copied()).collect();
+                self.cursor = line_end;
+                self.insert_text(&copy);
+                self.cursor = line_end + 1 + column;
+            }
+        }
+
+        // Swaps the lines under the cursor or selection with the line above or below, keeping the selection
+        pub fn move_lines(&mut self, up: bool) {
+            if !self.focused || !self.editable {
+                return;
+            }
+            let chars: Vec<char> = self.text.chars().collect();
+            let line_start = |i: usize| chars[..i].iter().rposition(|&c| c == '\n').map_or(0, |n| n + 1);
+            let line_end = |i: usize| chars[i..].iter().position(|&c| c == '\n').map_or(chars.len(), |n| i + n);
+            let (start, end) = self.selection.map_or((self.cursor, self.cursor), |(s, e)| (s.min(e), s.max(e)));
+            // A selection ending at the start of a line doesn't take that line along
+            let last = if end > start && end > 0 && chars[end - 1] == '\n' { end - 1 } else { end };
+            let (block_start, block_end) = (line_start(start), line_end(last));
+            let block: String = chars[block_start..block_end].iter().collect();
+
+            let (region, replacement, shift) = if up {
+                if block_start == 0 {
+                    return;
+                }
+                let prev_start = line_start(block_start - 1);
+                let prev: String = chars[prev_start..block_start - 1].iter().collect();
+                let shift = -((block_start - prev_start) as isize);
+                (prev_start..block_end, format!("{}\n{}", block, prev), shift)
+            } else {
+                if block_end == chars.len() {
+                    return;
+                }
+                let next_end = line_end(block_end + 1);
+                let next: String = chars[block_end + 1..next_end].iter().collect();
+                let shift = (next_end - block_end) as isize;
+                (block_start..next_end, format!("{}\n{}", next, block), shift)
+            };
+
+            let (b_start, b_end) = (self.char_to_byte(region.start), self.char_to_byte(region.end));
+            self.text.replace_range(b_start..b_end, &replacement);
+            self.text_changed(b_start, b_end - b_start, replacement.len());
+            let moved = |i: usize| (i as isize + shift) as usize;
+            self.cursor = moved(self.cursor);
+            self.selection = self.selection.map(|(s, e)| (moved(s), moved(e)));
+        }
+
         pub fn backspace(&mut self) {
             if self.focused && self.editable {
                 if let Some((s, e)) = self.selection.take() {
@@ -302,6 +626,7 @@ hotline::object!({
                     let b_start = self.char_to_byte(self.cursor - 1);
                     let b_end = self.char_to_byte(self.cursor);
                     self.text.replace_range(b_start..b_end, "");
+                    self.text_changed(b_start, b_end - b_start, 0);
                     self.cursor -= 1;
                 }
             }
@@ -319,7 +644,7 @@ hotline::object!({
         }
 
         pub fn move_cursor_right(&mut self, shift: bool) {
-            if self.cursor < self.text.chars().count() {
+            if self.cursor < self.char_count() {
                 self.cursor += 1;
             }
             if shift {
@@ -329,19 +654,13 @@ hotline::object!({
             }
         }
 
+        // Up and down move between visual rows, so a wrapped paragraph is walked a row at a time
         pub fn move_cursor_up(&mut self, shift: bool) {
-            let (line, col) = self.cursor_line_col();
-            if line == 0 {
-                if shift {
-                    self.update_selection();
-                } else {
-                    self.selection = None;
-                }
-                return;
+            let rows = self.visual_rows();
+            let row = Self::row_of(rows, self.cursor);
+            if row > 0 {
+                self.cursor = Self::index_in_row(rows, row - 1, self.cursor - rows[row].0);
             }
-            let new_line = line - 1;
-            let new_col = col.min(self.line_length(new_line));
-            self.cursor = self.line_start_index(new_line) + new_col;
             if shift {
                 self.update_selection();
             } else {
@@ -350,19 +669,11 @@ hotline::object!({
         }
 
         pub fn move_cursor_down(&mut self, shift: bool) {
-            let (line, col) = self.cursor_line_col();
-            let total_lines = self.text.lines().count();
-            if line + 1 >= total_lines {
-                if shift {
-                    self.update_selection();
-                } else {
-                    self.selection = None;
-                }
-                return;
+            let rows = self.visual_rows();
+            let row = Self::row_of(rows, self.cursor);
+            if row + 1 < rows.len() {
+                self.cursor = Self::index_in_row(rows, row + 1, self.cursor - rows[row].0);
             }
-            let new_line = line + 1;
-            let new_col = col.min(self.line_length(new_line));
-            self.cursor = self.line_start_index(new_line) + new_col;
             if shift {
                 self.update_selection();
             } else {
@@ -370,6 +681,21 @@ hotline::object!({
             }
         }
 
+        fn select_word_at(&mut self, idx: usize) {
+            let chars: Vec<char> = self.text.chars().collect();
+            let is_word = |c: char| c.is_alphanumeric() || c == '_';
+            let mut start = idx.min(chars.len());
+            let mut end = start;
+            while start > 0 && is_word(chars[start - 1]) {
+                start -= 1;
+            }
+            while end < chars.len() && is_word(chars[end]) {
+                end += 1;
+            }
+            self.selection = Some((start, end));
+            self.cursor = end;
+        }
+
         fn update_selection(&mut self) {
             match self.selection {
                 Some((start, _)) => self.selection = Some((start, self.cursor)),
@@ -380,16 +706,85 @@ hotline::object!({
         pub fn scroll_by(&mut self, delta: f64) {
             if let Some(ref rect) = self.rect {
                 let line_height = 14.0;
-                let total_height = self.text.lines().count() as f64 * line_height;
+                let total_height = self.visual_rows().len() as f64 * line_height;
                 let max_offset = (total_height - rect.clone().bounds().3).max(0.0);
                 self.scroll_offset = (self.scroll_offset + delta).max(0.0).min(max_offset);
             }
         }
 
+        // Horizontal scroll, up to the point where the widest row's end meets the right margin
+        pub fn scroll_horizontal_by(&mut self, delta: f64) {
+            if let Some(ref rect) = self.rect {
+                let chars: Vec<char> = self.text.chars().collect();
+                let widest = self
+                    .visual_rows()
+                    .iter()
+                    .map(|&(start, end, _)| match self.text_renderer {
+                        Some(ref tr) => tr.measure_text(&chars[start..end].iter().collect::<String>()),
+                        None => (end - start) as f64 * 8.0,
+                    })
+                    .fold(0.0, f64::max);
+                let max_offset = (widest - (rect.clone().bounds().2 - 20.0 - 8.0)).max(0.0); // margins + scroll bar
+                self.horizontal_scroll_offset = (self.horizontal_scroll_offset + delta).clamp(0.0, max_offset);
+            }
+        }
+
+        pub fn scroll_offset(&self) -> f64 {
+            self.scroll_offset
+        }
+
+        // Adds text above the current content, keeping the view, cursor and selection where they were
+        pub fn prepend_text(&mut self, text: &str) {
+            let rows_before = self.visual_rows().len();
+            let shift = text.chars().count();
+            self.text.insert_str(0, text);
+            self.text_changed(0, 0, text.len());
+            self.cursor += shift;
+            self.selection = self.selection.map(|(start, end)| (start + shift, end + shift));
+            for (start, end) in self.highlights.iter_mut() {
+                *start += shift;
+                *end += shift;
+            }
+            self.scroll_offset += (self.visual_rows().len() - rows_before) as f64 * self.line_height();
+        }
+
         pub fn add_scroll_velocity(&mut self, delta: f64) {
             self.scroll_velocity += delta;
         }
 
+        // Wheel notches in the same direction within RAPID_SCROLL_MS build up a boost; reversing stops the coast
+        pub fn scroll_wheel(&mut self, delta: f64) {
+            let velocity = -delta * self.scroll_multiplier;
+            if velocity * self.scroll_velocity < 0.0 {
+                self.scroll_velocity = 0.0;
+            }
+            let now = std::time::Instant::now();
+            let boost = match self.last_wheel {
+                Some((t, boost))
+                    if self.scroll_velocity != 0.0 && now.duration_since(t).as_millis() <= RAPID_SCROLL_MS =>
+                {
+                    (boost + self.scroll_acceleration * delta.abs()).min(self.max_scroll_boost.max(1.0))
+                }
+                _ => 1.0,
+            };
+            self.last_wheel = Some((now, boost));
+            self.add_scroll_velocity(velocity * boost);
+        }
+
+        // Horizontal wheel, positive to the right: the same multiplier and rapid-notch boost as
+        // scroll_wheel, applied at once rather than as momentum
+        pub fn scroll_wheel_horizontal(&mut self, delta: f64) {
+            let now = std::time::Instant::now();
+            let boost = match self.last_wheel_horizontal {
+                Some((t, boost)) if now.duration_since(t).as_millis() <= RAPID_SCROLL_MS => {
+                    (boost + self.scroll_acceleration * delta.abs()).min(self.max_scroll_boost.max(1.0))
+                }
+                _ => 1.0,
+            };
+            self.last_wheel_horizontal = Some((now, boost));
+            self.scroll_horizontal_by(delta * self.scroll_multiplier * boost);
+        }
+
         pub fn update_scroll(&mut self) {
             if self.scroll_velocity.abs() > 0.1 {
                 self.scroll_by(self.scroll_velocity);
@@ -419,6 +814,12 @@ hotline::object!({
                 self.selection_atlas_id = Some(id);
             }
 
+            // Register white cursor atlas, tinted by the cursor color
+            if self.cursor_atlas_id.is_none() {
+                let id = gpu_renderer.register_atlas(vec![255, 255, 255, 255], 1, 1, AtlasFormat::RGBA);
+                self.cursor_atlas_id = Some(id);
+            }
+
             // Register text renderer atlas
             // TODO: TextRenderer no longer has register_atlas method
             // if let Some(ref mut tr) = self.text_renderer {
@@ -426,6 +827,83 @@ hotline::object!({
             // }
         }
 
+        fn add_range_commands(
+            &self,
+            gpu_renderer: &mut GPURenderer,
+            texture_id: u32,
+            ranges: &[(usize, usize)],
+            color: (u8, u8, u8, u8),
+        ) {
+            let (x, y, _w, h) = match self.rect.as_ref() {
+                Some(r) => r.clone().bounds(),
+                None => return,
+            };
+            let line_height = self.line_height();
+            let chars: Vec<char> = self.text.chars().collect();
+            let width_to = |row_start: usize, idx: usize| match self.text_renderer {
+                Some(ref tr) if idx > row_start => tr.measure_text(&chars[row_start..idx].iter().collect::<String>()),
+                Some(_) => 0.0,
+                None => (idx - row_start) as f64 * 8.0,
+            };
+
+            let rows = self.visual_rows();
+            for &(start, end) in ranges {
+                let (start, end) = if start <= end { (start, end) } else { (end, start) };
+                for (row_idx, &(row_start, row_end, _)) in rows.iter().enumerate() {
+                    if row_end >= start && row_start <= end {
+                        let line_y = y + 10.0 + row_idx as f64 * line_height - self.scroll_offset;
+
+                        if line_y + line_height >= y && line_y <= y + h {
+                            let x0 =
+                                x + 10.0 - self.horizontal_scroll_offset + width_to(row_start, start.max(row_start));
+                            let x1 = x + 10.0 - self.horizontal_scroll_offset + width_to(row_start, end.min(row_end));
+
+                            gpu_renderer.add_command(RenderCommand::Rect {
+                                texture_id,
+                                dest_x: x0,
+                                dest_y: line_y,
+                                dest_width: x1 - x0,
+                                dest_height: line_height,
+                                rotation: 0.0,
+                                color,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        // Other whole-word copies of the selection within the rows on screen; empty unless the
+        // selection is exactly one word
+        fn selected_word_matches(&self, rows: &[(usize, usize, bool)]) -> Vec<(usize, usize)> {
+            let Some((s, e)) = self.selection.filter(|_| self.highlight_selected_word) else {
+                return Vec::new();
+            };
+            let (start, end) = (s.min(e), s.max(e));
+            let chars: Vec<char> = self.text.chars().collect();
+            let is_word = |c: char| c.is_alphanumeric() || c == '_';
+            let bounded = |start: usize, end: usize| {
+                (start == 0 || !is_word(chars[start - 1])) && (end == chars.len() || !is_word(chars[end]))
+            };
+            if start == end || !chars[start..end].iter().all(|&c| is_word(c)) || !bounded(start, end) {
+                return Vec::new();
+            }
+
+            let line_height = self.line_height();
+            let view_height = self.rect.as_ref().map_or(0.0, |r| r.clone().bounds().3);
+            let first_row = ((self.scroll_offset - 10.0) / line_height).floor().max(0.0) as usize;
+            let last_row = (((self.scroll_offset + view_height) / line_height).ceil() as usize).min(rows.len() - 1);
+            if first_row > last_row {
+                return Vec::new();
+            }
+            let word = &chars[start..end];
+            let (view_start, view_end) = (rows[first_row].0, rows[last_row].1);
+            (view_start..view_end.saturating_sub(word.len() - 1))
+                .filter(|&i| i != start && chars[i..i + word.len()] == *word && bounded(i, i + word.len()))
+                .map(|i| (i, i + word.len()))
+                .collect()
+        }
+
         pub fn generate_commands(&mut self, gpu_renderer: &mut GPURenderer) {
             let (x, y, w, h) = match self.rect.as_ref() {
                 Some(r) => r.clone().bounds(),
@@ -433,6 +911,8 @@ hotline::object!({
             };
 
             let scroll_bar_width = 8.0;
+            // Long lines and rows scrolled partly out of view stop at the edges
+            gpu_renderer.push_clip_rect(x, y, w - scroll_bar_width, h);
 
             // Render background using shared white atlas if available
             let bg_atlas = self.shared_white_atlas_id.or(self.background_atlas_id);
@@ -456,74 +936,135 @@ hotline::object!({
                 });
             }
 
-            // Render selection boxes
-            if let Some((start, end)) = self.selection {
-                if let Some(sel_id) = self.selection_atlas_id {
-                    let line_height = self.line_height();
-                    let text = self.text.clone();
-                    let (start, end) = if start <= end { (start, end) } else { (end, start) };
-                    let mut char_index = 0usize;
-                    let mut line_idx = 0usize;
-
-                    for line in text.split('\n') {
-                        let len = line.chars().count();
-                        let line_start = char_index;
-                        let line_end = char_index + len;
-
-                        if line_end >= start && line_start <= end {
-                            let s_col = if start > line_start { start - line_start } else { 0 };
-                            let e_col = if end < line_end { end - line_start } else { len };
-                            let line_y = y + 10.0 + line_idx as f64 * line_height - self.scroll_offset;
-
-                            if line_y + line_height >= y && line_y <= y + h {
-                                let x0 = if let Some(ref tr) = self.text_renderer {
-                                    let chars: Vec<char> = line.chars().collect();
-                                    let text_to_start: String = chars[..s_col].iter().collect();
-                                    x + 10.0 + if s_col == 0 { 0.0 } else { tr.measure_text(&text_to_start) }
-                                } else {
-                                    x + 10.0 + s_col as f64 * 8.0
-                                };
-
-                                let x1 = if let Some(ref tr) = self.text_renderer {
-                                    let chars: Vec<char> = line.chars().collect();
-                                    let text_to_end: String = chars[..e_col].iter().collect();
-                                    x + 10.0 + if e_col == 0 { 0.0 } else { tr.measure_text(&text_to_end) }
-                                } else {
-                                    x + 10.0 + e_col as f64 * 8.0
-                                };
-
-                                gpu_renderer.add_command(RenderCommand::Rect {
-                                    texture_id: sel_id,
-                                    dest_x: x0,
-                                    dest_y: line_y,
-                                    dest_width: x1 - x0,
-                                    dest_height: line_height,
-                                    rotation: 0.0,
-                                    color: (255, 255, 255, 255),
-                                });
-                            }
-                        }
-                        char_index += len + 1;
-                        line_idx += 1;
+            // Render copies of the selected word and search highlights, then the selection on top
+            if let Some(white_atlas) = self.shared_white_atlas_id.or(self.cursor_atlas_id) {
+                let matches = self.selected_word_matches(self.visual_rows());
+                self.add_range_commands(gpu_renderer, white_atlas, &matches, self.word_match_color);
+            }
+
+            if let Some(sel_id) = self.selection_atlas_id {
+                let (active, others): (Vec<_>, Vec<_>) =
+                    self.highlights.iter().enumerate().partition(|&(i, _)| self.active_highlight == Some(i));
+                let ranges =
+                    |list: Vec<(usize, &(usize, usize))>| list.into_iter().map(|(_, &r)| r).collect::<Vec<_>>();
+                self.add_range_commands(gpu_renderer, sel_id, &ranges(others), (0, 120, 160, 255));
+                self.add_range_commands(gpu_renderer, sel_id, &ranges(active), (0, 200, 255, 255));
+            }
+
+            if let (Some(selection), Some(white_atlas)) =
+                (self.selection, self.shared_white_atlas_id.or(self.cursor_atlas_id))
+            {
+                self.add_range_commands(gpu_renderer, white_atlas, &[selection], self.selection_color);
+            }
+
+            // Text runs are drawn by render_gpu, since TextRenderer only draws through the GPU context
+            let line_height = self.line_height();
+            let rows = self.visual_rows();
+            let chars: Vec<char> = self.text.chars().collect();
+
+            let white_atlas = self.shared_white_atlas_id.or(self.cursor_atlas_id);
+
+            // Mark soft wraps with a small return arrow past the end of the row
+            if let (Some(mark_atlas), Some(tr)) = (white_atlas, self.text_renderer.as_ref()) {
+                let mark_color = (140, 160, 160, 160);
+                for (row_idx, &(row_start, row_end, soft)) in rows.iter().enumerate() {
+                    let row_y = y + 10.0 + row_idx as f64 * line_height - self.scroll_offset;
+                    if !soft || row_y + line_height < y || row_y > y + h {
+                        continue;
                     }
+                    let mx = x + 10.0 - self.horizontal_scroll_offset
+                        + tr.measure_text(&chars[row_start..row_end].iter().collect::<String>())
+                        + 3.0;
+                    let my = row_y + line_height * 0.3;
+                    let rect = |dest_x: f64, dest_y: f64, dest_width: f64, dest_height: f64| RenderCommand::Rect {
+                        texture_id: mark_atlas,
+                        dest_x,
+                        dest_y,
+                        dest_width,
+                        dest_height,
+                        rotation: 0.0,
+                        color: mark_color,
+                    };
+                    // Down stroke, then the return leg and its arrowhead
+                    gpu_renderer.add_command(rect(mx + 5.0, my, 1.0, 5.0));
+                    gpu_renderer.add_command(rect(mx, my + 5.0, 6.0, 1.0));
+                    gpu_renderer.add_command(rect(mx + 1.0, my + 4.0, 1.0, 3.0));
                 }
             }
 
-            // Generate text commands
+            // Caret at the cursor position
+            if let Some(cursor_atlas) = white_atlas {
+                if self.show_cursor && self.focused {
+                    let row = Self::row_of(rows, self.cursor);
+                    let row_start = rows[row].0;
+                    let caret_y = y + 10.0 + row as f64 * line_height - self.scroll_offset;
+                    if caret_y + line_height >= y && caret_y <= y + h {
+                        let prefix: String = chars[row_start..self.cursor.min(chars.len())].iter().collect();
+                        let caret_x = match self.text_renderer {
+                            Some(ref tr) => x + 10.0 - self.horizontal_scroll_offset + tr.measure_text(&prefix),
+                            None => x + 10.0 - self.horizontal_scroll_offset + (self.cursor - row_start) as f64 * 8.0,
+                        };
+                        gpu_renderer.add_command(RenderCommand::Rect {
+                            texture_id: cursor_atlas,
+                            dest_x: caret_x,
+                            dest_y: caret_y,
+                            dest_width: self.cursor_width,
+                            dest_height: line_height,
+                            rotation: 0.0,
+                            color: self.cursor_color.unwrap_or(self.text_color),
+                        });
+                    }
+                }
+            }
+            gpu_renderer.pop_clip_rect();
+        }
+
+        // Replays generate_commands through the GPU context, then draws the text runs under the same clip
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            let (x, y, w, h) = match self.rect.as_ref() {
+                Some(r) => r.clone().bounds(),
+                None => return,
+            };
+
+            let mut commands = match self.gpu_commands.take() {
+                Some(commands) => commands,
+                None => {
+                    // Ids registered with an earlier recorder, say before a reload, mean nothing to a new one
+                    self.background_atlas_id = None;
+                    self.selection_atlas_id = None;
+                    self.cursor_atlas_id = None;
+                    let mut commands = GPURenderer::new();
+                    self.register_atlases(&mut commands);
+                    commands
+                }
+            };
+            commands.clear_commands();
+            self.generate_commands(&mut commands);
+            commands.render_gpu(gpu_renderer);
+            self.gpu_commands = Some(commands);
+
+            let scroll_bar_width = 8.0;
+            gpu_renderer.push_clip_rect(x as f32, y as f32, (w - scroll_bar_width) as f32, h as f32);
             let mut cursor_y = y + 10.0 - self.scroll_offset;
             let line_height = self.line_height();
-            if let Some(ref mut tr) = self.text_renderer {
-                for line in self.text.split('\n') {
-                    if cursor_y + line_height >= y && cursor_y <= y + h {
-                        tr.set_text(line.to_string());
-                        tr.set_x(x + 10.0);
-                        tr.set_y(cursor_y);
-                        // TODO: TextRenderer no longer has generate_commands, uses render_gpu instead
-                        // tr.generate_commands(gpu_renderer);
+            let chars: Vec<char> = self.text.chars().collect();
+            for (row_start, row_end, _) in self.visual_rows().to_vec() {
+                if cursor_y + line_height >= y && cursor_y <= y + h {
+                    let runs = self.color_runs(row_start, row_end);
+                    if let Some(ref mut tr) = self.text_renderer {
+                        for (run_start, run_end, color) in runs {
+                            let prefix: String = chars[row_start..run_start].iter().collect();
+                            tr.set_text(chars[run_start..run_end].iter().collect());
+                            tr.set_color(color);
+                            tr.set_x(x + 10.0 - self.horizontal_scroll_offset + tr.measure_text(&prefix));
+                            tr.set_y(cursor_y);
+                            tr.render_gpu(gpu_renderer);
+                        }
                     }
-                    cursor_y += line_height;
                 }
+                cursor_y += line_height;
             }
+            gpu_renderer.pop_clip_rect();
         }
     }
 });
@@ -0,0 +1,13 @@
+use crate::TextArea;
+
+#[test]
+fn test_colored_spans_inside_multibyte_chars() {
+    let mut ta = TextArea::default();
+    ta.set_text("héllo wörld".to_string());
+    let color = (255, 0, 0, 255);
+
+    // Bytes 2 and 9 fall inside é and ö, and count as those chars
+    ta.set_colored_spans(vec![(2, 9, color), (9, ta.byte_len() + 4, color)]);
+    assert_eq!(ta.colored_spans, vec![(1, 7, color), (7, 11, color)]);
+    assert_eq!(ta.text_in_bytes(2, 9), "éllo wö");
+}
@@ -1,8 +1,53 @@
 hotline::object!({
-    use std::sync::OnceLock;
+    use std::sync::{Mutex, OnceLock};
 
     static FONT: OnceLock<Font> = OnceLock::new();
 
+    // LRU keyed by string; `recency` maps last-use tick -> key so eviction pops the oldest
+    #[derive(Default)]
+    struct MeasureCache {
+        widths: std::collections::HashMap<String, (f64, u64)>,
+        recency: std::collections::BTreeMap<u64, String>,
+        tick: u64,
+        font_size: Option<u32>,
+    }
+
+    // Width cache shared by all renderers since they share FONT
+    const MEASURE_CACHE_CAPACITY: usize = 4096;
+    static MEASURE_CACHE: OnceLock<Mutex<MeasureCache>> = OnceLock::new();
+
+    impl MeasureCache {
+        fn get(&mut self, text: &str, font_size: u32) -> Option<f64> {
+            if self.font_size != Some(font_size) {
+                self.clear();
+                self.font_size = Some(font_size);
+                return None;
+            }
+            self.tick += 1;
+            let tick = self.tick;
+            let (width, last_used) = self.widths.get_mut(text)?;
+            self.recency.remove(last_used);
+            *last_used = tick;
+            self.recency.insert(tick, text.to_string());
+            Some(*width)
+        }
+
+        fn insert(&mut self, text: &str, width: f64) {
+            self.tick += 1;
+            if self.widths.len() >= MEASURE_CACHE_CAPACITY {
+                let (_, oldest) = self.recency.pop_first().expect("recency tracks every cached width");
+                self.widths.remove(&oldest);
+            }
+            self.widths.insert(text.to_string(), (width, self.tick));
+            self.recency.insert(self.tick, text.to_string());
+        }
+
+        fn clear(&mut self) {
+            self.widths.clear();
+            self.recency.clear();
+        }
+    }
+
     #[derive(Clone, Default)]
     pub struct TextRenderer {
         #[setter]
@@ -15,7 +60,13 @@ hotline::object!({
         y: f64,
         #[setter]
         #[default((255, 255, 255, 255))]
-        color: (u8, u8, u8, u8), // ABGR
+        color: (u8, u8, u8, u8), // (r, g, b, a), the same order CpuTextRenderer and the GPU use
+        #[setter]
+        #[default(false)]
+        snap_to_pixel: bool, // Rounds each glyph to whole pixels so fractional positions don't blur
+        #[setter]
+        #[default(0.0)]
+        tracking: f64, // Extra pixels after every character, counted by measure_text too so carets line up
         initialized: bool,
     }
 
@@ -75,12 +126,11 @@ hotline::object!({
             let cursor_y = self.y;
             let mut prev_char: Option<char> = None;
 
-            // Convert color from ABGR u8 to RGBA f32
             let color = [
-                self.color.2 as f32 / 255.0, // R
-                self.color.1 as f32 / 255.0, // G
-                self.color.0 as f32 / 255.0, // B
-                self.color.3 as f32 / 255.0, // A
+                self.color.0 as f32 / 255.0,
+                self.color.1 as f32 / 255.0,
+                self.color.2 as f32 / 255.0,
+                self.color.3 as f32 / 255.0,
             ];
 
             // Hardcoded font atlas dimensions - these match owlet font atlas
@@ -94,15 +144,19 @@ hotline::object!({
                 }
 
                 if ch == ' ' {
-                    cursor_x += font.space_width() as f64;
+                    cursor_x += font.space_width() as f64 + self.tracking;
                     prev_char = Some(ch);
                     continue;
                 }
 
                 if let Some((glyph_x, glyph_y, glyph_width, glyph_height, offset_x, offset_y, advance)) = font.glyph(ch)
                 {
-                    let dest_x = cursor_x + offset_x as f64;
-                    let dest_y = cursor_y + offset_y as f64 + font.size() as f64;
+                    let mut dest_x = cursor_x + offset_x as f64;
+                    let mut dest_y = cursor_y + offset_y as f64 + font.size() as f64;
+                    if self.snap_to_pixel {
+                        dest_x = dest_x.round();
+                        dest_y = dest_y.round();
+                    }
 
                     // Calculate texture coordinates for the glyph
                     let u0 = glyph_x as f32 / ATLAS_WIDTH;
@@ -126,6 +180,7 @@ hotline::object!({
                     cursor_x += advance as f64;
                     prev_char = Some(ch);
                 }
+                cursor_x += self.tracking;
             }
         }
 
@@ -135,13 +190,14 @@ hotline::object!({
                 None => return 0.0,
             };
 
-            if ch == ' ' {
+            let width = if ch == ' ' {
                 font.space_width() as f64
             } else if let Some((_x, _y, _w, _h, _off_x, _off_y, adv)) = font.glyph(ch) {
                 adv as f64
             } else {
                 font.space_width() as f64
-            }
+            };
+            width + self.tracking
         }
 
         pub fn measure_text(&self, text: &str) -> f64 {
@@ -150,6 +206,13 @@ hotline::object!({
                 None => return 0.0,
             };
 
+            // The cache is shared, so it holds untracked widths and tracking is added on the way out
+            let tracking = self.tracking * text.chars().count() as f64;
+            let mut cache = MEASURE_CACHE.get_or_init(Default::default).lock().unwrap();
+            if let Some(width) = cache.get(text, font.size()) {
+                return width + tracking;
+            }
+
             let mut width = 0.0;
             let mut prev_char: Option<char> = None;
 
@@ -170,7 +233,13 @@ hotline::object!({
                 prev_char = Some(ch);
             }
 
-            width
+            cache.insert(text, width);
+            width + tracking
+        }
+
+        // Drop cached widths, e.g. after swapping font metrics
+        pub fn clear_measure_cache(&self) {
+            MEASURE_CACHE.get_or_init(Default::default).lock().unwrap().clear();
         }
 
         pub fn line_height(&self) -> f64 {
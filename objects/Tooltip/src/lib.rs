@@ -0,0 +1,94 @@
+hotline::object!({
+    #[derive(Default)]
+    pub struct Tooltip {
+        text: String,
+        text_renderer: Option<TextRenderer>,
+        #[setter]
+        #[default(500)]
+        delay_ms: u64, // Hover time before the tooltip shows
+        #[setter]
+        #[default(5000)]
+        dismiss_ms: u64, // Time shown before it hides on its own; 0 keeps it until the pointer leaves
+        #[setter]
+        #[default(4.0)]
+        padding: f64,
+        #[setter]
+        #[default((12.0, 16.0))]
+        offset: (f64, f64), // From the pointer, so the cursor doesn't cover the text
+        // None follows the registry's theme
+        text_color: Option<(u8, u8, u8, u8)>,
+        background_color: Option<[f32; 4]>,
+        border_color: Option<[f32; 4]>,
+        x: f64,
+        y: f64,
+        #[serde(skip)]
+        hover_start: Option<std::time::Instant>,
+    }
+
+    impl Tooltip {
+        pub fn set_text_color(&mut self, color: (u8, u8, u8, u8)) {
+            self.text_color = Some(color);
+        }
+
+        pub fn set_background_color(&mut self, color: [f32; 4]) {
+            self.background_color = Some(color);
+        }
+
+        pub fn set_border_color(&mut self, color: [f32; 4]) {
+            self.border_color = Some(color);
+        }
+
+        // Call on every pointer move over a target; the tooltip follows the pointer until it shows
+        pub fn hover(&mut self, text: &str, x: f64, y: f64) {
+            if self.hover_start.is_none() || self.text != text {
+                self.text = text.to_string();
+                self.hover_start = Some(std::time::Instant::now());
+            }
+            if !self.is_visible() {
+                self.x = x + self.offset.0;
+                self.y = y + self.offset.1;
+            }
+        }
+
+        // Call when the pointer leaves every target
+        pub fn clear(&mut self) {
+            self.hover_start = None;
+        }
+
+        // Hovering but still inside the delay, so the owner keeps frames coming until it shows
+        pub fn is_pending(&self) -> bool {
+            self.hover_start.is_some_and(|start| start.elapsed().as_millis() < self.delay_ms as u128)
+        }
+
+        pub fn is_visible(&self) -> bool {
+            self.hover_start.is_some_and(|start| {
+                let elapsed = start.elapsed().as_millis();
+                let delay = self.delay_ms as u128;
+                elapsed >= delay && (self.dismiss_ms == 0 || elapsed < delay + self.dismiss_ms as u128)
+            })
+        }
+
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if !self.is_visible() {
+                return;
+            }
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+            let theme = self.registry().theme();
+            let tr = self.text_renderer.get_or_insert_with(TextRenderer::new);
+            tr.set_text(self.text.clone());
+            tr.set_color(self.text_color.unwrap_or(theme.text));
+            tr.set_x(self.x + self.padding);
+            tr.set_y(self.y + self.padding);
+
+            let w = (tr.measure_text(&self.text) + 2.0 * self.padding) as f32;
+            let h = (tr.line_height() + 2.0 * self.padding) as f32;
+            let (x, y) = (self.x as f32, self.y as f32);
+            gpu_renderer.add_solid_rect(x, y, w, h, self.border_color.unwrap_or(theme.border));
+            let background = self.background_color.unwrap_or(theme.panel);
+            gpu_renderer.add_solid_rect(x + 1.0, y + 1.0, w - 2.0, h - 2.0, background);
+            tr.render_gpu(gpu_renderer);
+        }
+    }
+});
@@ -3,6 +3,24 @@
 pub enum SelectedObject {
     Rect(usize),
     Polygon(usize),
+    Image(usize),
+}
+
+// A shape held by an undo entry while it's out of the scene
+#[derive(Clone)]
+enum SceneShape {
+    Rect(Rect),
+    Polygon(RegularPolygon),
+    Image(Image),
+}
+
+// Reversible scene edits recorded by WindowManager for undo/redo
+#[derive(Clone)]
+enum SceneCommand {
+    Create(SceneShape, usize),
+    Delete(SceneShape, usize),
+    // Serialized object state before and after a move, resize or rotate
+    Modify { target: SelectedObject, before: Vec<u8>, after: Vec<u8> },
 }
 
 hotline::object!({
@@ -33,8 +51,6 @@ hotline::object!({
         polygon_menu: Option<PolygonMenu>,
         click_inspector: Option<ClickInspector>,
         show_render_times: bool,
-        rect_time_labels: Vec<TextRenderer>,
-        polygon_time_labels: Vec<TextRenderer>,
         dragging: bool,
         drag_offset_x: f64,
         drag_offset_y: f64,
@@ -42,6 +58,31 @@ hotline::object!({
         resize_dir: ResizeDir,
         resize_start: Option<(f64, f64)>,
         resize_orig: Option<(f64, f64, f64, f64)>,
+        #[serde(skip)]
+        undo_stack: Vec<SceneCommand>,
+        #[serde(skip)]
+        redo_stack: Vec<SceneCommand>,
+        // Snapshot of the object being dragged or resized, taken when the gesture started
+        #[serde(skip)]
+        pending_edit: Option<(SelectedObject, Vec<u8>)>,
+        // Field last edited through the inspector, so a scrub across many values undoes as one step
+        #[serde(skip)]
+        last_field_edit: Option<(SelectedObject, String)>,
+        // Bounds of the last moved, resized or deleted shape of each type; new ones of that type start there
+        last_placement: std::collections::HashMap<String, (f64, f64, f64, f64)>,
+        #[setter]
+        #[default(true)]
+        animate_focus: bool, // focus_on glides the object into view rather than jumping
+        // Object focus_on is gliding, its destination position, and its state before the glide
+        #[serde(skip)]
+        focus_glide: Option<(SelectedObject, (f64, f64), Vec<u8>)>,
+        #[setter]
+        snap_grid: f64, // Grid spacing that created, dragged and resized shapes align to; 0 disables
+        #[setter]
+        #[default(1000)]
+        max_shapes: usize, // Soft limit that warns when the scene grows past it, to catch runaway creation; 0 disables
+        #[serde(skip)]
+        shape_limit_warned: bool, // Warn once per crossing rather than on every shape past the limit
     }
 
     impl WindowManager {
@@ -56,7 +97,7 @@ hotline::object!({
                     .with_text("Hello, Hotline!".to_string())
                     .with_x(20.0)
                     .with_y(20.0)
-                    .with_color((255, 0, 255, 255)); // Yellow text in ABGR format
+                    .with_color((255, 255, 0, 255)); // Yellow
                 self.text_renderer = Some(text_renderer);
 
                 // Create click inspector
@@ -72,23 +113,338 @@ hotline::object!({
             mover.set_target(rect.clone());
             self.rect_movers.push(mover);
             self.rects.push(rect);
+            self.check_shape_limit();
         }
 
+        // Images arrive from user drops, so unlike add_rect this is undoable
         pub fn add_image(&mut self, image: Image) {
-            self.images.push(image);
+            self.create_shape(SceneShape::Image(image));
         }
 
-        pub fn inspect_click(&mut self, x: f64, y: f64) -> Vec<String> {
-            let mut hits = Vec::new();
-            for rect in &mut self.rects {
-                if rect.contains_point(x, y) {
-                    hits.extend(rect.info_lines());
+        fn create_shape(&mut self, mut shape: SceneShape) {
+            match &mut shape {
+                SceneShape::Rect(r) => {
+                    if let Some(&(x, y, w, h)) = self.last_placement.get("Rect") {
+                        r.resize(x, y, w, h);
+                    }
+                }
+                SceneShape::Polygon(p) => {
+                    if let Some(&(x, y, w, h)) = self.last_placement.get("RegularPolygon") {
+                        p.resize(x, y, w, h);
+                    }
                 }
+                SceneShape::Image(_) => {}
+            }
+            let (x, y) = match &shape {
+                SceneShape::Rect(r) => r.position(),
+                SceneShape::Polygon(p) => p.position(),
+                SceneShape::Image(img) => img.position(),
+            };
+            let (dx, dy) = (self.snap(x) - x, self.snap(y) - y);
+            match &mut shape {
+                SceneShape::Rect(r) => r.move_by(dx, dy),
+                SceneShape::Polygon(p) => p.move_by(dx, dy),
+                SceneShape::Image(img) => img.move_by(dx, dy),
             }
-            for poly in &mut self.polygons {
-                if poly.contains_point(x, y) {
-                    hits.extend(poly.info_lines());
+            let index = match &shape {
+                SceneShape::Rect(_) => self.rects.len(),
+                SceneShape::Polygon(_) => self.polygons.len(),
+                SceneShape::Image(_) => self.images.len(),
+            };
+            self.insert_shape(index, &shape);
+            self.record(SceneCommand::Create(shape, index));
+        }
+
+        fn snap(&self, v: f64) -> f64 {
+            if self.snap_grid > 0.0 { (v / self.snap_grid).round() * self.snap_grid } else { v }
+        }
+
+        fn insert_shape(&mut self, index: usize, shape: &SceneShape) {
+            match shape {
+                SceneShape::Rect(r) => {
+                    let mut mover = RectMover::new();
+                    mover.set_target(r.clone());
+                    self.rect_movers.insert(index, mover);
+                    self.rects.insert(index, r.clone());
                 }
+                SceneShape::Polygon(p) => self.polygons.insert(index, p.clone()),
+                SceneShape::Image(img) => self.images.insert(index, img.clone()),
+            }
+            self.check_shape_limit();
+        }
+
+        fn check_shape_limit(&mut self) {
+            let count = self.rects.len() + self.polygons.len() + self.images.len();
+            let over = self.max_shapes > 0 && count > self.max_shapes;
+            if over && !self.shape_limit_warned {
+                eprintln!(
+                    "WARNING: scene has {} shapes, over the soft limit of {}; is something creating them in a loop?",
+                    count, self.max_shapes
+                );
+            }
+            self.shape_limit_warned = over;
+        }
+
+        fn remove_shape(&mut self, shape: &SceneShape, index: usize) {
+            match shape {
+                SceneShape::Rect(_) => {
+                    self.rect_movers.remove(index);
+                    self.rects.remove(index);
+                }
+                SceneShape::Polygon(_) => {
+                    self.polygons.remove(index);
+                }
+                SceneShape::Image(_) => {
+                    self.images.remove(index);
+                }
+            }
+        }
+
+        fn snapshot(&self, sel: SelectedObject) -> Vec<u8> {
+            let state = match sel {
+                SelectedObject::Rect(i) => ::hotline::HotlineObject::serialize_state(&self.rects[i]),
+                SelectedObject::Polygon(i) => ::hotline::HotlineObject::serialize_state(&self.polygons[i]),
+                SelectedObject::Image(i) => ::hotline::HotlineObject::serialize_state(&self.images[i]),
+            };
+            state.unwrap_or_else(|e| panic!("Failed to snapshot shape for undo: {}", e))
+        }
+
+        fn restore(&mut self, sel: SelectedObject, state: &[u8]) {
+            let result = match sel {
+                SelectedObject::Rect(i) => ::hotline::HotlineObject::deserialize_state(&mut self.rects[i], state),
+                SelectedObject::Polygon(i) => ::hotline::HotlineObject::deserialize_state(&mut self.polygons[i], state),
+                SelectedObject::Image(i) => ::hotline::HotlineObject::deserialize_state(&mut self.images[i], state),
+            };
+            result.unwrap_or_else(|e| panic!("Failed to restore shape for undo: {}", e));
+        }
+
+        fn record(&mut self, command: SceneCommand) {
+            self.undo_stack.push(command);
+            self.redo_stack.clear();
+            self.last_field_edit = None;
+        }
+
+        // Record a move/resize/rotate if the object actually changed
+        fn record_modify(&mut self, target: SelectedObject, before: Vec<u8>) {
+            let after = self.snapshot(target);
+            if after != before {
+                self.record(SceneCommand::Modify { target, before, after });
+                self.remember_placement(target);
+            }
+        }
+
+        // Images keep their drop location, so only menu-created types are remembered
+        fn remember_placement(&mut self, sel: SelectedObject) {
+            let (type_name, placement) = match sel {
+                SelectedObject::Rect(i) => ("Rect", self.rects[i].bounds()),
+                SelectedObject::Polygon(i) => {
+                    // The circumscribed square, which resize() maps back to the same center and radius
+                    let (cx, cy) = self.polygons[i].center();
+                    let r = self.polygons[i].radius() * self.polygons[i].scale();
+                    ("RegularPolygon", (cx - r, cy - r, 2.0 * r, 2.0 * r))
+                }
+                SelectedObject::Image(_) => return,
+            };
+            self.last_placement.insert(type_name.to_string(), placement);
+        }
+
+        fn apply(&mut self, command: &SceneCommand, forward: bool) {
+            self.last_field_edit = None;
+            match (command, forward) {
+                (SceneCommand::Create(shape, index), true) | (SceneCommand::Delete(shape, index), false) => {
+                    self.insert_shape(*index, shape);
+                    self.clear_selection();
+                }
+                (SceneCommand::Create(shape, index), false) | (SceneCommand::Delete(shape, index), true) => {
+                    self.remove_shape(shape, *index);
+                    self.clear_selection();
+                }
+                (SceneCommand::Modify { target, before, after }, forward) => {
+                    self.restore(*target, if forward { after } else { before });
+                    if self.selected == Some(*target) && !self.is_shape_visible(*target) {
+                        self.clear_selection();
+                    }
+                    self.update_highlight();
+                    self.update_inspector();
+                }
+            }
+        }
+
+        pub fn undo(&mut self) -> bool {
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+            self.finish_focus();
+            let Some(command) = self.undo_stack.pop() else {
+                return false;
+            };
+            self.apply(&command, false);
+            self.redo_stack.push(command);
+            true
+        }
+
+        pub fn redo(&mut self) -> bool {
+            if let Some(registry) = self.get_registry() {
+                ::hotline::set_library_registry(registry);
+            }
+            self.finish_focus();
+            let Some(command) = self.redo_stack.pop() else {
+                return false;
+            };
+            self.apply(&command, true);
+            self.undo_stack.push(command);
+            true
+        }
+
+        pub fn delete_selected(&mut self) {
+            self.finish_focus();
+            let Some(sel) = self.selected else {
+                return;
+            };
+            let (shape, index) = match sel {
+                SelectedObject::Rect(i) => (SceneShape::Rect(self.rects[i].clone()), i),
+                SelectedObject::Polygon(i) => (SceneShape::Polygon(self.polygons[i].clone()), i),
+                SelectedObject::Image(i) => (SceneShape::Image(self.images[i].clone()), i),
+            };
+            self.remember_placement(sel);
+            self.remove_shape(&shape, index);
+            self.record(SceneCommand::Delete(shape, index));
+            self.clear_selection();
+        }
+
+        fn is_shape_visible(&self, sel: SelectedObject) -> bool {
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].is_visible(),
+                SelectedObject::Polygon(i) => self.polygons[i].is_visible(),
+                SelectedObject::Image(i) => self.images[i].is_visible(),
+            }
+        }
+
+        // Undoable like any other edit; hiding the selection also deselects it
+        pub fn set_shape_visible(&mut self, sel: SelectedObject, visible: bool) {
+            self.finish_focus();
+            let before = self.snapshot(sel);
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].set_visible(visible),
+                SelectedObject::Polygon(i) => self.polygons[i].set_visible(visible),
+                SelectedObject::Image(i) => self.images[i].set_visible(visible),
+            }
+            self.record_modify(sel, before);
+            if !visible && self.selected == Some(sel) {
+                self.clear_selection();
+            }
+        }
+
+        pub fn hide_selected(&mut self) {
+            if let Some(sel) = self.selected {
+                self.set_shape_visible(sel, false);
+            }
+        }
+
+        fn hidden_shapes(&self) -> Vec<SelectedObject> {
+            let rects = (0..self.rects.len()).map(SelectedObject::Rect);
+            let polygons = (0..self.polygons.len()).map(SelectedObject::Polygon);
+            let images = (0..self.images.len()).map(SelectedObject::Image);
+            rects.chain(polygons).chain(images).filter(|&sel| !self.is_shape_visible(sel)).collect()
+        }
+
+        pub fn show_hidden(&mut self) {
+            for sel in self.hidden_shapes() {
+                self.set_shape_visible(sel, true);
+            }
+        }
+
+        fn z_index(&self, sel: SelectedObject) -> i64 {
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].z_index(),
+                SelectedObject::Polygon(i) => self.polygons[i].z_index(),
+                SelectedObject::Image(i) => self.images[i].z_index(),
+            }
+        }
+
+        // Every shape bottom to top: ascending z-index, then rects, polygons and images, each in creation order
+        fn draw_order(&self) -> Vec<SelectedObject> {
+            let rects = (0..self.rects.len()).map(SelectedObject::Rect);
+            let polygons = (0..self.polygons.len()).map(SelectedObject::Polygon);
+            let images = (0..self.images.len()).map(SelectedObject::Image);
+            let mut order: Vec<SelectedObject> = rects.chain(polygons).chain(images).collect();
+            order.sort_by_key(|&sel| self.z_index(sel)); // Stable, so ties keep the order above
+            order
+        }
+
+        // Every hit-testable shape, topmost first
+        fn hit_targets(&self) -> Vec<(SelectedObject, &dyn ::hotline::Bounded)> {
+            self.draw_order().into_iter().rev().map(|sel| (sel, self.shape(sel))).collect()
+        }
+
+        // Undoable like a move
+        pub fn set_shape_z_index(&mut self, sel: SelectedObject, z_index: i64) {
+            self.finish_focus();
+            let before = self.snapshot(sel);
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].set_z_index(z_index),
+                SelectedObject::Polygon(i) => self.polygons[i].set_z_index(z_index),
+                SelectedObject::Image(i) => self.images[i].set_z_index(z_index),
+            }
+            self.record_modify(sel, before);
+        }
+
+        // Selection above every other shape, leaving the rest of the stack as it was
+        pub fn bring_to_front(&mut self) {
+            self.restack_selected(true);
+        }
+
+        // Selection below every other shape
+        pub fn send_to_back(&mut self) {
+            self.restack_selected(false);
+        }
+
+        fn restack_selected(&mut self, front: bool) {
+            let Some(sel) = self.selected else {
+                return;
+            };
+            let others = self.draw_order().into_iter().filter(|&other| other != sel).map(|other| self.z_index(other));
+            let z_index = if front { others.max().map_or(0, |z| z + 1) } else { others.min().map_or(0, |z| z - 1) };
+            self.set_shape_z_index(sel, z_index);
+        }
+
+        // Rects in draw order, for hosts that draw them themselves
+        pub fn rects_by_z_index(&self) -> Vec<Rect> {
+            let mut rects = self.rects.clone();
+            rects.sort_by_key(|rect| rect.z_index());
+            rects
+        }
+
+        fn shape(&self, sel: SelectedObject) -> &dyn ::hotline::Bounded {
+            match sel {
+                SelectedObject::Rect(i) => &self.rects[i],
+                SelectedObject::Polygon(i) => &self.polygons[i],
+                SelectedObject::Image(i) => &self.images[i],
+            }
+        }
+
+        fn selected_position(&self, sel: SelectedObject) -> (f64, f64) {
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].position(),
+                SelectedObject::Polygon(i) => self.polygons[i].position(),
+                SelectedObject::Image(i) => self.images[i].position(),
+            }
+        }
+
+        pub fn inspect_click(&mut self, x: f64, y: f64) -> Vec<String> {
+            let hit: Vec<SelectedObject> = self
+                .hit_targets()
+                .into_iter()
+                .filter(|(_, shape)| shape.contains_point(x, y))
+                .map(|(sel, _)| sel)
+                .collect();
+            let mut hits = Vec::new();
+            for sel in hit {
+                hits.extend(match sel {
+                    SelectedObject::Rect(i) => self.rects[i].info_lines(),
+                    SelectedObject::Polygon(i) => self.polygons[i].info_lines(),
+                    SelectedObject::Image(i) => self.images[i].info_lines(),
+                });
             }
             hits
         }
@@ -106,6 +462,7 @@ hotline::object!({
         }
 
         pub fn clear_selection(&mut self) {
+            self.finish_focus();
             self.selected = None;
             self.highlight_lens = None;
             self.dragging = false;
@@ -113,6 +470,7 @@ hotline::object!({
             self.resize_dir = ResizeDir::None;
             self.resize_start = None;
             self.resize_orig = None;
+            self.pending_edit = None;
         }
 
         pub fn set_drag_offset(&mut self, x: f64, y: f64) {
@@ -130,11 +488,10 @@ hotline::object!({
         }
 
         fn update_highlight(&mut self) {
-            if let (Some(sel), Some(ref mut hl)) = (self.selected, self.highlight_lens.as_mut()) {
-                let bounds = match sel {
-                    SelectedObject::Rect(i) => self.rects[i].bounds(),
-                    SelectedObject::Polygon(i) => self.polygons[i].bounds(),
-                };
+            let Some(bounds) = self.selected.map(|sel| self.shape(sel).bounds()) else {
+                return;
+            };
+            if let Some(ref mut hl) = self.highlight_lens {
                 let mut r = Rect::new();
                 r.initialize(bounds.0, bounds.1, bounds.2, bounds.3);
                 hl.set_target(&r);
@@ -147,6 +504,7 @@ hotline::object!({
                     let items = match sel {
                         SelectedObject::Rect(i) => self.rects[i].info_lines(),
                         SelectedObject::Polygon(i) => self.polygons[i].info_lines(),
+                        SelectedObject::Image(i) => self.images[i].info_lines(),
                     };
                     inspector.update_items(items);
                 }
@@ -181,6 +539,7 @@ hotline::object!({
             match self.selected {
                 Some(SelectedObject::Rect(i)) => Some(self.rects[i].info_lines()),
                 Some(SelectedObject::Polygon(i)) => Some(self.polygons[i].info_lines()),
+                Some(SelectedObject::Image(i)) => Some(self.images[i].info_lines()),
                 None => None,
             }
         }
@@ -196,7 +555,7 @@ hotline::object!({
                     if let Some(sides) = pm.handle_mouse_down(x, y) {
                         let mut p = RegularPolygon::new();
                         p.initialize(x, y, 40.0, sides);
-                        self.polygons.push(p);
+                        self.create_shape(SceneShape::Polygon(p));
                     }
                     self.context_menu = None;
                     return;
@@ -205,19 +564,7 @@ hotline::object!({
 
             if let Some(ref mut menu) = self.context_menu {
                 if let Some(selection) = menu.handle_mouse_down(x, y) {
-                    match selection.as_str() {
-                        "Rect" => {
-                            let mut r = Rect::new();
-                            r.initialize(x, y, 100.0, 100.0);
-                            self.add_rect(r);
-                        }
-                        "RegularPolygon" => {
-                            if let Some(ref mut pm) = self.polygon_menu {
-                                pm.open(x + 100.0, y);
-                            }
-                        }
-                        _ => {}
-                    }
+                    self.apply_menu_choice(&selection, x, y);
                 }
                 let hide_menu = if let Some(pm) = self.polygon_menu.as_mut() { !pm.is_visible() } else { true };
                 if hide_menu {
@@ -227,19 +574,19 @@ hotline::object!({
             }
 
             // First check for hits
-            let mut hit: Option<(SelectedObject, (f64, f64), (f64, f64, f64, f64))> = None;
+            let mut hit: Option<(SelectedObject, (f64, f64, f64, f64))> = None;
             let mut resize_dir = ResizeDir::None;
 
-            for (i, rect_handle) in self.rects.iter_mut().enumerate().rev() {
-                // Check if this rect contains the point
-                let (rx, ry, rw, rh) = rect_handle.bounds();
+            for (sel, shape) in self.hit_targets() {
+                let (rx, ry, rw, rh) = shape.bounds();
                 let margin = 5.0;
-                let inside = rect_handle.contains_point(x, y);
+                let inside = shape.contains_point(x, y);
                 let near_left = (x - rx).abs() <= margin && y >= ry - margin && y <= ry + rh + margin;
                 let near_right = (x - (rx + rw)).abs() <= margin && y >= ry - margin && y <= ry + rh + margin;
                 let near_top = (y - ry).abs() <= margin && x >= rx - margin && x <= rx + rw + margin;
                 let near_bottom = (y - (ry + rh)).abs() <= margin && x >= rx - margin && x <= rx + rw + margin;
 
+                resize_dir = ResizeDir::None;
                 if near_left && near_top {
                     resize_dir = ResizeDir::TopLeft;
                 } else if near_right && near_top {
@@ -258,52 +605,23 @@ hotline::object!({
                     resize_dir = ResizeDir::Bottom;
                 }
 
-                if resize_dir != ResizeDir::None || inside {
-                    hit = Some((SelectedObject::Rect(i), rect_handle.position(), rect_handle.bounds()));
-                    break;
-                }
-            }
-
-            if hit.is_none() {
-                for (i, poly) in self.polygons.iter_mut().enumerate().rev() {
-                    let (rx, ry, rw, rh) = poly.bounds();
-                    let margin = 5.0;
-                    let inside = poly.contains_point(x, y);
-                    let near_left = (x - rx).abs() <= margin && y >= ry - margin && y <= ry + rh + margin;
-                    let near_right = (x - (rx + rw)).abs() <= margin && y >= ry - margin && y <= ry + rh + margin;
-                    let near_top = (y - ry).abs() <= margin && x >= rx - margin && x <= rx + rw + margin;
-                    let near_bottom = (y - (ry + rh)).abs() <= margin && x >= rx - margin && x <= rx + rw + margin;
-
+                // Images have no way to scale, so they only move
+                if matches!(sel, SelectedObject::Image(_)) {
                     resize_dir = ResizeDir::None;
-                    if near_left && near_top {
-                        resize_dir = ResizeDir::TopLeft;
-                    } else if near_right && near_top {
-                        resize_dir = ResizeDir::TopRight;
-                    } else if near_left && near_bottom {
-                        resize_dir = ResizeDir::BottomLeft;
-                    } else if near_right && near_bottom {
-                        resize_dir = ResizeDir::BottomRight;
-                    } else if near_left {
-                        resize_dir = ResizeDir::Left;
-                    } else if near_right {
-                        resize_dir = ResizeDir::Right;
-                    } else if near_top {
-                        resize_dir = ResizeDir::Top;
-                    } else if near_bottom {
-                        resize_dir = ResizeDir::Bottom;
-                    }
+                }
 
-                    if resize_dir != ResizeDir::None || inside {
-                        hit = Some((SelectedObject::Polygon(i), poly.position(), poly.bounds()));
-                        break;
-                    }
+                if resize_dir != ResizeDir::None || inside {
+                    hit = Some((sel, (rx, ry, rw, rh)));
+                    break;
                 }
             }
 
             // Clear previous selection
             self.clear_selection();
 
-            if let Some((sel, pos, bounds)) = hit {
+            if let Some((sel, bounds)) = hit {
+                let pos = self.selected_position(sel);
+                self.pending_edit = Some((sel, self.snapshot(sel)));
                 let mut rect_clone = Rect::new();
                 rect_clone.initialize(bounds.0, bounds.1, bounds.2, bounds.3);
                 self.selected = Some(sel);
@@ -346,6 +664,9 @@ hotline::object!({
             } else if self.dragging {
                 self.stop_dragging();
             }
+            if let Some((target, before)) = self.pending_edit.take() {
+                self.record_modify(target, before);
+            }
         }
 
         pub fn handle_mouse_motion(&mut self, x: f64, y: f64) {
@@ -366,24 +687,10 @@ hotline::object!({
             }
             if self.dragging {
                 if let Some(sel) = self.selected {
-                    let new_x = x + self.drag_offset_x;
-                    let new_y = y + self.drag_offset_y;
-                    match sel {
-                        SelectedObject::Rect(i) => {
-                            let (cx, cy) = self.rects[i].position();
-                            let dx = new_x - cx;
-                            let dy = new_y - cy;
-                            self.rects[i].move_by(dx, dy);
-                        }
-                        SelectedObject::Polygon(i) => {
-                            let (cx, cy) = self.polygons[i].position();
-                            let dx = new_x - cx;
-                            let dy = new_y - cy;
-                            self.polygons[i].move_by(dx, dy);
-                        }
-                    }
-                    self.update_highlight();
-                    self.update_inspector();
+                    let new_x = self.snap(x + self.drag_offset_x);
+                    let new_y = self.snap(y + self.drag_offset_y);
+                    let (cx, cy) = self.selected_position(sel);
+                    self.move_shape(sel, new_x - cx, new_y - cy);
                 }
             } else if self.resizing {
                 if let (Some(sel), Some((start_x, start_y)), Some((orig_x, orig_y, orig_w, orig_h))) =
@@ -434,6 +741,25 @@ hotline::object!({
                         ResizeDir::None => {}
                     }
 
+                    // Dragged edges land on the snap grid; the opposite edges stay where they were
+                    let dir = &self.resize_dir;
+                    if matches!(dir, ResizeDir::Left | ResizeDir::TopLeft | ResizeDir::BottomLeft) {
+                        let right = new_x + new_w;
+                        new_x = self.snap(new_x);
+                        new_w = right - new_x;
+                    }
+                    if matches!(dir, ResizeDir::Right | ResizeDir::TopRight | ResizeDir::BottomRight) {
+                        new_w = self.snap(new_x + new_w) - new_x;
+                    }
+                    if matches!(dir, ResizeDir::Top | ResizeDir::TopLeft | ResizeDir::TopRight) {
+                        let bottom = new_y + new_h;
+                        new_y = self.snap(new_y);
+                        new_h = bottom - new_y;
+                    }
+                    if matches!(dir, ResizeDir::Bottom | ResizeDir::BottomLeft | ResizeDir::BottomRight) {
+                        new_h = self.snap(new_y + new_h) - new_y;
+                    }
+
                     if new_w < 1.0 {
                         new_w = 1.0;
                     }
@@ -448,6 +774,7 @@ hotline::object!({
                         SelectedObject::Polygon(i) => {
                             self.polygons[i].resize(new_x, new_y, new_w, new_h);
                         }
+                        SelectedObject::Image(_) => {}
                     }
                     self.update_highlight();
                     self.update_inspector();
@@ -457,6 +784,7 @@ hotline::object!({
 
         pub fn rotate_selected(&mut self, angle: f64) {
             if let Some(sel) = self.selected {
+                let before = self.snapshot(sel);
                 match sel {
                     SelectedObject::Rect(i) => {
                         let new_rot = self.rects[i].rotation() + angle;
@@ -466,21 +794,271 @@ hotline::object!({
                         let new_rot = self.polygons[i].rotation() + angle;
                         self.polygons[i].set_rotation(new_rot);
                     }
+                    // Images are always axis-aligned
+                    SelectedObject::Image(_) => {}
                 }
+                self.record_modify(sel, before);
                 self.update_highlight();
                 self.update_inspector();
             }
         }
 
+        // Multiply the selected object's scale by `factor`, keeping it between 0.1x and 10x
+        pub fn scale_selected(&mut self, factor: f64) {
+            if let Some(sel) = self.selected {
+                let before = self.snapshot(sel);
+                match sel {
+                    SelectedObject::Rect(i) => {
+                        let scale = (self.rects[i].scale() * factor).clamp(0.1, 10.0);
+                        self.rects[i].set_scale(scale);
+                    }
+                    SelectedObject::Polygon(i) => {
+                        let scale = (self.polygons[i].scale() * factor).clamp(0.1, 10.0);
+                        self.polygons[i].set_scale(scale);
+                    }
+                    SelectedObject::Image(i) => {
+                        let scale = (self.images[i].scale() * factor).clamp(0.1, 10.0);
+                        self.images[i].set_scale(scale);
+                    }
+                }
+                self.record_modify(sel, before);
+                self.update_highlight();
+                self.update_inspector();
+            }
+        }
+
+        pub fn selected_fields(&self) -> Option<Vec<::hotline::FieldDescriptor>> {
+            self.selected.map(|sel| match sel {
+                SelectedObject::Rect(i) => self.rects[i].fields(),
+                SelectedObject::Polygon(i) => self.polygons[i].fields(),
+                SelectedObject::Image(i) => self.images[i].fields(),
+            })
+        }
+
+        pub fn selected_type_name(&self) -> Option<String> {
+            self.selected.map(|sel| {
+                match sel {
+                    SelectedObject::Rect(_) => "Rect",
+                    SelectedObject::Polygon(_) => "RegularPolygon",
+                    SelectedObject::Image(_) => "Image",
+                }
+                .to_string()
+            })
+        }
+
+        // Apply a field edit from the inspector to the selected object
+        pub fn set_selected_field(&mut self, name: String, value: ::hotline::FieldValue) -> Result<(), String> {
+            let sel = self.selected.ok_or("no object selected")?;
+            let before = self.snapshot(sel);
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].set_field(&name, value)?,
+                SelectedObject::Polygon(i) => self.polygons[i].set_field(&name, value)?,
+                SelectedObject::Image(i) => self.images[i].set_field(&name, value)?,
+            }
+            let after = self.snapshot(sel);
+            let continues_scrub = self.last_field_edit.as_ref().is_some_and(|(t, n)| *t == sel && *n == name);
+            match self.undo_stack.last_mut().filter(|_| continues_scrub) {
+                Some(SceneCommand::Modify { after: last, .. }) => *last = after,
+                _ if after != before => self.record(SceneCommand::Modify { target: sel, before, after }),
+                _ => {}
+            }
+            self.last_field_edit = Some((sel, name));
+            self.remember_placement(sel);
+            self.update_highlight();
+            self.update_inspector();
+            Ok(())
+        }
+
+        // Step the selection through rects, then polygons, then images, wrapping at either end.
+        // Returns false when the scene is empty.
+        pub fn cycle_selection(&mut self, reverse: bool) -> bool {
+            let order: Vec<SelectedObject> = (0..self.rects.len())
+                .map(SelectedObject::Rect)
+                .chain((0..self.polygons.len()).map(SelectedObject::Polygon))
+                .chain((0..self.images.len()).map(SelectedObject::Image))
+                .collect();
+            if order.is_empty() {
+                return false;
+            }
+            let next = match self.selected.and_then(|sel| order.iter().position(|&s| s == sel)) {
+                Some(i) if reverse => (i + order.len() - 1) % order.len(),
+                Some(i) => (i + 1) % order.len(),
+                None if reverse => order.len() - 1,
+                None => 0,
+            };
+            self.clear_selection();
+            let (x, y, w, h) = self.shape(order[next]).bounds();
+            let mut lens_rect = Rect::new();
+            lens_rect.initialize(x, y, w, h);
+            self.selected = Some(order[next]);
+            self.highlight_lens = Some(HighlightLens::new().with_target(&lens_rect).with_show_handles(true));
+            self.update_inspector();
+            true
+        }
+
+        // Select an object and, if it isn't entirely within the view, bring it to the view's center.
+        // There's no canvas pan yet, so the object itself moves; it's one undoable step.
+        pub fn focus_on(&mut self, selection: SelectedObject, view_width: f64, view_height: f64) {
+            self.clear_selection();
+            let (x, y, w, h) = self.shape(selection).bounds();
+            let mut lens_rect = Rect::new();
+            lens_rect.initialize(x, y, w, h);
+            self.selected = Some(selection);
+            self.highlight_lens = Some(HighlightLens::new().with_target(&lens_rect).with_show_handles(true));
+            if x >= 0.0 && y >= 0.0 && x + w <= view_width && y + h <= view_height {
+                return;
+            }
+            let (cx, cy) = self.shape(selection).center();
+            let (px, py) = self.selected_position(selection);
+            let target = (px + view_width / 2.0 - cx, py + view_height / 2.0 - cy);
+            self.focus_glide = Some((selection, target, self.snapshot(selection)));
+            if !self.animate_focus {
+                self.finish_focus();
+            }
+        }
+
+        pub fn focus_selected(&mut self, view_width: f64, view_height: f64) -> bool {
+            let Some(sel) = self.selected else {
+                return false;
+            };
+            self.focus_on(sel, view_width, view_height);
+            true
+        }
+
+        pub fn is_focusing(&self) -> bool {
+            self.focus_glide.is_some()
+        }
+
+        // Advance a focus_on glide; call every frame with the frame delta in seconds
+        pub fn update_focus(&mut self, dt: f64) {
+            let Some((sel, (tx, ty), _)) = self.focus_glide else {
+                return;
+            };
+            let (px, py) = self.selected_position(sel);
+            if (tx - px).hypot(ty - py) < 0.5 {
+                self.finish_focus();
+                return;
+            }
+            let k = 1.0 - (-dt / 0.08).exp(); // Ease out with an 80ms time constant
+            self.move_shape(sel, (tx - px) * k, (ty - py) * k);
+        }
+
+        // Land any glide in progress at its destination, so edits never see a half-moved object
+        fn finish_focus(&mut self) {
+            let Some((sel, (tx, ty), before)) = self.focus_glide.take() else {
+                return;
+            };
+            let (px, py) = self.selected_position(sel);
+            self.move_shape(sel, tx - px, ty - py);
+            self.record_modify(sel, before);
+        }
+
+        fn move_shape(&mut self, sel: SelectedObject, dx: f64, dy: f64) {
+            match sel {
+                SelectedObject::Rect(i) => self.rects[i].move_by(dx, dy),
+                SelectedObject::Polygon(i) => self.polygons[i].move_by(dx, dy),
+                SelectedObject::Image(i) => self.images[i].move_by(dx, dy),
+            }
+            self.update_highlight();
+            self.update_inspector();
+        }
+
         pub fn handle_right_click(&mut self, x: f64, y: f64) {
             if let Some(ref mut pm) = self.polygon_menu {
                 pm.close();
             }
             let mut menu = self.context_menu.take().unwrap_or_else(ContextMenu::new);
-            menu.open(x, y);
+            // Hidden shapes can't be clicked, so the menu is how they come back
+            let mut items = vec!["Rect".to_string(), "RegularPolygon".to_string()];
+            if self.selected.is_some() {
+                items.extend(["Bring to Front", "Send to Back", "Hide"].map(str::to_string));
+            }
+            if !self.hidden_shapes().is_empty() {
+                items.push("Show Hidden".to_string());
+            }
+            menu.open_with_items(items, x, y);
             self.context_menu = Some(menu);
         }
 
+        fn apply_menu_choice(&mut self, selection: &str, x: f64, y: f64) {
+            match selection {
+                "Rect" => {
+                    let mut r = Rect::new();
+                    r.initialize(x, y, 100.0, 100.0);
+                    self.create_shape(SceneShape::Rect(r));
+                }
+                "RegularPolygon" => {
+                    if let Some(ref mut pm) = self.polygon_menu {
+                        pm.open(x + 100.0, y);
+                    }
+                }
+                "Bring to Front" => self.bring_to_front(),
+                "Send to Back" => self.send_to_back(),
+                "Hide" => self.hide_selected(),
+                "Show Hidden" => self.show_hidden(),
+                _ => {}
+            }
+        }
+
+        // Keyboard equivalent of a right click: opens the context menu at the center of the selection
+        pub fn open_context_menu_at_selection(&mut self) -> bool {
+            let Some(sel) = self.selected else {
+                return false;
+            };
+            let (cx, cy) = self.shape(sel).center();
+            self.handle_right_click(cx, cy);
+            if let Some(ref mut menu) = self.context_menu {
+                menu.move_highlight(1);
+            }
+            true
+        }
+
+        pub fn is_menu_open(&mut self) -> bool {
+            self.context_menu.is_some()
+        }
+
+        // Move the keyboard highlight in whichever menu is frontmost
+        pub fn move_menu_highlight(&mut self, delta: i64) {
+            if let Some(pm) = self.polygon_menu.as_mut().filter(|pm| pm.is_visible()) {
+                pm.move_hover(delta);
+            } else if let Some(ref mut menu) = self.context_menu {
+                menu.move_highlight(delta);
+            }
+        }
+
+        // Choose the highlighted item in the frontmost menu, as if it had been clicked
+        pub fn activate_menu(&mut self) {
+            if let Some(pm) = self.polygon_menu.as_mut().filter(|pm| pm.is_visible()) {
+                let (x, y) = pm.position();
+                if let Some(sides) = pm.activate() {
+                    let mut p = RegularPolygon::new();
+                    p.initialize(x, y, 40.0, sides);
+                    self.create_shape(SceneShape::Polygon(p));
+                }
+                self.context_menu = None;
+                return;
+            }
+            if let Some(ref mut menu) = self.context_menu {
+                let (x, y) = menu.position();
+                if let Some(selection) = menu.activate() {
+                    self.apply_menu_choice(&selection, x, y);
+                }
+                let hide_menu = if let Some(pm) = self.polygon_menu.as_mut() { !pm.is_visible() } else { true };
+                if hide_menu {
+                    self.context_menu = None;
+                } else if let Some(ref mut pm) = self.polygon_menu {
+                    pm.move_hover(1);
+                }
+            }
+        }
+
+        pub fn close_menus(&mut self) {
+            if let Some(ref mut pm) = self.polygon_menu {
+                pm.close();
+            }
+            self.context_menu = None;
+        }
+
         pub fn set_show_render_times(&mut self, show: bool) {
             self.show_render_times = show;
         }
@@ -492,9 +1070,72 @@ hotline::object!({
             self.update_inspector();
         }
 
+        // Scene shapes for the CPU render mode; on the GPU path the host draws rects itself
         pub fn render(&mut self, buffer: &mut [u8], buffer_width: i64, buffer_height: i64, pitch: i64) {
-            // GPU only - no CPU rendering
-            let _ = (buffer, buffer_width, buffer_height, pitch);
+            for sel in self.draw_order() {
+                match sel {
+                    SelectedObject::Rect(i) => self.rects[i].render(buffer, buffer_width, buffer_height, pitch),
+                    SelectedObject::Polygon(i) => self.polygons[i].render(buffer, buffer_width, buffer_height, pitch),
+                    SelectedObject::Image(i) => self.images[i].render(buffer, buffer_width, buffer_height, pitch),
+                }
+            }
+        }
+
+        // Faint snap grid lines across a width x height view, for hosts to draw under the scene
+        pub fn render_grid_gpu(
+            &mut self,
+            gpu_renderer: &mut dyn ::hotline::GpuRenderingContext,
+            width: f64,
+            height: f64,
+        ) {
+            // Finer grids would wash over the whole scene
+            if self.snap_grid < 4.0 {
+                return;
+            }
+            let theme = self.registry().theme();
+            let [r, g, b, a] = theme.border;
+            let color = [r, g, b, a * 0.25];
+            let mut x = self.snap_grid;
+            while x < width {
+                gpu_renderer.add_solid_rect(x as f32, 0.0, 1.0, height as f32, color);
+                x += self.snap_grid;
+            }
+            let mut y = self.snap_grid;
+            while y < height {
+                gpu_renderer.add_solid_rect(0.0, y as f32, width as f32, 1.0, color);
+                y += self.snap_grid;
+            }
+        }
+
+        // Overlays drawn above the scene
+        pub fn render_gpu(&mut self, gpu_renderer: &mut dyn ::hotline::GpuRenderingContext) {
+            if let Some(ref mut inspector) = self.click_inspector {
+                inspector.render_gpu(gpu_renderer);
+            }
+            if let Some(ref mut menu) = self.context_menu {
+                menu.render_gpu(gpu_renderer);
+            }
+            if let Some(ref mut pm) = self.polygon_menu {
+                pm.render_gpu(gpu_renderer);
+            }
         }
     }
 });
+
+macro_rules! impl_bounded {
+    ($($shape:ident),*) => {
+        $(impl ::hotline::Bounded for $shape {
+            fn bounds(&self) -> (f64, f64, f64, f64) {
+                $shape::bounds(self)
+            }
+            fn contains_point(&self, x: f64, y: f64) -> bool {
+                $shape::contains_point(self, x, y)
+            }
+            fn center(&self) -> (f64, f64) {
+                $shape::center(self)
+            }
+        })*
+    };
+}
+
+impl_bounded!(Rect, RegularPolygon, Image);
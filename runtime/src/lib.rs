@@ -1,4 +1,4 @@
-use hotline::{HotlineObject, LibraryRegistry, ObjectHandle};
+use hotline::{HotlineObject, LibraryRegistry, ObjectHandle, StateFormat};
 use notify::{Config, RecommendedWatcher, RecursiveMode, Watcher};
 use std::collections::HashSet;
 use std::path::Path;
@@ -93,6 +93,24 @@ impl DirectRuntime {
         Ok(handle)
     }
 
+    // Json writes readable state for inspecting or committing; either format loads back with `load_state`
+    pub fn save_state(
+        &self,
+        handle: &ObjectHandle,
+        path: &Path,
+        format: StateFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let data = handle.lock().map_err(|_| "Failed to lock object")?.serialize_state_as(format)?;
+        std::fs::write(path, data).map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+        Ok(())
+    }
+
+    pub fn load_state(&self, handle: &ObjectHandle, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let data = std::fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        handle.lock().map_err(|_| "Failed to lock object")?.deserialize_state(&data)?;
+        Ok(())
+    }
+
     pub fn library_registry(&self) -> &LibraryRegistry {
         &self.library_registry
     }